@@ -0,0 +1,87 @@
+//! Throughput of the encoding loops and of whole dumps, in GB/s of input.
+//!
+//! Run with `cargo bench --bench throughput`; set `HEXDUMP_BENCH_MB` to
+//! change the input size (default 64).
+
+use std::hint::black_box;
+use std::io;
+use std::time::{Duration, Instant};
+
+use hexdump::encode::Backend;
+use hexdump::{HexDumper, Preset};
+
+const ROUNDS: usize = 5;
+
+fn main() {
+    let mb: usize = std::env::var("HEXDUMP_BENCH_MB")
+        .ok()
+        .and_then(|v| v.parse().ok())
+        .unwrap_or(64);
+    let data = input(mb << 20);
+    println!("input: {mb} MiB, best of {ROUNDS} rounds");
+
+    let mut hex = vec![0u8; data.len() * 2];
+    let mut text = vec![0u8; data.len()];
+    for backend in Backend::ALL.into_iter().filter(|b| b.is_available()) {
+        report(&format!("encode::hex {backend:?}"), data.len(), || {
+            backend.hex(&data, &mut hex, false);
+            black_box(&hex);
+        });
+        report(&format!("encode::ascii {backend:?}"), data.len(), || {
+            backend.ascii(&data, &mut text, b'.');
+            black_box(&text);
+        });
+    }
+
+    let layouts = [
+        ("default", HexDumper::new()),
+        ("xxd", HexDumper::new().preset(Preset::Xxd)),
+        (
+            "hexdump -C",
+            HexDumper::new().preset(Preset::HexdumpCanonical),
+        ),
+        ("od", HexDumper::new().preset(Preset::OdHex)),
+    ];
+    let mut out = String::with_capacity(data.len() * 5);
+    for (name, dumper) in &layouts {
+        report(&format!("write_dump {name}"), data.len(), || {
+            out.clear();
+            dumper.write_dump(&mut out, &data).unwrap();
+            black_box(&out);
+        });
+    }
+    report("dump_reader default", data.len(), || {
+        HexDumper::new().dump_reader(&data[..], io::sink()).unwrap();
+    });
+}
+
+/// Pseudo-random bytes with some runs of text, like a typical capture.
+fn input(len: usize) -> Vec<u8> {
+    let mut state = 0x2545_f491_4f6c_dd1du64;
+    (0..len)
+        .map(|i| {
+            state ^= state << 13;
+            state ^= state >> 7;
+            state ^= state << 17;
+            if i % 4096 < 1024 {
+                b' ' + (state % 95) as u8
+            } else {
+                state as u8
+            }
+        })
+        .collect()
+}
+
+fn report(name: &str, bytes: usize, mut run: impl FnMut()) {
+    run();
+    let best = (0..ROUNDS)
+        .map(|_| {
+            let start = Instant::now();
+            run();
+            start.elapsed()
+        })
+        .min()
+        .unwrap_or(Duration::MAX);
+    let gbps = bytes as f64 / best.as_secs_f64() / 1e9;
+    println!("{name:<28} {gbps:>8.3} GB/s");
+}
@@ -0,0 +1,249 @@
+//! Labelled byte ranges and the numbers they hold.
+//!
+//! An [`Annotation`] names a run of bytes, say a header field, so that
+//! outputs built for other programs and for browsers, such as
+//! [`JsonLines`](crate::json::JsonLines), can show it next to the lines it
+//! covers. An annotation made with [`Annotation::field`] also says how to
+//! read its bytes, and carries the decoded [`Value`] along:
+//!
+//! ```
+//! use hexdump::annotate::{Annotation, Field, Value};
+//!
+//! let header = b"\x7fELF\x02\x01\x01\x00";
+//! let class = Annotation::field(4, Field::U8, "class");
+//! assert_eq!(class.range, 4..5);
+//! assert_eq!(class.decode(&header[4..]), Some(Value::Unsigned(2)));
+//!
+//! let magic: Field = "u32be".parse()?;
+//! assert_eq!(magic.decode(header), Some(Value::Unsigned(0x7f45_4c46)));
+//! # Ok::<(), hexdump::annotate::UnknownField>(())
+//! ```
+
+use std::error::Error;
+use std::fmt;
+use std::ops::Range;
+use std::str::FromStr;
+
+/// A labelled run of bytes.
+///
+/// Ranges are numbered like the offset column of the dump they annotate,
+/// that is from the dumper's [`base_address`](crate::HexDumper::base_address)
+/// on.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Annotation {
+    pub range: Range<u64>,
+    pub label: String,
+    /// How to read the bytes, if they hold a number.
+    pub field: Option<Field>,
+}
+
+impl Annotation {
+    /// Labels the bytes in `range`.
+    pub fn new(range: Range<u64>, label: impl Into<String>) -> Self {
+        Annotation {
+            range,
+            label: label.into(),
+            field: None,
+        }
+    }
+
+    /// Labels the bytes of a `field` at `address`.
+    pub fn field(address: u64, field: Field, label: impl Into<String>) -> Self {
+        Annotation {
+            range: address..address.saturating_add(field.width() as u64),
+            label: label.into(),
+            field: Some(field),
+        }
+    }
+
+    /// Whether the annotation covers any of the bytes in `range`.
+    pub fn overlaps(&self, range: &Range<u64>) -> bool {
+        self.range.start < range.end && range.start < self.range.end
+    }
+
+    /// Decodes the field from `data`, the bytes numbered from the start of
+    /// the range. `None` for annotations without a field, or if `data` is
+    /// too short.
+    pub fn decode(&self, data: &[u8]) -> Option<Value> {
+        self.field?.decode(data)
+    }
+}
+
+/// Byte order of a multi-byte [`Field`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Endian {
+    Little,
+    Big,
+}
+
+/// How to read a number out of bytes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum Field {
+    U8,
+    I8,
+    U16(Endian),
+    I16(Endian),
+    U32(Endian),
+    I32(Endian),
+    U64(Endian),
+    I64(Endian),
+    F32(Endian),
+    F64(Endian),
+}
+
+impl Field {
+    /// The unsigned integers of every width, little endian first, as tools
+    /// showing every reading of the bytes under the cursor list them.
+    pub const UNSIGNED: [Field; 7] = [
+        Field::U8,
+        Field::U16(Endian::Little),
+        Field::U16(Endian::Big),
+        Field::U32(Endian::Little),
+        Field::U32(Endian::Big),
+        Field::U64(Endian::Little),
+        Field::U64(Endian::Big),
+    ];
+
+    /// The number of bytes read.
+    pub const fn width(self) -> usize {
+        match self {
+            Field::U8 | Field::I8 => 1,
+            Field::U16(_) | Field::I16(_) => 2,
+            Field::U32(_) | Field::I32(_) | Field::F32(_) => 4,
+            Field::U64(_) | Field::I64(_) | Field::F64(_) => 8,
+        }
+    }
+
+    /// The field's name, such as `u8` or `u32le`, as [`FromStr`] reads it.
+    pub fn name(self) -> &'static str {
+        use Endian::{Big, Little};
+        match self {
+            Field::U8 => "u8",
+            Field::I8 => "i8",
+            Field::U16(Little) => "u16le",
+            Field::U16(Big) => "u16be",
+            Field::I16(Little) => "i16le",
+            Field::I16(Big) => "i16be",
+            Field::U32(Little) => "u32le",
+            Field::U32(Big) => "u32be",
+            Field::I32(Little) => "i32le",
+            Field::I32(Big) => "i32be",
+            Field::U64(Little) => "u64le",
+            Field::U64(Big) => "u64be",
+            Field::I64(Little) => "i64le",
+            Field::I64(Big) => "i64be",
+            Field::F32(Little) => "f32le",
+            Field::F32(Big) => "f32be",
+            Field::F64(Little) => "f64le",
+            Field::F64(Big) => "f64be",
+        }
+    }
+
+    /// Reads the field from the start of `bytes`; `None` if there are fewer
+    /// than [`width`](Field::width) of them.
+    pub fn decode(self, bytes: &[u8]) -> Option<Value> {
+        let bytes = bytes.get(..self.width())?;
+        let mut word = [0u8; 8];
+        let endian = match self {
+            Field::U8 | Field::I8 => Endian::Little,
+            Field::U16(endian)
+            | Field::I16(endian)
+            | Field::U32(endian)
+            | Field::I32(endian)
+            | Field::U64(endian)
+            | Field::I64(endian)
+            | Field::F32(endian)
+            | Field::F64(endian) => endian,
+        };
+        // Read every width as a little endian u64, then narrow it.
+        match endian {
+            Endian::Little => word[..bytes.len()].copy_from_slice(bytes),
+            Endian::Big => {
+                for (to, from) in word.iter_mut().zip(bytes.iter().rev()) {
+                    *to = *from;
+                }
+            }
+        }
+        let n = u64::from_le_bytes(word);
+        Some(match self {
+            Field::U8 | Field::U16(_) | Field::U32(_) | Field::U64(_) => Value::Unsigned(n),
+            Field::I8 => Value::Signed(i64::from(n as u8 as i8)),
+            Field::I16(_) => Value::Signed(i64::from(n as u16 as i16)),
+            Field::I32(_) => Value::Signed(i64::from(n as u32 as i32)),
+            Field::I64(_) => Value::Signed(n as i64),
+            Field::F32(_) => Value::Float(f64::from(f32::from_bits(n as u32))),
+            Field::F64(_) => Value::Float(f64::from_bits(n)),
+        })
+    }
+}
+
+impl fmt::Display for Field {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+/// Error from parsing a [`Field`] name.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UnknownField(String);
+
+impl fmt::Display for UnknownField {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "unknown field type '{}': expected u8, i8, or u16, i16, u32, i32, u64, i64, f32 \
+             or f64 followed by le or be",
+            self.0
+        )
+    }
+}
+
+impl Error for UnknownField {}
+
+impl FromStr for Field {
+    type Err = UnknownField;
+
+    /// Reads a name as [`name`](Field::name) writes it, in either case.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let name = s.to_ascii_lowercase();
+        let (kind, endian) = match name.as_str() {
+            "u8" => return Ok(Field::U8),
+            "i8" => return Ok(Field::I8),
+            _ => match (name.strip_suffix("le"), name.strip_suffix("be")) {
+                (Some(kind), _) => (kind, Endian::Little),
+                (_, Some(kind)) => (kind, Endian::Big),
+                _ => return Err(UnknownField(s.to_owned())),
+            },
+        };
+        Ok(match kind {
+            "u16" => Field::U16(endian),
+            "i16" => Field::I16(endian),
+            "u32" => Field::U32(endian),
+            "i32" => Field::I32(endian),
+            "u64" => Field::U64(endian),
+            "i64" => Field::I64(endian),
+            "f32" => Field::F32(endian),
+            "f64" => Field::F64(endian),
+            _ => return Err(UnknownField(s.to_owned())),
+        })
+    }
+}
+
+/// A number decoded from a [`Field`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Value {
+    Unsigned(u64),
+    Signed(i64),
+    Float(f64),
+}
+
+impl fmt::Display for Value {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Value::Unsigned(n) => write!(f, "{n}"),
+            Value::Signed(n) => write!(f, "{n}"),
+            Value::Float(x) => write!(f, "{x}"),
+        }
+    }
+}
@@ -0,0 +1,932 @@
+//! Command line parsing.
+//!
+//! Options follow the usual conventions: short options can be clustered
+//! (`-au`) and take their value attached or as the next argument (`-c16`,
+//! `-c 16`), long options take it after `=` or as the next argument, and
+//! `--` ends the options.
+
+use std::ffi::OsString;
+use std::fmt;
+use std::path::PathBuf;
+
+use hexdump::annotate::{Annotation, Field};
+use hexdump::codegen::Language;
+use hexdump::color::{ColorChoice, ColorDepth};
+use hexdump::search::Pattern;
+use hexdump::strings::Encoding;
+#[cfg(feature = "visualize")]
+use hexdump::visualize::Colors;
+use hexdump::{HexDumper, OffsetBase, Preset, Squeeze, TrailingOffset, UnknownPreset};
+
+use crate::parse;
+
+pub const HELP: &str = "\
+Usage: hexdump-rs [OPTIONS] [INFILE [OUTFILE]]
+
+Dumps INFILE, or standard input if it is missing or '-', to OUTFILE or
+standard output.
+
+Layout:
+  -C, --canonical            hexdump -C layout
+  -p, --plain                plain hex digits, like xxd -p
+  -b, --bits                 binary digits, like xxd -b
+  -i, --include              C include file, like xxd -i
+      --preset NAME          xxd, xxd-plain, xxd-bits, hexdump, od-hex,
+                             od-decimal or od-octal
+  -c, --cols N               bytes per line, at most 4096; with -p, 0 puts
+                             everything on one line
+  -g, --groupsize N          bytes per group, 0 for one group per line
+  -u, --upper                upper case hex digits
+      --no-offset            hide the offset column
+      --no-ascii             hide the ASCII column
+      --placeholder CHAR     character shown for non-printable bytes
+      --offset-separator S   text between the offset and the hex column
+      --group-separator S    text between groups
+      --byte-separator S     text between bytes of a group
+      --ascii-separator S    text between the hex and ASCII columns
+      --ascii-delimiters LR  text around the ASCII column, split in half:
+                             '||' gives |ascii|, '' none
+
+Offsets:
+  -s, --seek [-]OFFSET       start at OFFSET, or OFFSET bytes before the end
+  -l, --len N                stop after N bytes
+  -d, --decimal              decimal offsets
+      --offset-base BASE     hex, HEX, dec or oct
+      --offset-width N       minimum number of offset digits
+  -o, --base-address ADDR    number lines from ADDR instead of 0
+      --relative-to ADDR     show offsets as distances from ADDR
+
+Squeezing:
+  -a, --autoskip             replace repeated lines with '*'
+      --squeeze N            replace runs of at least N repeated lines
+      --no-squeeze           print every line
+      --squeeze-count        show the number of lines each '*' replaces
+      --trailing-offset WHEN end with the final offset: never, non-empty
+                             or always
+
+Searching:
+      --find HEX             highlight bytes matching HEX, or mark them
+                             with ^ without colour; ? stands for any
+                             nibble: '4d 5a ?? ?0'
+      --find-text TEXT       highlight TEXT as ASCII or UTF-8
+      --find-utf16 TEXT      highlight TEXT as UTF-16LE
+      --find-utf16be TEXT    highlight TEXT as UTF-16BE
+      --find-regex REGEX     highlight matches of REGEX, when built with the
+                             regex feature
+      --context N            print only lines with matches and N lines
+                             around them
+
+Strings:
+      --strings              list runs of text with their offsets, like
+                             strings -t x
+      --min-len N            shortest string to list, in characters
+                             (default 4)
+      --encodings LIST       comma-separated encodings to look for: ascii
+                             (the default), utf8, utf16le, utf16be,
+                             utf32le, utf32be or all
+      --show-lines           follow each string with the dump lines
+                             holding it
+
+Firmware files:
+      --from FORMAT          read INFILE as ihex (Intel HEX) or srec
+                             (S-records) and dump it at its own addresses
+      --to FORMAT            convert to ihex, srec or raw bytes instead of
+                             dumping; -c sets bytes per record, and raw
+                             input is placed at the -o address
+      --gap-fill BYTE        byte filling gaps with '--to raw' (default
+                             0xff)
+
+Colour:
+  -R, --color WHEN           auto (the default), always or never
+      --color-depth DEPTH    16, 256 or truecolor
+
+Other modes:
+  -r, --reverse              turn a dump back into bytes; with -p, read
+                             plain hex, with -o, numbered from ADDR
+  -n, --name NAME            variable name for -i and --codegen
+      --capitalize           upper case variable names for -i and
+                             --codegen
+      --codegen LANG         the bytes as source code: c, rust, rust-slice,
+                             python, go or zig; -c sets bytes per line
+      --len-name NAME        name of the length constant for --codegen
+      --no-len               leave out the length constant
+      --comments             end each line with its offset and ASCII text
+      --json                 one JSON object per line: a header, the lines
+                             with their hex, ASCII and annotations, squeezed
+                             runs and the end offset
+      --html                 a web page with coloured bytes, tooltips
+                             reading the integers at each byte, selection
+                             and the annotations
+      --annotate SPEC        label bytes for '--json' and '--html', as
+                             ADDR:FIELD or ADDR:LEN with an optional :LABEL;
+                             FIELD is u8, i8, or u16, i16, u32, i32, u64,
+                             i64, f32 or f64 followed by le or be
+      --visualize COLORS     a PNG picture with a pixel per byte coloured by
+                             class, entropy or grey (its value), when built
+                             with the visualize feature; -c sets the width
+      --hilbert              lay the pixels out along a Hilbert curve
+      --pixel-bytes N        bytes per pixel, averaged
+
+  -h, --help                 print this help
+  -V, --version              print the version
+
+Numbers may be written in hex (0x), octal (0o) or binary (0b), and take a
+k, m or g suffix for KiB, MiB or GiB.
+
+Exit status is 0 on success, 1 if reading or writing fails and 2 for
+invalid arguments.
+";
+
+/// What the command line asks for.
+#[derive(Debug)]
+pub enum Command {
+    Run(Box<Options>),
+    Help,
+    Version,
+}
+
+/// What to turn the input into.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Mode {
+    Dump,
+    /// `-p -c 0`: all hex digits on one line.
+    SingleLine,
+    Include,
+    Reverse,
+    Strings,
+    Codegen(Language),
+    Convert(Format),
+    Json,
+    Html,
+    #[cfg(feature = "visualize")]
+    Visualize(Colors),
+}
+
+/// A file format for `--from` and `--to`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    Raw,
+    Ihex,
+    Srec,
+}
+
+/// Where to start reading.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Seek {
+    Start(u64),
+    End(u64),
+}
+
+#[derive(Debug)]
+pub struct Options {
+    /// The dump layout, without the base address.
+    pub dumper: HexDumper,
+    pub mode: Mode,
+    pub seek: Seek,
+    pub length: Option<u64>,
+    pub base_address: Option<u64>,
+    pub columns: Option<usize>,
+    pub uppercase: bool,
+    pub plain: bool,
+    pub offset_base: Option<OffsetBase>,
+    pub name: Option<String>,
+    pub capitalize: bool,
+    pub search: Option<Pattern>,
+    pub context: Option<usize>,
+    pub min_len: Option<usize>,
+    pub encodings: Option<Vec<Encoding>>,
+    pub show_lines: bool,
+    pub len_name: Option<String>,
+    pub len_constant: bool,
+    pub comments: bool,
+    pub from: Option<Format>,
+    pub gap_fill: Option<u8>,
+    pub annotations: Vec<Annotation>,
+    #[cfg(feature = "visualize")]
+    pub hilbert: bool,
+    #[cfg(feature = "visualize")]
+    pub pixel_bytes: Option<usize>,
+    pub input: Option<PathBuf>,
+    pub output: Option<PathBuf>,
+}
+
+/// An invalid command line.
+#[derive(Debug)]
+pub struct UsageError(String);
+
+impl fmt::Display for UsageError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+/// Short name, long name and whether the option takes a value.
+const OPTIONS: &[(Option<char>, &str, bool)] = &[
+    (Some('C'), "canonical", false),
+    (Some('p'), "plain", false),
+    (Some('b'), "bits", false),
+    (Some('i'), "include", false),
+    (None, "preset", true),
+    (Some('c'), "cols", true),
+    (Some('g'), "groupsize", true),
+    (Some('u'), "upper", false),
+    (None, "no-offset", false),
+    (None, "no-ascii", false),
+    (None, "placeholder", true),
+    (None, "offset-separator", true),
+    (None, "group-separator", true),
+    (None, "byte-separator", true),
+    (None, "ascii-separator", true),
+    (None, "ascii-delimiters", true),
+    (Some('s'), "seek", true),
+    (Some('l'), "len", true),
+    (Some('d'), "decimal", false),
+    (None, "offset-base", true),
+    (None, "offset-width", true),
+    (Some('o'), "base-address", true),
+    (None, "relative-to", true),
+    (Some('a'), "autoskip", false),
+    (None, "squeeze", true),
+    (None, "no-squeeze", false),
+    (None, "squeeze-count", false),
+    (None, "trailing-offset", true),
+    (None, "find", true),
+    (None, "find-text", true),
+    (None, "find-utf16", true),
+    (None, "find-utf16be", true),
+    (None, "find-regex", true),
+    (None, "context", true),
+    (None, "strings", false),
+    (None, "min-len", true),
+    (None, "encodings", true),
+    (None, "show-lines", false),
+    (None, "from", true),
+    (None, "to", true),
+    (None, "gap-fill", true),
+    (Some('R'), "color", true),
+    (None, "color-depth", true),
+    (Some('r'), "reverse", false),
+    (Some('n'), "name", true),
+    (None, "capitalize", false),
+    (None, "codegen", true),
+    (None, "len-name", true),
+    (None, "no-len", false),
+    (None, "comments", false),
+    (None, "json", false),
+    (None, "html", false),
+    (None, "visualize", true),
+    (None, "hilbert", false),
+    (None, "pixel-bytes", true),
+    (None, "annotate", true),
+    (Some('h'), "help", false),
+    (Some('V'), "version", false),
+];
+
+/// Settings as given, applied on top of the preset once every argument has
+/// been read, so that options adjust the preset whatever their order.
+#[derive(Default)]
+struct Settings {
+    preset: Option<Preset>,
+    include: bool,
+    reverse: bool,
+    columns: Option<usize>,
+    group_size: Option<usize>,
+    uppercase: bool,
+    no_offset: bool,
+    no_ascii: bool,
+    placeholder: Option<char>,
+    offset_separator: Option<&'static str>,
+    group_separator: Option<&'static str>,
+    byte_separator: Option<&'static str>,
+    ascii_separator: Option<&'static str>,
+    ascii_delimiters: Option<(&'static str, &'static str)>,
+    seek: Option<Seek>,
+    length: Option<u64>,
+    offset_base: Option<OffsetBase>,
+    offset_width: Option<usize>,
+    base_address: Option<u64>,
+    relative_to: Option<u64>,
+    squeeze: Option<Squeeze>,
+    squeeze_count: bool,
+    trailing_offset: Option<TrailingOffset>,
+    color: Option<ColorChoice>,
+    color_depth: Option<ColorDepth>,
+    name: Option<String>,
+    capitalize: bool,
+    codegen: Option<Language>,
+    len_name: Option<String>,
+    no_len: bool,
+    comments: bool,
+    from: Option<Format>,
+    to: Option<Format>,
+    gap_fill: Option<u8>,
+    json: bool,
+    html: bool,
+    #[cfg(feature = "visualize")]
+    visualize: Option<Colors>,
+    hilbert: bool,
+    pixel_bytes: Option<usize>,
+    annotations: Vec<Annotation>,
+    search: Option<Pattern>,
+    searches: usize,
+    context: Option<usize>,
+    strings: bool,
+    min_len: Option<usize>,
+    encodings: Option<Vec<Encoding>>,
+    show_lines: bool,
+    files: Vec<PathBuf>,
+}
+
+/// Parses the arguments following the program name.
+pub fn parse<I: IntoIterator<Item = OsString>>(args: I) -> Result<Command, UsageError> {
+    let mut settings = Settings::default();
+    let mut args = args.into_iter();
+    let mut options_done = false;
+    while let Some(arg) = args.next() {
+        let text = match arg.to_str() {
+            Some(text) if !options_done && text.len() > 1 && text.starts_with('-') => text,
+            _ => {
+                settings.files.push(PathBuf::from(arg));
+                continue;
+            }
+        };
+        if text == "--" {
+            options_done = true;
+        } else if let Some(long) = text.strip_prefix("--") {
+            let (name, inline) = match long.split_once('=') {
+                Some((name, value)) => (name, Some(value.to_owned())),
+                None => (long, None),
+            };
+            let &(_, name, takes_value) = OPTIONS
+                .iter()
+                .find(|(_, long, _)| *long == name)
+                .ok_or_else(|| usage(format!("unknown option '--{name}'")))?;
+            let value = match (takes_value, inline) {
+                (true, Some(value)) => Some(value),
+                (true, None) => Some(next_value(&mut args, name)?),
+                (false, None) => None,
+                (false, Some(_)) => return Err(usage(format!("'--{name}' takes no value"))),
+            };
+            if let Some(command) = settings.apply(name, value)? {
+                return Ok(command);
+            }
+        } else {
+            let cluster = &text[1..];
+            for (i, c) in cluster.char_indices() {
+                let &(_, name, takes_value) = OPTIONS
+                    .iter()
+                    .find(|(short, _, _)| *short == Some(c))
+                    .ok_or_else(|| usage(format!("unknown option '-{c}'")))?;
+                let rest = &cluster[i + c.len_utf8()..];
+                let value = match takes_value {
+                    true if rest.is_empty() => Some(next_value(&mut args, name)?),
+                    true => Some(rest.to_owned()),
+                    false => None,
+                };
+                if let Some(command) = settings.apply(name, value)? {
+                    return Ok(command);
+                }
+                if takes_value {
+                    break;
+                }
+            }
+        }
+    }
+    settings
+        .finish()
+        .map(|options| Command::Run(Box::new(options)))
+}
+
+fn next_value<I: Iterator<Item = OsString>>(
+    args: &mut I,
+    name: &str,
+) -> Result<String, UsageError> {
+    let value = args
+        .next()
+        .ok_or_else(|| usage(format!("'--{name}' needs a value")))?;
+    value
+        .into_string()
+        .map_err(|_| usage(format!("the value of '--{name}' is not valid UTF-8")))
+}
+
+impl Settings {
+    /// Records one option. Returns a command to run straight away for
+    /// `--help` and `--version`.
+    fn apply(&mut self, name: &str, value: Option<String>) -> Result<Option<Command>, UsageError> {
+        let value = value.unwrap_or_default();
+        let value = value.as_str();
+        match name {
+            "canonical" => self.preset = Some(Preset::HexdumpCanonical),
+            "plain" => self.preset = Some(Preset::XxdPlain),
+            "bits" => self.preset = Some(Preset::XxdBits),
+            "include" => self.include = true,
+            "preset" => self.preset = Some(preset(value)?),
+            "cols" => {
+                let n =
+                    parse::columns(value).map_err(|expected| invalid(name, value, &expected))?;
+                self.columns = Some(n);
+            }
+            "groupsize" => self.group_size = Some(size(name, value)?),
+            "upper" => self.uppercase = true,
+            "no-offset" => self.no_offset = true,
+            "no-ascii" => self.no_ascii = true,
+            "placeholder" => {
+                let mut chars = value.chars();
+                match (chars.next(), chars.next()) {
+                    (Some(c), None) => self.placeholder = Some(c),
+                    _ => return Err(invalid(name, value, "expected a single character")),
+                }
+            }
+            "offset-separator" => self.offset_separator = Some(leak(value)),
+            "group-separator" => self.group_separator = Some(leak(value)),
+            "byte-separator" => self.byte_separator = Some(leak(value)),
+            "ascii-separator" => self.ascii_separator = Some(leak(value)),
+            "ascii-delimiters" => {
+                let chars: Vec<char> = value.chars().collect();
+                if !chars.len().is_multiple_of(2) {
+                    return Err(invalid(
+                        name,
+                        value,
+                        "expected an even number of characters",
+                    ));
+                }
+                let (left, right) = chars.split_at(chars.len() / 2);
+                let left: String = left.iter().collect();
+                let right: String = right.iter().collect();
+                self.ascii_delimiters = Some((leak(&left), leak(&right)));
+            }
+            "seek" => {
+                self.seek = Some(match value.strip_prefix('-') {
+                    Some(n) => Seek::End(number(name, n)?),
+                    None => Seek::Start(number(name, value.strip_prefix('+').unwrap_or(value))?),
+                })
+            }
+            "len" => self.length = Some(number(name, value)?),
+            "decimal" => self.offset_base = Some(OffsetBase::Decimal),
+            "offset-base" => {
+                self.offset_base = Some(match value {
+                    "hex" => OffsetBase::Hex,
+                    "HEX" => OffsetBase::UpperHex,
+                    "dec" => OffsetBase::Decimal,
+                    "oct" => OffsetBase::Octal,
+                    _ => return Err(invalid(name, value, "expected hex, HEX, dec or oct")),
+                })
+            }
+            "offset-width" => self.offset_width = Some(size(name, value)?),
+            "base-address" => self.base_address = Some(number(name, value)?),
+            "relative-to" => self.relative_to = Some(number(name, value)?),
+            "autoskip" => self.squeeze = Some(Squeeze::Always),
+            "squeeze" => self.squeeze = Some(Squeeze::After(size(name, value)?)),
+            "no-squeeze" => self.squeeze = Some(Squeeze::Off),
+            "squeeze-count" => self.squeeze_count = true,
+            "trailing-offset" => {
+                self.trailing_offset = Some(match value {
+                    "never" => TrailingOffset::Never,
+                    "non-empty" => TrailingOffset::NonEmpty,
+                    "always" => TrailingOffset::Always,
+                    _ => return Err(invalid(name, value, "expected never, non-empty or always")),
+                })
+            }
+            "find" => {
+                let pattern =
+                    Pattern::hex(value).map_err(|err| invalid(name, value, &err.to_string()))?;
+                self.find(pattern);
+            }
+            "find-text" => self.find(Pattern::text(value)),
+            "find-utf16" => self.find(Pattern::utf16le(value)),
+            "find-utf16be" => self.find(Pattern::utf16be(value)),
+            "find-regex" => {
+                #[cfg(feature = "regex")]
+                {
+                    let pattern = Pattern::regex(value)
+                        .map_err(|err| invalid(name, value, &err.to_string()))?;
+                    self.find(pattern);
+                }
+                #[cfg(not(feature = "regex"))]
+                return Err(usage(
+                    "'--find-regex' needs a build with the regex feature".to_owned(),
+                ));
+            }
+            "context" => self.context = Some(size(name, value)?),
+            "strings" => self.strings = true,
+            "min-len" => {
+                let n = size(name, value)?;
+                if n == 0 {
+                    return Err(invalid(name, value, "expected at least 1"));
+                }
+                self.min_len = Some(n);
+            }
+            "encodings" => self.encodings = Some(encodings(value)?),
+            "show-lines" => self.show_lines = true,
+            "color" => {
+                self.color = Some(match value {
+                    "auto" => ColorChoice::Auto,
+                    "always" => ColorChoice::Always,
+                    "never" => ColorChoice::Never,
+                    _ => return Err(invalid(name, value, "expected auto, always or never")),
+                })
+            }
+            "color-depth" => {
+                self.color_depth = Some(match value {
+                    "16" => ColorDepth::Ansi16,
+                    "256" => ColorDepth::Ansi256,
+                    "truecolor" => ColorDepth::TrueColor,
+                    _ => return Err(invalid(name, value, "expected 16, 256 or truecolor")),
+                })
+            }
+            "reverse" => self.reverse = true,
+            "name" => self.name = Some(value.to_owned()),
+            "capitalize" => self.capitalize = true,
+            "codegen" => {
+                self.codegen = Some(match value {
+                    "c" => Language::C,
+                    "rust" => Language::Rust,
+                    "rust-slice" => Language::RustSlice,
+                    "python" => Language::Python,
+                    "go" => Language::Go,
+                    "zig" => Language::Zig,
+                    _ => {
+                        return Err(invalid(
+                            name,
+                            value,
+                            "expected c, rust, rust-slice, python, go or zig",
+                        ))
+                    }
+                })
+            }
+            "len-name" => self.len_name = Some(value.to_owned()),
+            "no-len" => self.no_len = true,
+            "comments" => self.comments = true,
+            "from" => {
+                self.from = Some(match value {
+                    "ihex" => Format::Ihex,
+                    "srec" => Format::Srec,
+                    _ => return Err(invalid(name, value, "expected ihex or srec")),
+                })
+            }
+            "to" => {
+                self.to = Some(match value {
+                    "ihex" => Format::Ihex,
+                    "srec" => Format::Srec,
+                    "raw" => Format::Raw,
+                    _ => return Err(invalid(name, value, "expected ihex, srec or raw")),
+                })
+            }
+            "gap-fill" => {
+                let byte = number(name, value)?;
+                let byte =
+                    u8::try_from(byte).map_err(|_| invalid(name, value, "expected a byte"))?;
+                self.gap_fill = Some(byte);
+            }
+            "json" => self.json = true,
+            "html" => self.html = true,
+            "visualize" => {
+                #[cfg(feature = "visualize")]
+                {
+                    self.visualize = Some(match value {
+                        "class" => Colors::ByteClass,
+                        "entropy" => Colors::Entropy,
+                        "grey" | "gray" => Colors::Greyscale,
+                        _ => return Err(invalid(name, value, "expected class, entropy or grey")),
+                    });
+                }
+                #[cfg(not(feature = "visualize"))]
+                return Err(usage(
+                    "'--visualize' needs a build with the visualize feature".to_owned(),
+                ));
+            }
+            "hilbert" => self.hilbert = true,
+            "pixel-bytes" => self.pixel_bytes = Some(size(name, value)?),
+            "annotate" => self.annotations.push(annotation(value)?),
+            "help" => return Ok(Some(Command::Help)),
+            "version" => return Ok(Some(Command::Version)),
+            _ => unreachable!("option '--{name}' is listed but not handled"),
+        }
+        Ok(None)
+    }
+
+    fn visualizing(&self) -> bool {
+        #[cfg(feature = "visualize")]
+        return self.visualize.is_some();
+        #[cfg(not(feature = "visualize"))]
+        false
+    }
+
+    fn find(&mut self, pattern: Pattern) {
+        self.search = Some(pattern);
+        self.searches += 1;
+    }
+
+    fn finish(self) -> Result<Options, UsageError> {
+        if self.files.len() > 2 {
+            return Err(usage("too many file arguments".to_owned()));
+        }
+        if self.include && self.reverse {
+            return Err(usage(
+                "'--include' and '--reverse' cannot be combined".to_owned(),
+            ));
+        }
+        if self.strings && (self.include || self.reverse || self.search.is_some()) {
+            return Err(usage(
+                "'--strings' cannot be combined with '--include', '--reverse' or searching"
+                    .to_owned(),
+            ));
+        }
+        if !self.strings && (self.min_len.is_some() || self.encodings.is_some() || self.show_lines)
+        {
+            return Err(usage(
+                "'--min-len', '--encodings' and '--show-lines' need '--strings'".to_owned(),
+            ));
+        }
+        if self.codegen.is_some()
+            && (self.include || self.reverse || self.strings || self.search.is_some())
+        {
+            return Err(usage(
+                "'--codegen' cannot be combined with '--include', '--reverse', '--strings' or \
+                 searching"
+                    .to_owned(),
+            ));
+        }
+        if self.codegen.is_none() && (self.len_name.is_some() || self.no_len || self.comments) {
+            return Err(usage(
+                "'--len-name', '--no-len' and '--comments' need '--codegen'".to_owned(),
+            ));
+        }
+        if self.json && self.html {
+            return Err(usage("'--json' and '--html' cannot be combined".to_owned()));
+        }
+        let page = self.json || self.html;
+        if page
+            && (self.include
+                || self.reverse
+                || self.strings
+                || self.codegen.is_some()
+                || self.search.is_some())
+        {
+            return Err(usage(
+                "'--json' and '--html' cannot be combined with '--include', '--reverse', \
+                 '--strings', '--codegen' or searching"
+                    .to_owned(),
+            ));
+        }
+        if !page && !self.annotations.is_empty() {
+            return Err(usage("'--annotate' needs '--json' or '--html'".to_owned()));
+        }
+        let visualize = self.visualizing();
+        if visualize
+            && (self.include
+                || self.reverse
+                || self.strings
+                || self.codegen.is_some()
+                || page
+                || self.from.is_some()
+                || self.to.is_some()
+                || self.search.is_some())
+        {
+            return Err(usage(
+                "'--visualize' cannot be combined with other modes".to_owned(),
+            ));
+        }
+        if !visualize && (self.hilbert || self.pixel_bytes.is_some()) {
+            return Err(usage(
+                "'--hilbert' and '--pixel-bytes' need '--visualize'".to_owned(),
+            ));
+        }
+        if self.hilbert && self.columns.is_some() {
+            return Err(usage("'--hilbert' cannot be combined with '-c'".to_owned()));
+        }
+        let firmware = self.from.is_some() || self.to.is_some();
+        if firmware
+            && (self.include
+                || self.reverse
+                || self.strings
+                || self.codegen.is_some()
+                || page
+                || self.search.is_some())
+        {
+            return Err(usage(
+                "'--from' and '--to' cannot be combined with other modes".to_owned(),
+            ));
+        }
+        if self.from.is_some()
+            && (self.seek.is_some() || self.length.is_some() || self.base_address.is_some())
+        {
+            return Err(usage(
+                "'--from' cannot be combined with '--seek', '--len' or '--base-address'".to_owned(),
+            ));
+        }
+        if self.gap_fill.is_some() && self.to != Some(Format::Raw) {
+            return Err(usage("'--gap-fill' needs '--to raw'".to_owned()));
+        }
+        let plain = self.preset == Some(Preset::XxdPlain);
+        let mode = match self.columns {
+            _ if self.reverse => Mode::Reverse,
+            _ if self.include => Mode::Include,
+            _ if self.strings => Mode::Strings,
+            _ if self.codegen.is_some() => Mode::Codegen(self.codegen.expect("checked above")),
+            _ if self.to.is_some() => Mode::Convert(self.to.expect("checked above")),
+            Some(0) if page => {
+                return Err(usage(
+                    "'--json' and '--html' cannot be combined with '-c 0'".to_owned(),
+                ))
+            }
+            _ if self.json => Mode::Json,
+            _ if self.html => Mode::Html,
+            #[cfg(feature = "visualize")]
+            _ if self.visualize.is_some() => {
+                Mode::Visualize(self.visualize.expect("checked above"))
+            }
+            Some(0) if plain && firmware => {
+                return Err(usage("'--from' cannot be combined with '-c 0'".to_owned()))
+            }
+            Some(0) if plain => Mode::SingleLine,
+            Some(0) => return Err(invalid("cols", "0", "only '--plain' allows zero columns")),
+            _ => Mode::Dump,
+        };
+        if self.searches > 1 {
+            return Err(usage("only one search pattern can be given".to_owned()));
+        }
+        if self.search.is_some() && mode != Mode::Dump {
+            return Err(usage(
+                "searching cannot be combined with '--include', '--reverse' or '-c 0'".to_owned(),
+            ));
+        }
+        if self.context.is_some() && self.search.is_none() {
+            return Err(usage("'--context' needs a search pattern".to_owned()));
+        }
+
+        let mut dumper = HexDumper::new();
+        if let Some(preset) = self.preset {
+            dumper = dumper.preset(preset);
+        }
+        if let Some(n) = self.columns.filter(|&n| n > 0) {
+            dumper = dumper.bytes_per_line(n);
+        }
+        if let Some(n) = self.group_size {
+            dumper = dumper.group_size(n);
+        }
+        if self.uppercase {
+            dumper = dumper.uppercase(true);
+        }
+        if self.no_offset {
+            dumper = dumper.show_offset(false);
+        }
+        if self.no_ascii {
+            dumper = dumper.show_ascii(false);
+        }
+        if let Some(c) = self.placeholder {
+            dumper = dumper.placeholder(c);
+        }
+        if let Some(sep) = self.offset_separator {
+            dumper = dumper.offset_separator(sep);
+        }
+        if let Some(sep) = self.group_separator {
+            dumper = dumper.group_separator(sep);
+        }
+        if let Some(sep) = self.byte_separator {
+            dumper = dumper.byte_separator(sep);
+        }
+        if let Some(sep) = self.ascii_separator {
+            dumper = dumper.ascii_separator(sep);
+        }
+        if let Some((left, right)) = self.ascii_delimiters {
+            dumper = dumper.ascii_delimiters(left, right);
+        }
+        if let Some(base) = self.offset_base {
+            dumper = dumper.offset_base(base);
+        }
+        if let Some(width) = self.offset_width {
+            dumper = dumper.offset_width(width);
+        }
+        if let Some(anchor) = self.relative_to {
+            dumper = dumper.relative_to(anchor);
+        }
+        if let Some(squeeze) = self.squeeze {
+            dumper = dumper.squeeze(squeeze);
+        }
+        if self.squeeze_count {
+            dumper = dumper.squeeze_annotation(true);
+        }
+        if let Some(trailing) = self.trailing_offset {
+            dumper = dumper.trailing_offset(trailing);
+        }
+        dumper = dumper.color(self.color.unwrap_or(ColorChoice::Auto));
+        if let Some(depth) = self.color_depth {
+            dumper = dumper.color_depth(depth);
+        }
+
+        let mut files = self.files.into_iter();
+        let stdio = |path: PathBuf| (path.as_os_str() != "-").then_some(path);
+        Ok(Options {
+            dumper,
+            mode,
+            seek: self.seek.unwrap_or(Seek::Start(0)),
+            length: self.length,
+            base_address: self.base_address,
+            columns: self.columns,
+            uppercase: self.uppercase,
+            plain,
+            offset_base: self.offset_base,
+            name: self.name,
+            capitalize: self.capitalize,
+            search: self.search,
+            context: self.context,
+            min_len: self.min_len,
+            encodings: self.encodings,
+            show_lines: self.show_lines,
+            len_name: self.len_name,
+            len_constant: !self.no_len,
+            comments: self.comments,
+            from: self.from,
+            gap_fill: self.gap_fill,
+            annotations: self.annotations,
+            #[cfg(feature = "visualize")]
+            hilbert: self.hilbert,
+            #[cfg(feature = "visualize")]
+            pixel_bytes: self.pixel_bytes,
+            input: files.next().and_then(stdio),
+            output: files.next().and_then(stdio),
+        })
+    }
+}
+
+fn preset(value: &str) -> Result<Preset, UsageError> {
+    value
+        .parse()
+        .map_err(|err: UnknownPreset| invalid("preset", value, &err.to_string()))
+}
+
+/// Parses a comma-separated list of encodings for `--encodings`.
+fn encodings(value: &str) -> Result<Vec<Encoding>, UsageError> {
+    let mut list = Vec::new();
+    for name in value.split(',') {
+        let encoding = match name.trim() {
+            "all" => {
+                list.extend(Encoding::ALL);
+                continue;
+            }
+            "ascii" => Encoding::Ascii,
+            "utf8" | "utf-8" => Encoding::Utf8,
+            "utf16le" | "utf-16le" => Encoding::Utf16Le,
+            "utf16be" | "utf-16be" => Encoding::Utf16Be,
+            "utf32le" | "utf-32le" => Encoding::Utf32Le,
+            "utf32be" | "utf-32be" => Encoding::Utf32Be,
+            _ => {
+                return Err(invalid(
+                    "encodings",
+                    value,
+                    "expected ascii, utf8, utf16le, utf16be, utf32le, utf32be or all",
+                ))
+            }
+        };
+        list.push(encoding);
+    }
+    Ok(list)
+}
+
+/// Parses an `--annotate` value: `ADDR:FIELD` or `ADDR:LEN`, then an
+/// optional `:LABEL`, which defaults to the field or length as given.
+fn annotation(value: &str) -> Result<Annotation, UsageError> {
+    let mut parts = value.splitn(3, ':');
+    let (Some(address), Some(kind)) = (parts.next(), parts.next()) else {
+        return Err(invalid(
+            "annotate",
+            value,
+            "expected ADDR:FIELD or ADDR:LEN, then an optional :LABEL",
+        ));
+    };
+    let address = number("annotate", address)?;
+    let label = parts.next().unwrap_or(kind);
+    match kind.parse::<Field>() {
+        Ok(field) => Ok(Annotation::field(address, field, label)),
+        Err(err) => {
+            let len = number("annotate", kind)
+                .map_err(|_| invalid("annotate", value, &err.to_string()))?;
+            Ok(Annotation::new(address..address.saturating_add(len), label))
+        }
+    }
+}
+
+fn number(name: &str, value: &str) -> Result<u64, UsageError> {
+    parse::number(value).map_err(|expected| invalid(name, value, expected))
+}
+
+fn size(name: &str, value: &str) -> Result<usize, UsageError> {
+    parse::size(value).map_err(|expected| invalid(name, value, expected))
+}
+
+/// Separators are borrowed for the life of the program, like the string
+/// literals the library expects.
+fn leak(value: &str) -> &'static str {
+    Box::leak(value.to_owned().into_boxed_str())
+}
+
+fn usage(message: String) -> UsageError {
+    UsageError(message)
+}
+
+fn invalid(name: &str, value: &str, expected: &str) -> UsageError {
+    UsageError(format!(
+        "invalid value '{value}' for '--{name}': {expected}"
+    ))
+}
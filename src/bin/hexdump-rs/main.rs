@@ -0,0 +1,500 @@
+//! `hexdump-rs`: the library's dumps, presets and `xxd` modes on the
+//! command line. Run `hexdump-rs --help` for the options.
+
+mod args;
+#[path = "../shared/parse.rs"]
+mod parse;
+
+use std::fs::{File, OpenOptions};
+use std::io::{self, BufWriter, Read, Seek as _, SeekFrom, Write};
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+
+use hexdump::codegen::{Codegen, Language};
+use hexdump::html::HtmlPage;
+use hexdump::ihex::{self, IhexFile, StartAddress};
+use hexdump::image::Image;
+use hexdump::json::JsonLines;
+use hexdump::search::SearchDumper;
+use hexdump::srec::{self, SrecFile};
+use hexdump::strings::Strings;
+use hexdump::undump::Undumper;
+use hexdump::xxd::{self, Xxd, XxdMode};
+use hexdump::HexDumper;
+
+use args::{Command, Format, Mode, Options, Seek};
+
+fn main() -> ExitCode {
+    let options = match args::parse(std::env::args_os().skip(1)) {
+        Ok(Command::Run(options)) => options,
+        Ok(Command::Help) => {
+            print!("{}", args::HELP);
+            return ExitCode::SUCCESS;
+        }
+        Ok(Command::Version) => {
+            println!("hexdump-rs {}", env!("CARGO_PKG_VERSION"));
+            return ExitCode::SUCCESS;
+        }
+        Err(err) => {
+            eprintln!("hexdump-rs: {err}\nTry 'hexdump-rs --help' for more information.");
+            return ExitCode::from(2);
+        }
+    };
+    match run(&options) {
+        Ok(()) => ExitCode::SUCCESS,
+        // Output cut short by `head` and the like is not an error.
+        Err(err) if err.kind() == io::ErrorKind::BrokenPipe => ExitCode::SUCCESS,
+        Err(err) => {
+            eprintln!("hexdump-rs: {err}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn run(options: &Options) -> io::Result<()> {
+    let mut input = Input::open(options.input.as_deref())?;
+    let pos = input.seek(options.seek)?;
+    let (dumper, output): (HexDumper, Box<dyn Write>) = match &options.output {
+        Some(path) => {
+            let file = OutFile::open(path).map_err(|err| in_file(err, path))?;
+            (options.dumper.clone().color_for(&file.file), Box::new(file))
+        }
+        None => {
+            let stdout = io::stdout();
+            (
+                options.dumper.clone().color_for(&stdout),
+                Box::new(stdout.lock()),
+            )
+        }
+    };
+    let mut output = BufWriter::new(output);
+    let length = options.length.unwrap_or(u64::MAX);
+
+    match options.mode {
+        Mode::Dump if options.search.is_some() => {
+            let base = options.base_address.unwrap_or(0).wrapping_add(pos);
+            let pattern = options.search.clone().expect("checked by the guard");
+            let mut search = SearchDumper::new(pattern).dumper(dumper.base_address(base));
+            if let Some(lines) = options.context {
+                search = search.context(lines);
+            }
+            search.dump_reader(input.take(length), &mut output)?;
+        }
+        Mode::Dump if options.from.is_some() => {
+            let firmware = Firmware::read(&mut input, options)?;
+            let mut text = String::new();
+            dumper
+                .write_image(&mut text, &firmware.image)
+                .expect("writing to a String cannot fail");
+            output.write_all(text.as_bytes())?;
+        }
+        Mode::Dump => {
+            #[cfg(feature = "mmap")]
+            if let Input::File {
+                path, len: Some(_), ..
+            } = &input
+            {
+                use std::ops::Bound;
+
+                let end = match options.length {
+                    Some(len) => Bound::Excluded(pos.saturating_add(len)),
+                    None => Bound::Unbounded,
+                };
+                let base = options.base_address.or((pos > 0).then_some(0));
+                let dumper = match base {
+                    Some(base) => dumper.base_address(base),
+                    None => dumper,
+                };
+                dumper
+                    .dump_file(path, (Bound::Included(pos), end), &mut output)
+                    .map_err(|err| in_file(err, path))?;
+                return output.flush();
+            }
+            let base = match options.base_address {
+                Some(base) => Some(base.wrapping_add(pos)),
+                None => (pos > 0).then_some(pos),
+            };
+            let dumper = match base {
+                Some(base) => dumper.base_address(base),
+                None => dumper,
+            };
+            dumper.dump_reader(input.take(length), &mut output)?;
+        }
+        Mode::Strings => {
+            let base = options.base_address.unwrap_or(0).wrapping_add(pos);
+            let mut strings = Strings::new()
+                .dumper(dumper.base_address(base))
+                .lines(options.show_lines);
+            if let Some(n) = options.min_len {
+                strings = strings.min_len(n);
+            }
+            if let Some(encodings) = &options.encodings {
+                strings = strings.encodings(encodings);
+            }
+            strings.report(input.take(length), &mut output)?;
+        }
+        Mode::Codegen(language) => {
+            let mut data = Vec::new();
+            let name = input.name();
+            input
+                .take(length)
+                .read_to_end(&mut data)
+                .map_err(|err| in_file(err, &name))?;
+            let mut codegen = Codegen::new(language)
+                .uppercase(options.uppercase)
+                .len_constant(options.len_constant)
+                .comments(options.comments);
+            if let Some(columns) = options.columns {
+                codegen = codegen.bytes_per_line(columns);
+            }
+            // Rust wants its statics in upper case.
+            let rust = matches!(language, Language::Rust | Language::RustSlice);
+            let name = match (&options.name, &options.input) {
+                (Some(name), _) => Some(name.clone()),
+                (None, Some(path)) if rust => {
+                    Some(xxd::c_identifier(&path.to_string_lossy()).to_ascii_uppercase())
+                }
+                (None, Some(path)) => Some(xxd::c_identifier(&path.to_string_lossy())),
+                (None, None) => None,
+            };
+            if let Some(name) = name {
+                codegen = codegen.name(if options.capitalize {
+                    name.to_ascii_uppercase()
+                } else {
+                    name
+                });
+            }
+            if let Some(len_name) = &options.len_name {
+                codegen = codegen.len_name(len_name);
+            }
+            codegen.write_to(&data, &mut output)?;
+        }
+        Mode::Convert(format) => {
+            let firmware = match options.from {
+                Some(_) => Firmware::read(&mut input, options)?,
+                None => {
+                    let mut data = Vec::new();
+                    let name = input.name();
+                    input
+                        .take(length)
+                        .read_to_end(&mut data)
+                        .map_err(|err| in_file(err, &name))?;
+                    let base = options.base_address.unwrap_or(0).wrapping_add(pos);
+                    Firmware {
+                        image: Image::from_bytes(base, data),
+                        start: None,
+                        header: file_name(options),
+                    }
+                }
+            };
+            let too_large = |err| io::Error::new(io::ErrorKind::InvalidInput, err);
+            match format {
+                Format::Raw => {
+                    output.write_all(&firmware.image.to_bytes(options.gap_fill.unwrap_or(0xff)))?
+                }
+                Format::Ihex => {
+                    let mut encoder = ihex::Encoder::new();
+                    if let Some(columns) = options.columns {
+                        encoder = encoder.record_len(columns);
+                    }
+                    let file = IhexFile {
+                        image: firmware.image,
+                        start: firmware.start,
+                    };
+                    output.write_all(encoder.encode(&file).map_err(too_large)?.as_bytes())?;
+                }
+                Format::Srec => {
+                    let mut encoder = srec::Encoder::new();
+                    if let Some(columns) = options.columns {
+                        encoder = encoder.record_len(columns);
+                    }
+                    let file = SrecFile {
+                        header: firmware.header,
+                        image: firmware.image,
+                        start: firmware.start.map(StartAddress::linear),
+                    };
+                    output.write_all(encoder.encode(&file).map_err(too_large)?.as_bytes())?;
+                }
+            }
+        }
+        Mode::Json => {
+            let base = options.base_address.unwrap_or(0).wrapping_add(pos);
+            JsonLines::new()
+                .dumper(dumper.base_address(base))
+                .annotations(options.annotations.iter().cloned())
+                .dump_reader(input.take(length), &mut output)?;
+        }
+        Mode::Html => {
+            let mut data = Vec::new();
+            let name = input.name();
+            input
+                .take(length)
+                .read_to_end(&mut data)
+                .map_err(|err| in_file(err, &name))?;
+            let base = options.base_address.unwrap_or(0).wrapping_add(pos);
+            HtmlPage::new()
+                .title(name.to_string_lossy())
+                .dumper(dumper.base_address(base))
+                .annotations(options.annotations.iter().cloned())
+                .write_to(&data, &mut output)?;
+        }
+        #[cfg(feature = "visualize")]
+        Mode::Visualize(colors) => {
+            use hexdump::visualize::{Layout, Visualizer};
+
+            let mut data = Vec::new();
+            let name = input.name();
+            input
+                .take(length)
+                .read_to_end(&mut data)
+                .map_err(|err| in_file(err, &name))?;
+            let mut visualizer = Visualizer::new().colors(colors);
+            if options.hilbert {
+                visualizer = visualizer.layout(Layout::Hilbert);
+            }
+            if let Some(columns) = options.columns {
+                visualizer = visualizer.width(u32::try_from(columns).unwrap_or(u32::MAX));
+            }
+            if let Some(n) = options.pixel_bytes {
+                visualizer = visualizer.bytes_per_pixel(n);
+            }
+            visualizer.write_png(&data, &mut output)?;
+        }
+        Mode::SingleLine => {
+            let xxd = Xxd::new()
+                .mode(XxdMode::Plain)
+                .columns(0)
+                .uppercase(options.uppercase);
+            xxd.dump_reader(input.take(length), &mut output)?;
+        }
+        Mode::Include => {
+            let mut xxd = Xxd::new()
+                .mode(XxdMode::Include)
+                .uppercase(options.uppercase)
+                .capitalize(options.capitalize);
+            if let Some(columns) = options.columns {
+                xxd = xxd.columns(columns);
+            }
+            let name = match (&options.name, &options.input) {
+                (Some(name), _) => Some(name.clone()),
+                (None, Some(path)) => Some(xxd::c_identifier(&path.to_string_lossy())),
+                (None, None) => None,
+            };
+            if let Some(name) = name {
+                xxd = xxd.name(name);
+            }
+            xxd.dump_reader(input.take(length), &mut output)?;
+        }
+        Mode::Reverse => {
+            let mut data = Vec::new();
+            let name = input.name();
+            input
+                .take(length)
+                .read_to_end(&mut data)
+                .map_err(|err| in_file(err, &name))?;
+            // Gutters of dumps made by other tools may hold raw bytes.
+            let text = String::from_utf8_lossy(&data);
+            let mut undumper = Undumper::new().plain(options.plain);
+            if let Some(base) = options.offset_base {
+                undumper = undumper.offset_base(base);
+            }
+            if let Some(origin) = options.base_address {
+                undumper = undumper.origin(origin);
+            }
+            let bytes = undumper
+                .parse(&text)
+                .map_err(|err| in_file(io::Error::new(io::ErrorKind::InvalidData, err), &name))?;
+            output.write_all(&bytes)?;
+        }
+    }
+    // Also truncates an output file that nothing was written to.
+    output.flush()
+}
+
+/// The contents of an Intel HEX or S-record file, in terms both can write.
+struct Firmware {
+    image: Image,
+    start: Option<StartAddress>,
+    /// The S0 header, or the input's file name for other inputs.
+    header: Vec<u8>,
+}
+
+impl Firmware {
+    /// Reads the input in the format given by `--from`.
+    fn read(input: &mut Input, options: &Options) -> io::Result<Firmware> {
+        let mut text = String::new();
+        let name = input.name();
+        input
+            .read_to_string(&mut text)
+            .map_err(|err| in_file(err, &name))?;
+        let invalid = |err: &dyn std::fmt::Display| {
+            in_file(
+                io::Error::new(io::ErrorKind::InvalidData, err.to_string()),
+                &name,
+            )
+        };
+        match options.from {
+            Some(Format::Srec) => {
+                let file = srec::parse(&text).map_err(|err| invalid(&err))?;
+                Ok(Firmware {
+                    image: file.image,
+                    start: file.start.map(StartAddress::Linear),
+                    header: file.header,
+                })
+            }
+            _ => {
+                let file = ihex::parse(&text).map_err(|err| invalid(&err))?;
+                Ok(Firmware {
+                    image: file.image,
+                    start: file.start,
+                    header: file_name(options),
+                })
+            }
+        }
+    }
+}
+
+/// The name of the input file, without its directory, as S0 headers
+/// usually hold it.
+fn file_name(options: &Options) -> Vec<u8> {
+    let name = options.input.as_deref().and_then(Path::file_name);
+    name.map(|name| name.to_string_lossy().into_owned().into_bytes())
+        .unwrap_or_default()
+}
+
+/// The output file. It is opened without truncating it, and truncated
+/// just before the first write, so a run that fails before writing
+/// anything, such as reversing a malformed dump, leaves the file as it was.
+/// A run writing nothing truncates it when the output is flushed.
+struct OutFile {
+    file: File,
+    /// Whether the file still has to be truncated; never for devices.
+    truncate: bool,
+}
+
+impl OutFile {
+    fn open(path: &Path) -> io::Result<OutFile> {
+        let file = OpenOptions::new()
+            .write(true)
+            .create(true)
+            .truncate(false)
+            .open(path)?;
+        let truncate = file.metadata()?.is_file();
+        Ok(OutFile { file, truncate })
+    }
+
+    fn truncate(&mut self) -> io::Result<()> {
+        if std::mem::take(&mut self.truncate) {
+            self.file.set_len(0)?;
+        }
+        Ok(())
+    }
+}
+
+impl Write for OutFile {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.truncate()?;
+        self.file.write(buf)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.truncate()?;
+        self.file.flush()
+    }
+}
+
+/// Where the bytes come from.
+enum Input {
+    Stdin(io::Stdin),
+    File {
+        file: File,
+        path: PathBuf,
+        /// The length of a regular file; `None` for devices and pipes.
+        len: Option<u64>,
+    },
+}
+
+impl Input {
+    fn open(path: Option<&Path>) -> io::Result<Input> {
+        let Some(path) = path else {
+            return Ok(Input::Stdin(io::stdin()));
+        };
+        let file = File::open(path).map_err(|err| in_file(err, path))?;
+        let metadata = file.metadata().map_err(|err| in_file(err, path))?;
+        Ok(Input::File {
+            file,
+            path: path.to_owned(),
+            len: metadata.is_file().then_some(metadata.len()),
+        })
+    }
+
+    /// A name for the input in error messages.
+    fn name(&self) -> PathBuf {
+        match self {
+            Input::Stdin(_) => PathBuf::from("standard input"),
+            Input::File { path, .. } => path.clone(),
+        }
+    }
+
+    /// Moves to where the dump starts and returns its offset in the input.
+    fn seek(&mut self, seek: Seek) -> io::Result<u64> {
+        let past_end = |path: &Path, len| {
+            let err = io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!("seek offset is outside the file ({len} bytes)"),
+            );
+            in_file(err, path)
+        };
+        match (self, seek) {
+            (_, Seek::Start(0)) => Ok(0),
+            (
+                Input::File {
+                    len: Some(len),
+                    path,
+                    ..
+                },
+                Seek::Start(n) | Seek::End(n),
+            ) if n > *len => Err(past_end(path, *len)),
+            (
+                Input::File {
+                    file,
+                    len: Some(len),
+                    ..
+                },
+                Seek::End(n),
+            ) => file.seek(SeekFrom::Start(*len - n)),
+            (Input::File { file, path, .. }, Seek::Start(n)) => match file.seek(SeekFrom::Start(n))
+            {
+                // Devices may accept the seek without reporting a position.
+                Ok(_) => Ok(n),
+                // Pipes and terminals cannot seek; read past the bytes instead.
+                Err(_) => skip(file, n).map_err(|err| in_file(err, path)),
+            },
+            (Input::Stdin(stdin), Seek::Start(n)) => skip(stdin.lock(), n),
+            (input, Seek::End(_)) => Err(in_file(
+                io::Error::new(io::ErrorKind::InvalidInput, "cannot seek from the end"),
+                &input.name(),
+            )),
+        }
+    }
+}
+
+impl Read for Input {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        match self {
+            Input::Stdin(stdin) => stdin.read(buf),
+            Input::File { file, .. } => file.read(buf),
+        }
+    }
+}
+
+/// Reads and discards `n` bytes, or up to the end of the input.
+fn skip<R: Read>(mut reader: R, n: u64) -> io::Result<u64> {
+    io::copy(&mut (&mut reader).take(n), &mut io::sink())?;
+    Ok(n)
+}
+
+/// Prefixes an error with the file it concerns.
+fn in_file(err: io::Error, path: &Path) -> io::Error {
+    io::Error::new(err.kind(), format!("{}: {err}", path.display()))
+}
@@ -0,0 +1,151 @@
+//! `hexdump-tui`: a full-screen hex viewer and editor. Run `hexdump-tui --help` for
+//! the options.
+
+#[path = "shared/parse.rs"]
+mod parse;
+
+use std::fmt;
+use std::io::{self, IsTerminal, Read};
+use std::process::ExitCode;
+
+use hexdump::color::ColorDepth;
+use hexdump::tui::Viewer;
+use hexdump::{HexDumper, Preset};
+
+const HELP: &str = "\
+Usage: hexdump-tui [OPTIONS] [FILE]
+
+Shows FILE, or standard input if it is missing or '-', in a scrollable hex
+view. Press q to quit; the status bar lists the other keys.
+
+With --edit, hex digits and, after Tab, text overwrite the bytes under the
+cursor, or are inserted after pressing i. Press u to undo, U to redo and w
+to save. Saving replaces the file atomically, and is refused if the file
+changed on disk since it was opened.
+
+Options:
+  -C, --canonical          hexdump -C layout
+      --preset NAME        xxd, xxd-plain, xxd-bits, hexdump, od-hex,
+                           od-decimal or od-octal
+  -c, --cols N             bytes per line, at most 4096, narrowed to fit the
+                           terminal
+  -g, --groupsize N        bytes per group
+  -s, --seek OFFSET        start with the cursor at OFFSET
+  -e, --edit               allow editing
+  -h, --help               print this help
+  -V, --version            print the version
+";
+
+struct Options {
+    dumper: HexDumper,
+    seek: u64,
+    edit: bool,
+    path: Option<String>,
+}
+
+fn main() -> ExitCode {
+    let options = match parse(std::env::args().skip(1)) {
+        Ok(Some(options)) => options,
+        Ok(None) => return ExitCode::SUCCESS,
+        Err(err) => {
+            eprintln!("hexdump-tui: {err}\nTry 'hexdump-tui --help' for more information.");
+            return ExitCode::from(2);
+        }
+    };
+    match run(options) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(err) => {
+            eprintln!("hexdump-tui: {err}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn run(options: Options) -> io::Result<()> {
+    if !io::stdout().is_terminal() {
+        return Err(io::Error::other("standard output is not a terminal"));
+    }
+    let viewer = match &options.path {
+        Some(path) => Viewer::open(path)
+            .map_err(|err| io::Error::new(err.kind(), format!("{path}: {err}")))?,
+        None => {
+            let mut bytes = Vec::new();
+            io::stdin().read_to_end(&mut bytes)?;
+            Viewer::new(bytes, "standard input")
+        }
+    };
+    let mut viewer = viewer
+        .dumper(options.dumper.color_depth(ColorDepth::detect()))
+        .editable(options.edit);
+    viewer.goto(options.seek);
+    viewer.run()
+}
+
+/// Parses the arguments. Returns `None` when help or the version was
+/// printed instead.
+fn parse<I: Iterator<Item = String>>(mut args: I) -> Result<Option<Options>, String> {
+    let mut dumper = HexDumper::new();
+    let mut columns = None;
+    let mut group_size = None;
+    let mut seek = 0;
+    let mut edit = false;
+    let mut path = None;
+    while let Some(arg) = args.next() {
+        let (name, inline) = match arg.split_once('=') {
+            Some((name, value)) if name.starts_with("--") => {
+                (name.to_owned(), Some(value.to_owned()))
+            }
+            _ => (arg.clone(), None),
+        };
+        let mut value = |name: &str| {
+            inline
+                .clone()
+                .or_else(|| args.next())
+                .ok_or_else(|| format!("'{name}' needs a value"))
+        };
+        match name.as_str() {
+            "-h" | "--help" => {
+                print!("{HELP}");
+                return Ok(None);
+            }
+            "-V" | "--version" => {
+                println!("hexdump-tui {}", env!("CARGO_PKG_VERSION"));
+                return Ok(None);
+            }
+            "-C" | "--canonical" => dumper = dumper.preset(Preset::HexdumpCanonical),
+            "--preset" => dumper = dumper.preset(parsed(&name, &value(&name)?, str::parse)?),
+            "-c" | "--cols" => columns = Some(parsed(&name, &value(&name)?, parse::columns)?),
+            "-g" | "--groupsize" => group_size = Some(parsed(&name, &value(&name)?, parse::size)?),
+            "-s" | "--seek" => seek = parsed(&name, &value(&name)?, parse::number)?,
+            "-e" | "--edit" => edit = true,
+            "-" => path = None,
+            _ if name.starts_with('-') => return Err(format!("unknown option '{name}'")),
+            _ if path.is_some() => return Err("too many file arguments".to_owned()),
+            _ => path = Some(arg),
+        }
+    }
+    if let Some(n) = columns {
+        if n == 0 {
+            return Err("'--cols' must be at least 1".to_owned());
+        }
+        dumper = dumper.bytes_per_line(n);
+    }
+    if let Some(n) = group_size {
+        dumper = dumper.group_size(n);
+    }
+    Ok(Some(Options {
+        dumper,
+        seek,
+        edit,
+        path,
+    }))
+}
+
+/// Parses the value of option `name` with `parse`.
+fn parsed<T, E: fmt::Display>(
+    name: &str,
+    value: &str,
+    parse: impl FnOnce(&str) -> Result<T, E>,
+) -> Result<T, String> {
+    parse(value).map_err(|err| format!("invalid value '{value}' for '{name}': {err}"))
+}
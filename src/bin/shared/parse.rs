@@ -0,0 +1,41 @@
+//! Argument parsing shared by the binaries.
+
+/// Most bytes per line `-c` accepts. Lines are padded to their full
+/// width, so a huge value would print mostly spaces, or hang.
+pub const MAX_COLUMNS: usize = 4096;
+
+/// Parses a number with an optional `0x`, `0o` or `0b` prefix and `k`, `m`
+/// or `g` suffix. The error says what was expected instead.
+pub fn number(value: &str) -> Result<u64, &'static str> {
+    const EXPECTED: &str = "expected a number";
+    let (digits, shift) = match value.as_bytes().last() {
+        Some(b'k' | b'K') => (&value[..value.len() - 1], 10),
+        Some(b'm' | b'M') => (&value[..value.len() - 1], 20),
+        Some(b'g' | b'G') => (&value[..value.len() - 1], 30),
+        _ => (value, 0),
+    };
+    let (digits, radix) = match digits.get(..2) {
+        Some("0x" | "0X") => (&digits[2..], 16),
+        Some("0o" | "0O") => (&digits[2..], 8),
+        Some("0b" | "0B") => (&digits[2..], 2),
+        _ => (digits, 10),
+    };
+    if digits.starts_with(['+', '-']) {
+        return Err(EXPECTED);
+    }
+    let n = u64::from_str_radix(digits, radix).map_err(|_| EXPECTED)?;
+    n.checked_mul(1 << shift).ok_or(EXPECTED)
+}
+
+/// Parses a [`number`] that must fit in a `usize`.
+pub fn size(value: &str) -> Result<usize, &'static str> {
+    usize::try_from(number(value)?).map_err(|_| "number too large")
+}
+
+/// Parses a `-c` value: a [`size`] of at most [`MAX_COLUMNS`].
+pub fn columns(value: &str) -> Result<usize, String> {
+    match size(value)? {
+        n if n > MAX_COLUMNS => Err(format!("at most {MAX_COLUMNS} allowed")),
+        n => Ok(n),
+    }
+}
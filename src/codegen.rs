@@ -0,0 +1,261 @@
+//! Source code embedding a buffer, like `xxd -i` for several languages.
+//!
+//! [`Codegen`] writes a byte array declaration in the chosen [`Language`],
+//! followed by a constant holding its length, ready to paste or include
+//! without fixing up by hand:
+//!
+//! ```
+//! use hexdump::codegen::{Codegen, Language};
+//!
+//! let code = Codegen::new(Language::Rust).name("MAGIC").generate(b"\x7fELF");
+//! assert_eq!(
+//!     code,
+//!     "pub static MAGIC: [u8; 4] = [\n    0x7f, 0x45, 0x4c, 0x46,\n];\n\
+//!      pub const MAGIC_LEN: usize = 4;\n",
+//! );
+//! ```
+//!
+//! With [`comments`](Codegen::comments) every line ends with the offset
+//! and ASCII column of its bytes, as a dump would show them:
+//!
+//! ```text
+//! static const uint8_t boot[] = {
+//!     0x48, 0x65, 0x6c, 0x6c, 0x6f, 0x2c, 0x20, 0x77, // 00000000: Hello, w
+//!     0x6f, 0x72, 0x6c, 0x64, 0x21, 0x0a,             // 00000008: orld!.
+//! };
+//! static const size_t boot_len = 14;
+//! ```
+
+use std::fmt::{self, Write as _};
+use std::io::{self, Write};
+
+use crate::{is_printable, STRING_WRITE};
+
+/// The language of the generated declarations.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum Language {
+    /// `static const uint8_t name[] = { ... };` and a `size_t` length. The
+    /// file needs `<stdint.h>` and `<stddef.h>`.
+    C,
+    /// `pub static NAME: [u8; N] = [ ... ];` and a `usize` length.
+    Rust,
+    /// `pub static NAME: &[u8] = &[ ... ];` and a `usize` length.
+    RustSlice,
+    /// `name = (b"..." ...)`, one `bytes` literal per line, and a length.
+    Python,
+    /// `var name = []byte{ ... }` and an untyped length constant.
+    Go,
+    /// `pub const name = [_]u8{ ... };` and a `usize` length.
+    Zig,
+}
+
+impl Language {
+    /// The start of a line comment, after a space. PEP 8 wants two spaces
+    /// before Python's.
+    fn comment(self) -> &'static str {
+        match self {
+            Language::Python => " #",
+            _ => "//",
+        }
+    }
+
+    /// The indentation of the array's lines, as each language's formatter
+    /// would leave it.
+    fn indent(self) -> &'static str {
+        match self {
+            Language::Go => "\t",
+            _ => "    ",
+        }
+    }
+
+    /// The name used when none is given.
+    fn default_name(self) -> &'static str {
+        match self {
+            Language::Rust | Language::RustSlice => "DATA",
+            _ => "data",
+        }
+    }
+}
+
+/// Configurable source code generator.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Codegen {
+    language: Language,
+    name: Option<String>,
+    len_name: Option<String>,
+    bytes_per_line: usize,
+    uppercase: bool,
+    len_constant: bool,
+    comments: bool,
+}
+
+impl Codegen {
+    /// Creates a generator for `language` with twelve bytes per line, the
+    /// `xxd -i` default, and a length constant.
+    pub fn new(language: Language) -> Self {
+        Codegen {
+            language,
+            name: None,
+            len_name: None,
+            bytes_per_line: 12,
+            uppercase: false,
+            len_constant: true,
+            comments: false,
+        }
+    }
+
+    /// Sets the name of the array, used as given. It defaults to `DATA` in
+    /// Rust and `data` elsewhere; [`c_identifier`](crate::xxd::c_identifier)
+    /// turns a file name into one.
+    pub fn name(mut self, name: impl Into<String>) -> Self {
+        self.name = Some(name.into());
+        self
+    }
+
+    /// Sets the name of the length constant. It defaults to the array's name
+    /// followed by `Len` in Go, `_LEN` when the name has no lower case
+    /// letters and `_len` otherwise.
+    pub fn len_name(mut self, name: impl Into<String>) -> Self {
+        self.len_name = Some(name.into());
+        self
+    }
+
+    /// Sets the number of bytes on each line. Zero counts as one.
+    pub fn bytes_per_line(mut self, n: usize) -> Self {
+        self.bytes_per_line = n.max(1);
+        self
+    }
+
+    /// Upper case hex digits.
+    pub fn uppercase(mut self, upper: bool) -> Self {
+        self.uppercase = upper;
+        self
+    }
+
+    /// Whether to declare the length constant after the array.
+    pub fn len_constant(mut self, declare: bool) -> Self {
+        self.len_constant = declare;
+        self
+    }
+
+    /// Ends each line with a comment showing the offset and ASCII column of
+    /// its bytes.
+    pub fn comments(mut self, comments: bool) -> Self {
+        self.comments = comments;
+        self
+    }
+
+    /// Returns the declarations for `data`.
+    pub fn generate(&self, data: &[u8]) -> String {
+        let mut out = String::new();
+        self.write_code(&mut out, data).expect(STRING_WRITE);
+        out
+    }
+
+    /// Writes the declarations for `data` to `writer`.
+    pub fn write_to<W: Write>(&self, data: &[u8], mut writer: W) -> io::Result<()> {
+        writer.write_all(self.generate(data).as_bytes())?;
+        writer.flush()
+    }
+
+    fn write_code(&self, out: &mut String, data: &[u8]) -> fmt::Result {
+        let name = self.name.as_deref().unwrap_or(self.language.default_name());
+        let len = data.len();
+        match self.language {
+            Language::C => writeln!(out, "static const uint8_t {name}[] = {{")?,
+            Language::Rust => writeln!(out, "pub static {name}: [u8; {len}] = [")?,
+            Language::RustSlice => writeln!(out, "pub static {name}: &[u8] = &[")?,
+            Language::Python if data.is_empty() => writeln!(out, "{name} = b\"\"")?,
+            Language::Python => writeln!(out, "{name} = (")?,
+            Language::Go => writeln!(out, "var {name} = []byte{{")?,
+            Language::Zig => writeln!(out, "pub const {name} = [_]u8{{")?,
+        }
+        let width = self.bytes_per_line.min(len.max(1));
+        for (i, line) in data.chunks(self.bytes_per_line).enumerate() {
+            let start = out.len();
+            out.push_str(self.language.indent());
+            self.write_bytes(out, line)?;
+            if self.comments {
+                // Pad short lines so that the comments line up.
+                let full = self.language.indent().len() + self.line_len(width);
+                let written = out.len() - start;
+                write!(out, "{:pad$} ", "", pad = full.saturating_sub(written))?;
+                self.write_comment(out, i * self.bytes_per_line, line)?;
+            }
+            out.push('\n');
+        }
+        match self.language {
+            Language::C | Language::Zig => out.push_str("};\n"),
+            Language::Rust | Language::RustSlice => out.push_str("];\n"),
+            Language::Python if data.is_empty() => {}
+            Language::Python => out.push_str(")\n"),
+            Language::Go => out.push_str("}\n"),
+        }
+        if !self.len_constant {
+            return Ok(());
+        }
+        let len_name = match &self.len_name {
+            Some(len_name) => len_name.clone(),
+            None if self.language == Language::Go => format!("{name}Len"),
+            None if name.chars().any(|c| c.is_lowercase()) => format!("{name}_len"),
+            None => format!("{name}_LEN"),
+        };
+        match self.language {
+            Language::C => writeln!(out, "static const size_t {len_name} = {len};"),
+            Language::Rust | Language::RustSlice => {
+                writeln!(out, "pub const {len_name}: usize = {len};")
+            }
+            Language::Python => writeln!(out, "{len_name} = {len}"),
+            Language::Go => writeln!(out, "\nconst {len_name} = {len}"),
+            Language::Zig => writeln!(out, "pub const {len_name}: usize = {len};"),
+        }
+    }
+
+    /// Writes one line's bytes: `0x48, 0x65,` or `b"\x48\x65"`.
+    fn write_bytes(&self, out: &mut String, line: &[u8]) -> fmt::Result {
+        if self.language == Language::Python {
+            out.push_str("b\"");
+            for b in line {
+                self.write_byte(out, "\\x", *b)?;
+            }
+            out.push('"');
+            return Ok(());
+        }
+        for (i, b) in line.iter().enumerate() {
+            if i > 0 {
+                out.push(' ');
+            }
+            self.write_byte(out, "0x", *b)?;
+            out.push(',');
+        }
+        Ok(())
+    }
+
+    fn write_byte(&self, out: &mut String, prefix: &str, b: u8) -> fmt::Result {
+        if self.uppercase {
+            write!(out, "{prefix}{b:02X}")
+        } else {
+            write!(out, "{prefix}{b:02x}")
+        }
+    }
+
+    /// The length of a line of `n` bytes, without the indentation.
+    fn line_len(&self, n: usize) -> usize {
+        match self.language {
+            Language::Python => 3 + 4 * n,
+            _ => 6 * n - 1,
+        }
+    }
+
+    fn write_comment(&self, out: &mut String, offset: usize, line: &[u8]) -> fmt::Result {
+        write!(out, "{} {offset:08x}: ", self.language.comment())?;
+        for &b in line {
+            // A backslash at the end of a C line comment would continue it
+            // onto the next line.
+            let shown = is_printable(b) && !(b == b'\\' && self.language == Language::C);
+            out.push(if shown { b as char } else { '.' });
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,559 @@
+//! ANSI colouring of bytes by class.
+//!
+//! Every byte falls into one [`ByteClass`], and a [`Theme`] assigns a
+//! [`Style`] to each class. Colour is off unless asked for: set
+//! [`ColorChoice::Always`] to force it, or [`ColorChoice::Auto`] and resolve
+//! it against the output stream with [`HexDumper::color_for`], which only
+//! turns colour on for a terminal and honours `NO_COLOR`. An unresolved
+//! `Auto` never writes escape codes, so dumps written to files stay clean.
+//!
+//! ```
+//! # #[cfg(feature = "std")] {
+//! use hexdump::color::{Color, ColorChoice, Style, Theme};
+//! use hexdump::HexDumper;
+//!
+//! let theme = Theme {
+//!     null: Style::new().fg(Color::Rgb(90, 90, 90)),
+//!     ..Theme::default()
+//! };
+//! let dumper = HexDumper::new()
+//!     .theme(theme)
+//!     .color(ColorChoice::Auto)
+//!     .color_for(&std::io::stdout());
+//! print!("{}", dumper.dump(b"\x00\x01 AB\xff"));
+//! # }
+//! ```
+
+use core::fmt::{self, Write};
+#[cfg(feature = "std")]
+use std::io::IsTerminal;
+
+use crate::HexDumper;
+
+/// The classes bytes are coloured by.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ByteClass {
+    /// `0x00`.
+    Null,
+    /// Printable ASCII other than the space.
+    Printable,
+    /// ASCII whitespace: space, `\t`, `\n`, `\v`, `\f` and `\r`.
+    Whitespace,
+    /// Other ASCII control characters, including DEL.
+    Control,
+    /// `0x80` to `0xfe`.
+    HighBit,
+    /// `0xff`.
+    Ff,
+}
+
+impl ByteClass {
+    /// Returns the class of `b`.
+    pub const fn of(b: u8) -> Self {
+        match b {
+            0x00 => ByteClass::Null,
+            b' ' | 0x09..=0x0d => ByteClass::Whitespace,
+            0x21..=0x7e => ByteClass::Printable,
+            0x01..=0x1f | 0x7f => ByteClass::Control,
+            0xff => ByteClass::Ff,
+            _ => ByteClass::HighBit,
+        }
+    }
+}
+
+/// A terminal colour.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Color {
+    /// One of the 16 basic colours: 0 to 7 are black, red, green, yellow,
+    /// blue, magenta, cyan and white, 8 to 15 their bright variants.
+    Ansi(u8),
+    /// An index into the 256 colour palette.
+    Ansi256(u8),
+    /// A 24-bit colour.
+    Rgb(u8, u8, u8),
+}
+
+impl Color {
+    /// Basic colour 0, black.
+    pub const BLACK: Color = Color::Ansi(0);
+    /// Basic colour 1, red.
+    pub const RED: Color = Color::Ansi(1);
+    /// Basic colour 2, green.
+    pub const GREEN: Color = Color::Ansi(2);
+    /// Basic colour 3, yellow.
+    pub const YELLOW: Color = Color::Ansi(3);
+    /// Basic colour 4, blue.
+    pub const BLUE: Color = Color::Ansi(4);
+    /// Basic colour 5, magenta.
+    pub const MAGENTA: Color = Color::Ansi(5);
+    /// Basic colour 6, cyan.
+    pub const CYAN: Color = Color::Ansi(6);
+    /// Basic colour 7, white, which most terminals show as light grey.
+    pub const WHITE: Color = Color::Ansi(7);
+    /// Basic colour 8, bright black, which most terminals show as dark grey.
+    pub const BRIGHT_BLACK: Color = Color::Ansi(8);
+
+    /// Converts the colour to one that `depth` can show.
+    pub fn to_depth(self, depth: ColorDepth) -> Color {
+        match (self, depth) {
+            (Color::Rgb(r, g, b), ColorDepth::Ansi256) => Color::Ansi256(rgb_to_256(r, g, b)),
+            (Color::Rgb(r, g, b), ColorDepth::Ansi16) => Color::Ansi(rgb_to_16(r, g, b)),
+            (Color::Ansi256(n), ColorDepth::Ansi16) if n >= 16 => {
+                let (r, g, b) = ansi256_to_rgb(n);
+                Color::Ansi(rgb_to_16(r, g, b))
+            }
+            (Color::Ansi256(n), ColorDepth::Ansi16) => Color::Ansi(n),
+            (color, _) => color,
+        }
+    }
+
+    /// The colour's red, green and blue values, taking the basic colours to
+    /// be the usual xterm ones.
+    pub fn to_rgb(self) -> (u8, u8, u8) {
+        match self {
+            Color::Ansi(n) => BASIC[usize::from(n % 16)],
+            Color::Ansi256(n) => ansi256_to_rgb(n),
+            Color::Rgb(r, g, b) => (r, g, b),
+        }
+    }
+
+    fn write_sgr<W: Write>(self, out: &mut W, background: bool) -> fmt::Result {
+        match self {
+            Color::Ansi(n) => {
+                let n = u16::from(n % 16);
+                let base = if n < 8 { 30 } else { 90 - 8 };
+                write!(out, "{}", base + n + if background { 10 } else { 0 })
+            }
+            Color::Ansi256(n) => write!(out, "{};5;{n}", if background { 48 } else { 38 }),
+            Color::Rgb(r, g, b) => {
+                write!(out, "{};2;{r};{g};{b}", if background { 48 } else { 38 })
+            }
+        }
+    }
+}
+
+/// How many colours the terminal can show.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum ColorDepth {
+    /// The 16 basic colours.
+    Ansi16,
+    /// The 256 colour palette.
+    Ansi256,
+    /// 24-bit colour.
+    #[default]
+    TrueColor,
+}
+
+#[cfg(feature = "std")]
+impl ColorDepth {
+    /// Guesses the depth from the `COLORTERM` and `TERM` environment
+    /// variables.
+    pub fn detect() -> Self {
+        let colorterm = std::env::var("COLORTERM").unwrap_or_default();
+        let term = std::env::var("TERM").unwrap_or_default();
+        if colorterm == "truecolor" || colorterm == "24bit" {
+            ColorDepth::TrueColor
+        } else if term.contains("256") {
+            ColorDepth::Ansi256
+        } else {
+            ColorDepth::Ansi16
+        }
+    }
+}
+
+/// The look of one class of bytes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Style {
+    /// The foreground colour, or `None` to keep the terminal's.
+    pub fg: Option<Color>,
+    /// The background colour, or `None` to keep the terminal's.
+    pub bg: Option<Color>,
+    /// Whether the text is bold.
+    pub bold: bool,
+    /// Whether the text is dimmed.
+    pub dim: bool,
+}
+
+impl Style {
+    /// A style that changes nothing.
+    pub const fn new() -> Self {
+        Style {
+            fg: None,
+            bg: None,
+            bold: false,
+            dim: false,
+        }
+    }
+
+    /// Sets the foreground colour.
+    pub const fn fg(mut self, color: Color) -> Self {
+        self.fg = Some(color);
+        self
+    }
+
+    /// Sets the background colour.
+    pub const fn bg(mut self, color: Color) -> Self {
+        self.bg = Some(color);
+        self
+    }
+
+    /// Makes the text bold.
+    pub const fn bold(mut self) -> Self {
+        self.bold = true;
+        self
+    }
+
+    /// Makes the text dim.
+    pub const fn dim(mut self) -> Self {
+        self.dim = true;
+        self
+    }
+
+    fn is_plain(&self) -> bool {
+        *self == Style::new()
+    }
+
+    /// Writes the escape sequence switching to this style from the
+    /// terminal's default.
+    pub fn write_start<W: Write>(&self, out: &mut W, depth: ColorDepth) -> fmt::Result {
+        if self.is_plain() {
+            return Ok(());
+        }
+        out.write_str("\x1b[")?;
+        let mut sep = "";
+        if self.bold {
+            out.write_str("1")?;
+            sep = ";";
+        }
+        if self.dim {
+            write!(out, "{sep}2")?;
+            sep = ";";
+        }
+        if let Some(fg) = self.fg {
+            out.write_str(sep)?;
+            fg.to_depth(depth).write_sgr(out, false)?;
+            sep = ";";
+        }
+        if let Some(bg) = self.bg {
+            out.write_str(sep)?;
+            bg.to_depth(depth).write_sgr(out, true)?;
+        }
+        out.write_char('m')
+    }
+
+    /// Writes the escape sequence returning to the terminal's default.
+    pub fn write_end<W: Write>(&self, out: &mut W) -> fmt::Result {
+        if self.is_plain() {
+            return Ok(());
+        }
+        out.write_str(RESET)
+    }
+}
+
+const RESET: &str = "\x1b[0m";
+
+/// Styles for each [`ByteClass`] and for the offset column.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Theme {
+    /// The style of [`ByteClass::Null`] bytes.
+    pub null: Style,
+    /// The style of [`ByteClass::Printable`] bytes.
+    pub printable: Style,
+    /// The style of [`ByteClass::Whitespace`] bytes.
+    pub whitespace: Style,
+    /// The style of [`ByteClass::Control`] bytes.
+    pub control: Style,
+    /// The style of [`ByteClass::HighBit`] bytes.
+    pub high_bit: Style,
+    /// The style of [`ByteClass::Ff`] bytes.
+    pub ff: Style,
+    /// The style of the offset column.
+    pub offset: Style,
+}
+
+impl Default for Theme {
+    fn default() -> Self {
+        Theme::DEFAULT
+    }
+}
+
+impl Theme {
+    /// The default theme, using only the 16 basic colours.
+    pub const DEFAULT: Theme = Theme {
+        null: Style::new().fg(Color::BRIGHT_BLACK),
+        printable: Style::new().fg(Color::CYAN),
+        whitespace: Style::new().fg(Color::GREEN),
+        control: Style::new().fg(Color::MAGENTA),
+        high_bit: Style::new().fg(Color::YELLOW),
+        ff: Style::new().fg(Color::RED).bold(),
+        offset: Style::new(),
+    };
+
+    /// Returns the style for bytes of `class`.
+    pub fn style(&self, class: ByteClass) -> Style {
+        match class {
+            ByteClass::Null => self.null,
+            ByteClass::Printable => self.printable,
+            ByteClass::Whitespace => self.whitespace,
+            ByteClass::Control => self.control,
+            ByteClass::HighBit => self.high_bit,
+            ByteClass::Ff => self.ff,
+        }
+    }
+}
+
+/// Whether to colour output.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum ColorChoice {
+    /// Never colour.
+    #[default]
+    Never,
+    /// Always colour, even when `NO_COLOR` is set.
+    Always,
+    /// Colour when writing to a terminal and `NO_COLOR` is not set. Resolve
+    /// it with [`HexDumper::color_for`]; until then it behaves like
+    /// [`Never`](ColorChoice::Never).
+    Auto,
+}
+
+#[cfg(feature = "std")]
+impl ColorChoice {
+    /// Resolves the choice for output to a stream that is (or is not) a
+    /// terminal.
+    pub fn enabled(self, is_terminal: bool) -> bool {
+        match self {
+            ColorChoice::Never => false,
+            ColorChoice::Always => true,
+            ColorChoice::Auto => {
+                is_terminal && std::env::var_os("NO_COLOR").is_none_or(|v| v.is_empty())
+            }
+        }
+    }
+}
+
+impl HexDumper {
+    /// Sets whether output is coloured.
+    pub fn color(mut self, choice: ColorChoice) -> Self {
+        self.color = choice;
+        self
+    }
+
+    /// Sets the styles used for coloured output.
+    pub fn theme(mut self, theme: Theme) -> Self {
+        self.theme = theme;
+        self
+    }
+
+    /// Sets how many colours the output may use. Theme colours beyond it are
+    /// mapped to the nearest colour available.
+    pub fn color_depth(mut self, depth: ColorDepth) -> Self {
+        self.color_depth = depth;
+        self
+    }
+
+    /// Resolves [`ColorChoice::Auto`] for output to `stream`.
+    ///
+    /// Colour stays on only if `stream` is a terminal and `NO_COLOR` is not
+    /// set, in which case the colour depth is also detected from the
+    /// environment. Other choices are left as they are.
+    #[cfg(feature = "std")]
+    pub fn color_for<S: IsTerminal>(mut self, stream: &S) -> Self {
+        if self.color == ColorChoice::Auto {
+            if ColorChoice::Auto.enabled(stream.is_terminal()) {
+                self.color = ColorChoice::Always;
+                self.color_depth = ColorDepth::detect();
+            } else {
+                self.color = ColorChoice::Never;
+            }
+        }
+        self
+    }
+
+    /// The theme to colour with, if colour is on.
+    pub(crate) fn active_theme(&self) -> Option<&Theme> {
+        (self.color == ColorChoice::Always).then_some(&self.theme)
+    }
+}
+
+/// Tracks the current style while writing a run of coloured bytes, so that
+/// escape codes are only written when the style changes.
+pub(crate) struct Painter<'a> {
+    theme: Option<&'a Theme>,
+    depth: ColorDepth,
+    current: Style,
+}
+
+impl<'a> Painter<'a> {
+    pub(crate) fn new(dumper: &'a HexDumper) -> Self {
+        Painter {
+            theme: dumper.active_theme(),
+            depth: dumper.color_depth,
+            current: Style::new(),
+        }
+    }
+
+    /// The style to draw `b` in: `highlight` if given, else its class style.
+    fn style_of(&self, b: u8, highlight: Option<Style>) -> Option<Style> {
+        self.theme
+            .map(|theme| highlight.unwrap_or_else(|| theme.style(ByteClass::of(b))))
+    }
+
+    /// Returns to the terminal's default style.
+    fn reset<W: Write>(&mut self, out: &mut W) -> fmt::Result {
+        self.set(out, Style::new())
+    }
+
+    fn set<W: Write>(&mut self, out: &mut W, style: Style) -> fmt::Result {
+        if style != self.current {
+            self.current.write_end(out)?;
+            style.write_start(out, self.depth)?;
+            self.current = style;
+        }
+        Ok(())
+    }
+}
+
+impl Markup for Painter<'_> {
+    fn is_plain(&self) -> bool {
+        self.theme.is_none()
+    }
+
+    /// Switches to the offset style for the offset column.
+    fn start<W: Write>(&mut self, out: &mut W, column: Column) -> fmt::Result {
+        match (column, self.theme) {
+            (Column::Offset, Some(theme)) => self.set(out, theme.offset),
+            _ => Ok(()),
+        }
+    }
+
+    fn end<W: Write>(&mut self, out: &mut W) -> fmt::Result {
+        self.reset(out)
+    }
+
+    /// Switches to the style of `b`, or to `highlight` if given.
+    fn byte<W: Write>(&mut self, out: &mut W, b: u8, highlight: Option<Style>) -> fmt::Result {
+        match self.style_of(b, highlight) {
+            Some(style) => self.set(out, style),
+            None => Ok(()),
+        }
+    }
+
+    /// Ends the current style before a separator unless the byte after it
+    /// keeps that style, so separators are only coloured inside runs.
+    fn separator<W: Write>(
+        &mut self,
+        out: &mut W,
+        next: u8,
+        highlight: Option<Style>,
+    ) -> fmt::Result {
+        match self.style_of(next, highlight) {
+            Some(style) if style != self.current => self.reset(out),
+            _ => Ok(()),
+        }
+    }
+}
+
+/// A column of a dump line.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Column {
+    Offset,
+    Hex,
+    Ascii,
+}
+
+/// Marks up the parts of a dump line as the line writer produces them:
+/// with terminal escape codes for [`Painter`], or with the tags of an
+/// [`HtmlPage`](crate::html::HtmlPage).
+pub(crate) trait Markup {
+    /// Whether the markup adds nothing, so the line can be written plain.
+    fn is_plain(&self) -> bool {
+        false
+    }
+
+    /// Starts a line numbered `offset` into the input.
+    fn line(&mut self, _offset: u64) {}
+
+    /// Starts `column`, which runs until the next call to
+    /// [`end`](Markup::end).
+    fn start<W: Write>(&mut self, out: &mut W, column: Column) -> fmt::Result;
+
+    /// Ends the column last started.
+    fn end<W: Write>(&mut self, out: &mut W) -> fmt::Result;
+
+    /// Starts byte `b`, to be drawn in `highlight` if given.
+    fn byte<W: Write>(&mut self, out: &mut W, b: u8, highlight: Option<Style>) -> fmt::Result;
+
+    /// Comes between two bytes of the hex column, before the separator
+    /// leading to `next`.
+    fn separator<W: Write>(
+        &mut self,
+        out: &mut W,
+        next: u8,
+        highlight: Option<Style>,
+    ) -> fmt::Result;
+
+    /// Writes text that is not markup: separators, delimiters and the
+    /// characters of the ASCII column.
+    fn text<W: Write>(&mut self, out: &mut W, text: &str) -> fmt::Result {
+        out.write_str(text)
+    }
+}
+
+/// Maps a 256 colour palette index to RGB.
+fn ansi256_to_rgb(n: u8) -> (u8, u8, u8) {
+    const LEVELS: [u8; 6] = [0, 95, 135, 175, 215, 255];
+    match n {
+        0..=15 => BASIC[n as usize],
+        16..=231 => {
+            let i = n - 16;
+            (
+                LEVELS[(i / 36) as usize],
+                LEVELS[(i / 6 % 6) as usize],
+                LEVELS[(i % 6) as usize],
+            )
+        }
+        _ => {
+            let v = 8 + (n - 232) * 10;
+            (v, v, v)
+        }
+    }
+}
+
+/// Finds the nearest colour in the 6x6x6 cube or the grey ramp.
+fn rgb_to_256(r: u8, g: u8, b: u8) -> u8 {
+    (16..=255)
+        .min_by_key(|&n| distance(ansi256_to_rgb(n), (r, g, b)))
+        .expect("palette is not empty")
+}
+
+/// Finds the nearest of the 16 basic colours.
+fn rgb_to_16(r: u8, g: u8, b: u8) -> u8 {
+    (0..16)
+        .min_by_key(|&n| distance(BASIC[n as usize], (r, g, b)))
+        .expect("palette is not empty")
+}
+
+fn distance(a: (u8, u8, u8), b: (u8, u8, u8)) -> u32 {
+    let d = |x: u8, y: u8| (i32::from(x) - i32::from(y)).unsigned_abs().pow(2);
+    d(a.0, b.0) + d(a.1, b.1) + d(a.2, b.2)
+}
+
+/// The usual xterm values of the 16 basic colours.
+const BASIC: [(u8, u8, u8); 16] = [
+    (0, 0, 0),
+    (205, 0, 0),
+    (0, 205, 0),
+    (205, 205, 0),
+    (0, 0, 238),
+    (205, 0, 205),
+    (0, 205, 205),
+    (229, 229, 229),
+    (127, 127, 127),
+    (255, 0, 0),
+    (0, 255, 0),
+    (255, 255, 0),
+    (92, 92, 255),
+    (255, 0, 255),
+    (0, 255, 255),
+    (255, 255, 255),
+];
@@ -0,0 +1,366 @@
+//! Side-by-side dumps of two inputs with their differences marked.
+//!
+//! Each output row shows a line of the left input, a marker, and the
+//! matching line of the right input:
+//!
+//! | Marker | Meaning                                   |
+//! |--------|-------------------------------------------|
+//! | ` `    | both lines are identical                  |
+//! | `!`    | the lines differ                          |
+//! | `<`    | bytes only in the left input (deleted)    |
+//! | `>`    | bytes only in the right input (inserted)  |
+//!
+//! Differing bytes are drawn in the [`highlight`](DiffDumper::highlight)
+//! style when the dumper has colour turned on.
+//!
+//! By default bytes are compared at equal offsets. With
+//! [`resync`](DiffDumper::resync) the dumper looks ahead after a mismatch
+//! for a small insertion or deletion that brings the inputs back in step,
+//! and continues from there with separate offsets for each side.
+//!
+//! ```
+//! use hexdump::diff::DiffDumper;
+//!
+//! let diff = DiffDumper::new().diff(b"Hello, world", b"Hello, World");
+//! assert_eq!(
+//!     diff,
+//!     "00000000: 48 65 6c 6c 6f 2c 20 77  |Hello, w| ! \
+//!      00000000: 48 65 6c 6c 6f 2c 20 57  |Hello, W|\n\
+//!      00000008: 6f 72 6c 64              |orld|       \
+//!      00000008: 6f 72 6c 64              |orld|\n",
+//! );
+//! ```
+
+use std::collections::VecDeque;
+use std::io::{self, Read, Write};
+
+use crate::color::{Color, Style};
+use crate::{HexDumper, STRING_WRITE};
+
+/// Bytes that must match after a candidate shift before the inputs are
+/// considered back in step.
+const SYNC_LEN: usize = 8;
+
+/// Configurable side-by-side diff dumper.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DiffDumper {
+    dumper: HexDumper,
+    context: Option<usize>,
+    resync: usize,
+    highlight: Style,
+}
+
+impl Default for DiffDumper {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Totals from a diff.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct DiffSummary {
+    /// Length of the left input.
+    pub left_len: u64,
+    /// Length of the right input.
+    pub right_len: u64,
+    /// Bytes that differ, including bytes present on one side only.
+    pub differing_bytes: u64,
+}
+
+impl DiffSummary {
+    /// Whether the inputs were identical.
+    pub fn is_identical(&self) -> bool {
+        self.differing_bytes == 0
+    }
+}
+
+impl DiffDumper {
+    /// Creates a diff dumper showing 8 bytes per side on each row.
+    pub fn new() -> Self {
+        DiffDumper {
+            dumper: HexDumper::new().bytes_per_line(8),
+            context: None,
+            resync: 0,
+            highlight: Style::new().fg(Color::RED).bold(),
+        }
+    }
+
+    /// Sets the layout used for each side.
+    ///
+    /// Squeezing and the trailing offset line do not apply to diffs.
+    pub fn dumper(mut self, dumper: HexDumper) -> Self {
+        self.dumper = dumper;
+        self
+    }
+
+    /// Shows only differing rows and `lines` rows of context around them.
+    /// Separate groups of rows are divided by a `--` line.
+    pub fn context(mut self, lines: usize) -> Self {
+        self.context = Some(lines);
+        self
+    }
+
+    /// Looks up to `window` bytes ahead for an insertion or deletion after
+    /// a mismatch. Zero, the default, compares bytes at equal offsets only.
+    pub fn resync(mut self, window: usize) -> Self {
+        self.resync = window;
+        self
+    }
+
+    /// Sets the style of differing bytes when colour is on.
+    pub fn highlight(mut self, style: Style) -> Self {
+        self.highlight = style;
+        self
+    }
+
+    /// Renders the diff of two buffers.
+    pub fn diff(&self, left: &[u8], right: &[u8]) -> String {
+        let mut out = Vec::new();
+        self.diff_readers(left, right, &mut out)
+            .expect("reading from and writing to memory cannot fail");
+        String::from_utf8(out).expect("dumps are UTF-8")
+    }
+
+    /// Streams the diff of two readers to `writer`.
+    ///
+    /// Memory use is bounded by the line length and the resync window, not
+    /// by the size of the inputs.
+    pub fn diff_readers<L: Read, R: Read, W: Write>(
+        &self,
+        left: L,
+        right: R,
+        mut writer: W,
+    ) -> io::Result<DiffSummary> {
+        let bpl = self.dumper.bytes_per_line;
+        let lookahead = bpl + self.resync + SYNC_LEN;
+        let mut left = Side::new(left);
+        let mut right = Side::new(right);
+        let mut summary = DiffSummary::default();
+        let mut context = Context::new(self.context);
+        let mut text = String::new();
+        loop {
+            left.fill(lookahead)?;
+            right.fill(lookahead)?;
+            let Some((ln, rn)) = self.next_row(left.avail(), right.avail()) else {
+                break;
+            };
+            let (l, r) = (&left.avail()[..ln], &right.avail()[..rn]);
+            let differing = if ln > 0 && rn > 0 {
+                let changed = l.iter().zip(r).filter(|(a, b)| a != b).count();
+                changed + ln.abs_diff(rn)
+            } else {
+                ln + rn
+            };
+            summary.differing_bytes += differing as u64;
+
+            text.clear();
+            self.write_row(&mut text, (left.offset, l), (right.offset, r))
+                .expect(STRING_WRITE);
+            context.row(&mut writer, &text, differing > 0)?;
+
+            left.consume(ln);
+            right.consume(rn);
+        }
+        summary.left_len = left.offset;
+        summary.right_len = right.offset;
+        writer.flush()?;
+        Ok(summary)
+    }
+
+    /// Decides how many bytes of each side go on the next row. Returns
+    /// `None` once both sides are exhausted.
+    fn next_row(&self, l: &[u8], r: &[u8]) -> Option<(usize, usize)> {
+        let bpl = self.dumper.bytes_per_line;
+        if l.is_empty() && r.is_empty() {
+            return None;
+        }
+        let n = bpl.min(l.len()).min(r.len());
+        if self.resync > 0 {
+            for k in 0..n {
+                if l[k] == r[k] || in_step(&l[k + 1..], &r[k + 1..]) {
+                    continue;
+                }
+                if let Some(shift) = self.find_shift(l, r, k) {
+                    return Some(match (k, shift) {
+                        (0, Shift::Deleted(s)) => (s.min(bpl), 0),
+                        (0, Shift::Inserted(s)) => (0, s.min(bpl)),
+                        _ => (k, k),
+                    });
+                }
+            }
+        }
+        Some((bpl.min(l.len()), bpl.min(r.len())))
+    }
+
+    /// Looks for the smallest shift after the mismatch at `k` that brings
+    /// the two sides back in step.
+    fn find_shift(&self, l: &[u8], r: &[u8], k: usize) -> Option<Shift> {
+        (1..=self.resync).find_map(|s| {
+            if k + s <= l.len() && in_step(&l[k + s..], &r[k..]) {
+                Some(Shift::Deleted(s))
+            } else if k + s <= r.len() && in_step(&l[k..], &r[k + s..]) {
+                Some(Shift::Inserted(s))
+            } else {
+                None
+            }
+        })
+    }
+
+    fn write_row(
+        &self,
+        out: &mut String,
+        (loff, l): (u64, &[u8]),
+        (roff, r): (u64, &[u8]),
+    ) -> std::fmt::Result {
+        let dumper = &self.dumper;
+        let full = dumper.line_width(loff, dumper.bytes_per_line);
+        let marker = match (l.is_empty(), r.is_empty()) {
+            (false, true) => '<',
+            (true, false) => '>',
+            _ if l == r => ' ',
+            _ => '!',
+        };
+        let differs = |this: &[u8], other: &[u8], i: usize| {
+            (other.get(i) != this.get(i)).then_some(self.highlight)
+        };
+        let mut width = 0;
+        if !l.is_empty() {
+            dumper.write_line_with(out, loff, l, |i| differs(l, r, i))?;
+            width = dumper.line_width(loff, l.len());
+        }
+        for _ in width..full {
+            out.push(' ');
+        }
+        out.push(' ');
+        out.push(marker);
+        if !r.is_empty() {
+            out.push(' ');
+            dumper.write_line_with(out, roff, r, |i| differs(r, l, i))?;
+        }
+        out.push('\n');
+        Ok(())
+    }
+}
+
+enum Shift {
+    /// Bytes present only in the left input.
+    Deleted(usize),
+    /// Bytes present only in the right input.
+    Inserted(usize),
+}
+
+/// Whether two tails start with the same [`SYNC_LEN`] bytes, or are equal
+/// outright when shorter than that.
+fn in_step(a: &[u8], b: &[u8]) -> bool {
+    let n = SYNC_LEN.min(a.len()).min(b.len());
+    a[..n] == b[..n] && (n == SYNC_LEN || a.len() == b.len())
+}
+
+/// One input with a lookahead buffer.
+struct Side<R> {
+    reader: R,
+    buf: Vec<u8>,
+    pos: usize,
+    eof: bool,
+    /// Offset of `buf[pos]` in the input.
+    offset: u64,
+}
+
+impl<R: Read> Side<R> {
+    fn new(reader: R) -> Self {
+        Side {
+            reader,
+            buf: Vec::new(),
+            pos: 0,
+            eof: false,
+            offset: 0,
+        }
+    }
+
+    /// Reads until at least `want` bytes are buffered or the input ends.
+    fn fill(&mut self, want: usize) -> io::Result<()> {
+        if self.pos > 0 {
+            self.buf.drain(..self.pos);
+            self.pos = 0;
+        }
+        let mut chunk = [0u8; 4096];
+        while !self.eof && self.buf.len() < want {
+            match self.reader.read(&mut chunk) {
+                Ok(0) => self.eof = true,
+                Ok(n) => self.buf.extend_from_slice(&chunk[..n]),
+                Err(e) if e.kind() == io::ErrorKind::Interrupted => {}
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(())
+    }
+
+    fn avail(&self) -> &[u8] {
+        &self.buf[self.pos..]
+    }
+
+    fn consume(&mut self, n: usize) {
+        self.pos += n;
+        self.offset += n as u64;
+    }
+}
+
+/// Filters rows down to the differing ones and their context. Also used by
+/// [`SearchDumper`](crate::search::SearchDumper) for rows with matches.
+pub(crate) struct Context {
+    lines: Option<usize>,
+    /// Identical rows held back as leading context.
+    before: VecDeque<String>,
+    /// Identical rows still to print as trailing context.
+    after: usize,
+    /// Whether rows were dropped since the last one printed.
+    skipped: bool,
+    printed: bool,
+}
+
+impl Context {
+    pub(crate) fn new(lines: Option<usize>) -> Self {
+        Context {
+            lines,
+            before: VecDeque::new(),
+            after: 0,
+            skipped: false,
+            printed: false,
+        }
+    }
+
+    pub(crate) fn row<W: Write>(
+        &mut self,
+        out: &mut W,
+        text: &str,
+        differs: bool,
+    ) -> io::Result<()> {
+        let Some(lines) = self.lines else {
+            return out.write_all(text.as_bytes());
+        };
+        if differs {
+            if self.skipped && self.printed {
+                out.write_all(b"--\n")?;
+            }
+            for held in self.before.drain(..) {
+                out.write_all(held.as_bytes())?;
+            }
+            out.write_all(text.as_bytes())?;
+            self.after = lines;
+            self.skipped = false;
+            self.printed = true;
+        } else if self.after > 0 {
+            out.write_all(text.as_bytes())?;
+            self.after -= 1;
+        } else if lines > 0 {
+            if self.before.len() == lines {
+                self.before.pop_front();
+                self.skipped = true;
+            }
+            self.before.push_back(text.to_owned());
+        } else {
+            self.skipped = true;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,232 @@
+use core::fmt::{self, Write};
+
+use crate::HexDumper;
+
+/// Formats bytes as a hex dump with the default [`HexDumper`] layout.
+///
+/// The wrapper only borrows the bytes, so it costs nothing to build one
+/// inside a `format!` call or a `Debug` implementation:
+///
+/// | Format  | Output                                               |
+/// |---------|------------------------------------------------------|
+/// | `{}`    | the dump, without a newline after the last line      |
+/// | `{:#?}` | the same dump, so pretty `Debug` output stays readable |
+/// | `{:?}`  | bytes on one line, e.g. `[de ad be ef]`              |
+/// | `{:x}`  | bare hex digits, e.g. `deadbeef`                     |
+/// | `{:X}`  | bare upper case hex digits, e.g. `DEADBEEF`          |
+///
+/// A width sets the number of bytes per line, of the dump or of the bare
+/// hex digits, and a precision the maximum number of bytes shown. Longer
+/// input is cut off with a `...` marker. With `#`, hex digits are prefixed
+/// with `0x`.
+///
+/// ```
+/// use hexdump::HexDump;
+///
+/// let bytes = b"hexdump\x00";
+/// assert_eq!(
+///     format!("{:4}", HexDump(bytes)),
+///     "00000000: 68 65 78 64  |hexd|\n00000004: 75 6d 70 00  |ump.|",
+/// );
+/// assert_eq!(format!("{:?}", HexDump(bytes)), "[68 65 78 64 75 6d 70 00]");
+/// assert_eq!(format!("{:.3?}", HexDump(bytes)), "[68 65 78 ...]");
+/// assert_eq!(format!("{:#x}", HexDump(&bytes[..4])), "0x68657864");
+/// ```
+///
+/// Wrapping byte fields keeps derived `Debug` output readable:
+///
+/// ```
+/// use hexdump::HexDump;
+///
+/// struct Packet {
+///     kind: u8,
+///     payload: Vec<u8>,
+/// }
+///
+/// impl std::fmt::Debug for Packet {
+///     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+///         f.debug_struct("Packet")
+///             .field("kind", &self.kind)
+///             .field("payload", &HexDump(&self.payload))
+///             .finish()
+///     }
+/// }
+///
+/// let packet = Packet { kind: 2, payload: vec![0xca, 0xfe] };
+/// assert_eq!(format!("{packet:?}"), "Packet { kind: 2, payload: [ca fe] }");
+/// ```
+#[derive(Clone, Copy)]
+pub struct HexDump<'a>(pub &'a [u8]);
+
+impl<'a> HexDump<'a> {
+    /// Formats `data` with the layout of `dumper` instead of the default.
+    ///
+    /// ```
+    /// use hexdump::{HexDump, HexDumper, Preset};
+    ///
+    /// let xxd = HexDumper::new().preset(Preset::Xxd);
+    /// assert_eq!(
+    ///     format!("{}", HexDump::with(&xxd, b"xxd")),
+    ///     "00000000: 7878 64                                  xxd",
+    /// );
+    /// ```
+    pub fn with(dumper: &'a HexDumper, data: &'a [u8]) -> HexDumpWith<'a> {
+        HexDumpWith { dumper, data }
+    }
+}
+
+/// Formats bytes as a hex dump with a given [`HexDumper`] layout.
+///
+/// Created by [`HexDump::with`]; formats like [`HexDump`] otherwise. A width
+/// overrides the dumper's bytes per line, and the compact `{:?}` and `{:x}`
+/// forms ignore the layout.
+#[derive(Clone, Copy)]
+pub struct HexDumpWith<'a> {
+    dumper: &'a HexDumper,
+    data: &'a [u8],
+}
+
+static DEFAULT: HexDumper = HexDumper::new();
+
+impl fmt::Display for HexDump<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write_dump(f, &DEFAULT, self.0)
+    }
+}
+
+impl fmt::Display for HexDumpWith<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write_dump(f, self.dumper, self.data)
+    }
+}
+
+impl fmt::Debug for HexDump<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write_debug(f, &DEFAULT, self.0)
+    }
+}
+
+impl fmt::Debug for HexDumpWith<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write_debug(f, self.dumper, self.data)
+    }
+}
+
+impl fmt::LowerHex for HexDump<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write_digits(f, self.0, false)
+    }
+}
+
+impl fmt::LowerHex for HexDumpWith<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write_digits(f, self.data, false)
+    }
+}
+
+impl fmt::UpperHex for HexDump<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write_digits(f, self.0, true)
+    }
+}
+
+impl fmt::UpperHex for HexDumpWith<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write_digits(f, self.data, true)
+    }
+}
+
+/// Splits `data` at the formatter's precision into the bytes to show and
+/// the number left out.
+fn truncate<'d>(f: &fmt::Formatter<'_>, data: &'d [u8]) -> (&'d [u8], usize) {
+    let shown = f.precision().map_or(data.len(), |max| max.min(data.len()));
+    (&data[..shown], data.len() - shown)
+}
+
+fn write_dump(f: &mut fmt::Formatter<'_>, dumper: &HexDumper, data: &[u8]) -> fmt::Result {
+    let (shown, rest) = truncate(f, data);
+    let mut out = TrimNewline {
+        out: f,
+        pending: false,
+    };
+    match out.out.width() {
+        Some(width) if width > 0 && width != dumper.bytes_per_line => {
+            let dumper = dumper.clone().bytes_per_line(width);
+            dumper.write_dump(&mut out, shown)?;
+        }
+        _ => dumper.write_dump(&mut out, shown)?,
+    }
+    if rest > 0 {
+        if !shown.is_empty() {
+            out.out.write_char('\n')?;
+        }
+        let s = if rest == 1 { "" } else { "s" };
+        write!(out.out, "... ({rest} more byte{s})")?;
+    }
+    Ok(())
+}
+
+fn write_debug(f: &mut fmt::Formatter<'_>, dumper: &HexDumper, data: &[u8]) -> fmt::Result {
+    if f.alternate() {
+        return write_dump(f, dumper, data);
+    }
+    let (shown, rest) = truncate(f, data);
+    f.write_char('[')?;
+    for (i, b) in shown.iter().enumerate() {
+        if i > 0 {
+            f.write_char(' ')?;
+        }
+        if dumper.uppercase {
+            write!(f, "{b:02X}")?;
+        } else {
+            write!(f, "{b:02x}")?;
+        }
+    }
+    if rest > 0 {
+        f.write_str(if shown.is_empty() { "..." } else { " ..." })?;
+    }
+    f.write_char(']')
+}
+
+fn write_digits(f: &mut fmt::Formatter<'_>, data: &[u8], upper: bool) -> fmt::Result {
+    let (shown, _) = truncate(f, data);
+    if f.alternate() {
+        f.write_str("0x")?;
+    }
+    for (i, b) in shown.iter().enumerate() {
+        if matches!(f.width(), Some(width) if width > 0 && i > 0 && i % width == 0) {
+            f.write_char('\n')?;
+        }
+        if upper {
+            write!(f, "{b:02X}")?;
+        } else {
+            write!(f, "{b:02x}")?;
+        }
+    }
+    Ok(())
+}
+
+/// Holds back the newline ending the text written so far, so the last line
+/// of a dump is left unterminated.
+struct TrimNewline<'a, W> {
+    out: &'a mut W,
+    pending: bool,
+}
+
+impl<W: Write> Write for TrimNewline<'_, W> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        if s.is_empty() {
+            return Ok(());
+        }
+        if core::mem::take(&mut self.pending) {
+            self.out.write_char('\n')?;
+        }
+        match s.strip_suffix('\n') {
+            Some(body) => {
+                self.pending = true;
+                self.out.write_str(body)
+            }
+            None => self.out.write_str(s),
+        }
+    }
+}
@@ -0,0 +1,806 @@
+use core::fmt::{self, Write};
+
+use crate::color::{ColorChoice, ColorDepth, Column, Markup, Painter, Style, Theme};
+use crate::encode::{self, LOWER, UPPER};
+use crate::render::Renderer;
+
+/// Numeric base used to print line offsets.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum OffsetBase {
+    /// Hexadecimal, e.g. `000000a0`.
+    #[default]
+    Hex,
+    /// Upper case hexadecimal, e.g. `000000A0`.
+    UpperHex,
+    /// Decimal, e.g. `00000160`.
+    Decimal,
+    /// Octal, e.g. `00000240`.
+    Octal,
+}
+
+/// How each byte is written in the hex column.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum ByteFormat {
+    /// Two hex digits per byte, e.g. `4f`.
+    #[default]
+    Hex,
+    /// Eight binary digits per byte, e.g. `01001111`.
+    Binary,
+}
+
+impl ByteFormat {
+    /// Number of characters used to print one byte.
+    pub const fn width(self) -> usize {
+        match self {
+            ByteFormat::Hex => 2,
+            ByteFormat::Binary => 8,
+        }
+    }
+}
+
+/// Whether a dump ends with a line holding only the offset just past the
+/// last byte, as `hexdump` and `od` print.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum TrailingOffset {
+    /// No trailing offset line.
+    #[default]
+    Never,
+    /// A trailing offset line unless the input was empty (`hexdump`).
+    NonEmpty,
+    /// Always a trailing offset line, even for empty input (`od`).
+    Always,
+}
+
+/// When runs of identical lines are collapsed into a single `*` line.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Squeeze {
+    /// Every line is printed.
+    #[default]
+    Off,
+    /// Any repeat of the line above is collapsed, like `hexdump` and `od`.
+    Always,
+    /// Only runs of at least this many repeats are collapsed; shorter runs
+    /// are printed in full.
+    After(usize),
+}
+
+/// Configurable hex dump formatter.
+///
+/// A `HexDumper` is a small, cheaply cloned description of a dump layout.
+/// Build one with [`HexDumper::new`] and the chained setters, then render
+/// bytes with [`dump`](HexDumper::dump) or [`write_dump`](HexDumper::write_dump).
+///
+/// Every line has the same shape:
+///
+/// ```text
+/// <offset><offset_separator><hex groups><ascii_separator><left>ascii<right>
+/// ```
+///
+/// The hex column of a short final line is padded so the ASCII gutter stays
+/// aligned with the lines above it.
+///
+/// ```
+/// # #[cfg(feature = "std")] {
+/// use hexdump::HexDumper;
+///
+/// let dumper = HexDumper::new().bytes_per_line(8).group_size(2);
+/// assert_eq!(
+///     dumper.dump(b"Hello, world"),
+///     "00000000: 4865 6c6c 6f2c 2077  |Hello, w|\n\
+///      00000008: 6f72 6c64            |orld|\n",
+/// );
+/// # }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HexDumper {
+    pub(crate) bytes_per_line: usize,
+    pub(crate) group_size: usize,
+    pub(crate) offset_width: usize,
+    pub(crate) offset_base: OffsetBase,
+    pub(crate) show_offset: bool,
+    pub(crate) base_address: Option<u64>,
+    pub(crate) anchor: Option<u64>,
+    pub(crate) uppercase: bool,
+    pub(crate) byte_format: ByteFormat,
+    pub(crate) offset_separator: &'static str,
+    pub(crate) group_separator: &'static str,
+    pub(crate) byte_separator: &'static str,
+    pub(crate) ascii_separator: &'static str,
+    pub(crate) ascii_left: &'static str,
+    pub(crate) ascii_right: &'static str,
+    pub(crate) show_ascii: bool,
+    pub(crate) placeholder: char,
+    pub(crate) squeeze: Squeeze,
+    pub(crate) squeeze_annotation: bool,
+    pub(crate) trailing_offset: TrailingOffset,
+    pub(crate) color: ColorChoice,
+    pub(crate) theme: Theme,
+    pub(crate) color_depth: ColorDepth,
+}
+
+impl Default for HexDumper {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl HexDumper {
+    /// Creates a dumper with the default layout: 16 bytes per line in
+    /// single-byte groups, an 8 digit hex offset and an ASCII gutter.
+    pub const fn new() -> Self {
+        HexDumper {
+            bytes_per_line: 16,
+            group_size: 1,
+            offset_width: 8,
+            offset_base: OffsetBase::Hex,
+            show_offset: true,
+            base_address: None,
+            anchor: None,
+            uppercase: false,
+            byte_format: ByteFormat::Hex,
+            offset_separator: ": ",
+            group_separator: " ",
+            byte_separator: "",
+            ascii_separator: "  ",
+            ascii_left: "|",
+            ascii_right: "|",
+            show_ascii: true,
+            placeholder: '.',
+            squeeze: Squeeze::Off,
+            squeeze_annotation: false,
+            trailing_offset: TrailingOffset::Never,
+            color: ColorChoice::Never,
+            theme: Theme::DEFAULT,
+            color_depth: ColorDepth::TrueColor,
+        }
+    }
+
+    /// Sets the number of bytes rendered on each line.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `n` is zero.
+    pub fn bytes_per_line(mut self, n: usize) -> Self {
+        assert!(n > 0, "bytes_per_line must be non-zero");
+        self.bytes_per_line = n;
+        self
+    }
+
+    /// Sets the number of bytes printed together before a group separator.
+    ///
+    /// A group size of zero puts the whole line in a single group.
+    pub fn group_size(mut self, n: usize) -> Self {
+        self.group_size = n;
+        self
+    }
+
+    /// Sets the minimum number of digits in the offset column.
+    ///
+    /// Offsets are zero padded to this width. Larger offsets are printed in
+    /// full rather than truncated.
+    pub fn offset_width(mut self, width: usize) -> Self {
+        self.offset_width = width;
+        self
+    }
+
+    /// Sets the base used to print offsets.
+    pub fn offset_base(mut self, base: OffsetBase) -> Self {
+        self.offset_base = base;
+        self
+    }
+
+    /// Numbers lines by address, `address` being the address of the first
+    /// byte, instead of by offset from the start of the input.
+    ///
+    /// Addresses that do not fit in 32 bits are printed at the full width
+    /// of a 64-bit address, so a dump of high memory stays aligned.
+    ///
+    /// ```
+    /// # #[cfg(feature = "std")] {
+    /// use hexdump::HexDumper;
+    ///
+    /// let dumper = HexDumper::new().bytes_per_line(4).show_ascii(false);
+    /// assert_eq!(
+    ///     dumper.clone().base_address(0x8000_0000).dump(b"abcdef"),
+    ///     "80000000: 61 62 63 64\n80000004: 65 66\n",
+    /// );
+    /// assert_eq!(
+    ///     dumper.base_address(0x7fff_0000_0000).dump(b"ab"),
+    ///     "00007fff00000000: 61 62\n",
+    /// );
+    /// # }
+    /// ```
+    pub fn base_address(mut self, address: u64) -> Self {
+        self.base_address = Some(address);
+        self
+    }
+
+    /// Prints offsets as signed distances from `anchor`, with lines before
+    /// it negative, e.g. `-00000010` and `+00000020`.
+    ///
+    /// `anchor` is an address when a [`base_address`](HexDumper::base_address)
+    /// is set and an offset into the input otherwise. Distances grow to full
+    /// 64-bit width like addresses do.
+    ///
+    /// ```
+    /// # #[cfg(feature = "std")] {
+    /// use hexdump::HexDumper;
+    ///
+    /// let dumper = HexDumper::new()
+    ///     .bytes_per_line(4)
+    ///     .offset_width(4)
+    ///     .show_ascii(false)
+    ///     .base_address(0x1000)
+    ///     .relative_to(0x1004);
+    /// assert_eq!(
+    ///     dumper.dump(b"abcdefghi"),
+    ///     "-0004: 61 62 63 64\n+0000: 65 66 67 68\n+0004: 69\n",
+    /// );
+    /// # }
+    /// ```
+    pub fn relative_to(mut self, anchor: u64) -> Self {
+        self.anchor = Some(anchor);
+        self
+    }
+
+    /// Shows or hides the offset column.
+    pub fn show_offset(mut self, show: bool) -> Self {
+        self.show_offset = show;
+        self
+    }
+
+    /// Prints the hex digits of bytes in upper case.
+    ///
+    /// The offset column is controlled separately through
+    /// [`OffsetBase::UpperHex`].
+    pub fn uppercase(mut self, upper: bool) -> Self {
+        self.uppercase = upper;
+        self
+    }
+
+    /// Sets how bytes are written in the hex column.
+    pub fn byte_format(mut self, format: ByteFormat) -> Self {
+        self.byte_format = format;
+        self
+    }
+
+    /// Sets the text printed between the offset and the hex column.
+    pub fn offset_separator(mut self, sep: &'static str) -> Self {
+        self.offset_separator = sep;
+        self
+    }
+
+    /// Sets the text printed between two groups of hex digits.
+    pub fn group_separator(mut self, sep: &'static str) -> Self {
+        self.group_separator = sep;
+        self
+    }
+
+    /// Sets the text printed between two bytes of the same group.
+    pub fn byte_separator(mut self, sep: &'static str) -> Self {
+        self.byte_separator = sep;
+        self
+    }
+
+    /// Sets the text printed between the hex column and the ASCII gutter.
+    pub fn ascii_separator(mut self, sep: &'static str) -> Self {
+        self.ascii_separator = sep;
+        self
+    }
+
+    /// Sets the delimiters printed on either side of the ASCII gutter.
+    pub fn ascii_delimiters(mut self, left: &'static str, right: &'static str) -> Self {
+        self.ascii_left = left;
+        self.ascii_right = right;
+        self
+    }
+
+    /// Shows or hides the ASCII gutter.
+    pub fn show_ascii(mut self, show: bool) -> Self {
+        self.show_ascii = show;
+        self
+    }
+
+    /// Sets the character shown in the ASCII gutter for non-printable bytes.
+    pub fn placeholder(mut self, c: char) -> Self {
+        self.placeholder = c;
+        self
+    }
+
+    /// Sets when runs of identical lines are collapsed.
+    ///
+    /// The first line of a run is printed as usual and the lines repeating
+    /// it are replaced by one `*` line.
+    ///
+    /// ```
+    /// # #[cfg(feature = "std")] {
+    /// use hexdump::{HexDumper, Squeeze};
+    ///
+    /// let dumper = HexDumper::new()
+    ///     .bytes_per_line(4)
+    ///     .squeeze(Squeeze::After(2))
+    ///     .squeeze_annotation(true);
+    /// assert_eq!(
+    ///     dumper.dump(&[0; 16]),
+    ///     "00000000: 00 00 00 00  |....|\n\
+    ///      * (3 identical lines)\n",
+    /// );
+    /// assert_eq!(
+    ///     dumper.dump(&[0; 8]),
+    ///     "00000000: 00 00 00 00  |....|\n\
+    ///      00000004: 00 00 00 00  |....|\n",
+    /// );
+    /// # }
+    /// ```
+    pub fn squeeze(mut self, squeeze: Squeeze) -> Self {
+        self.squeeze = squeeze;
+        self
+    }
+
+    /// Appends the number of collapsed lines to each `*` line, as in
+    /// `* (12 identical lines)`.
+    pub fn squeeze_annotation(mut self, annotate: bool) -> Self {
+        self.squeeze_annotation = annotate;
+        self
+    }
+
+    /// Sets whether the dump ends with a line holding the final offset.
+    pub fn trailing_offset(mut self, trailing: TrailingOffset) -> Self {
+        self.trailing_offset = trailing;
+        self
+    }
+
+    /// Renders `data` as a dump, one newline-terminated line per
+    /// [`bytes_per_line`](HexDumper::bytes_per_line) bytes, squeezed and
+    /// followed by a trailing offset line if so configured.
+    #[cfg(feature = "std")]
+    pub fn dump(&self, data: &[u8]) -> String {
+        let mut out = String::new();
+        self.write_dump(&mut out, data).expect(crate::STRING_WRITE);
+        out
+    }
+
+    /// Writes the dump of `data` to `out`.
+    pub fn write_dump<W: Write>(&self, out: &mut W, data: &[u8]) -> fmt::Result {
+        self.write_dump_from(out, 0, data)
+    }
+
+    /// Writes the dump of `data`, numbering its first byte `offset`.
+    pub(crate) fn write_dump_from<W: Write>(
+        &self,
+        out: &mut W,
+        offset: u64,
+        data: &[u8],
+    ) -> fmt::Result {
+        let mut renderer = Renderer::new(self, offset);
+        let mut prev = None;
+        for line in data.chunks(self.bytes_per_line) {
+            renderer.line(out, prev, line)?;
+            prev = Some(line);
+        }
+        renderer.finish(out, prev).map(drop)
+    }
+
+    /// Writes a single dump line, without a trailing newline.
+    ///
+    /// `offset` is the value printed in the offset column and `line` holds
+    /// at most [`bytes_per_line`](HexDumper::bytes_per_line) bytes; longer
+    /// lines are an error and nothing is written. This is the formatter
+    /// every other rendering path is built on.
+    pub fn write_line<W: Write>(&self, out: &mut W, offset: u64, line: &[u8]) -> fmt::Result {
+        self.write_line_with(out, offset, line, |_| None)
+    }
+
+    /// Writes a dump line, drawing each byte for which `highlight` returns a
+    /// style in that style instead of its class style when colour is on.
+    /// `highlight` is called with the byte's index within `line`.
+    pub(crate) fn write_line_with<W, H>(
+        &self,
+        out: &mut W,
+        offset: u64,
+        line: &[u8],
+        highlight: H,
+    ) -> fmt::Result
+    where
+        W: Write,
+        H: Fn(usize) -> Option<Style>,
+    {
+        self.write_marked_line(out, offset, line, &mut Painter::new(self), highlight)
+    }
+
+    /// Writes a dump line marked up by `markup`, the one layout behind both
+    /// terminal and HTML output.
+    pub(crate) fn write_marked_line<W, M, H>(
+        &self,
+        out: &mut W,
+        offset: u64,
+        line: &[u8],
+        markup: &mut M,
+        highlight: H,
+    ) -> fmt::Result
+    where
+        W: Write,
+        M: Markup,
+        H: Fn(usize) -> Option<Style>,
+    {
+        if line.len() > self.bytes_per_line {
+            return Err(fmt::Error);
+        }
+        if markup.is_plain() {
+            let mut staged = Staged::new(out);
+            self.write_plain_line(&mut staged, offset, line)?;
+            return staged.flush();
+        }
+        markup.line(offset);
+        if self.show_offset {
+            markup.start(out, Column::Offset)?;
+            self.write_offset(out, offset)?;
+            markup.end(out)?;
+            markup.text(out, self.offset_separator)?;
+        }
+        markup.start(out, Column::Hex)?;
+        self.write_hex(out, markup, line, &highlight)?;
+        markup.end(out)?;
+        if self.show_ascii {
+            self.pad_hex(out, line.len())?;
+            markup.text(out, self.ascii_separator)?;
+            markup.text(out, self.ascii_left)?;
+            markup.start(out, Column::Ascii)?;
+            for (i, &b) in line.iter().enumerate() {
+                markup.byte(out, b, highlight(i))?;
+                let c = if is_printable(b) {
+                    b as char
+                } else {
+                    self.placeholder
+                };
+                markup.text(out, c.encode_utf8(&mut [0; 4]))?;
+            }
+            markup.end(out)?;
+            markup.text(out, self.ascii_right)?;
+        }
+        Ok(())
+    }
+
+    /// Writes an uncoloured line, encoding the hex and ASCII columns a batch
+    /// of bytes at a time with [`encode`].
+    fn write_plain_line<W: Write>(
+        &self,
+        out: &mut Staged<'_, W>,
+        offset: u64,
+        line: &[u8],
+    ) -> fmt::Result {
+        if self.show_offset {
+            self.write_offset(out, offset)?;
+            out.push(self.offset_separator)?;
+        }
+        match self.byte_format {
+            ByteFormat::Hex => self.write_plain_hex(out, line)?,
+            ByteFormat::Binary => self.write_hex(out, &mut Painter::new(self), line, &|_| None)?,
+        }
+        if self.show_ascii {
+            self.pad_hex(out, line.len())?;
+            out.push(self.ascii_separator)?;
+            out.push(self.ascii_left)?;
+            match u8::try_from(self.placeholder) {
+                Ok(placeholder) if placeholder.is_ascii() => {
+                    let mut text = [0u8; BATCH];
+                    for batch in line.chunks(BATCH) {
+                        let text = &mut text[..batch.len()];
+                        encode::ascii(batch, text, placeholder);
+                        out.push_ascii(text)?;
+                    }
+                }
+                _ => {
+                    for &b in line {
+                        let c = if is_printable(b) {
+                            b as char
+                        } else {
+                            self.placeholder
+                        };
+                        out.write_char(c)?;
+                    }
+                }
+            }
+            out.push(self.ascii_right)?;
+        }
+        Ok(())
+    }
+
+    fn write_plain_hex<W: Write>(&self, out: &mut Staged<'_, W>, line: &[u8]) -> fmt::Result {
+        let group = self.effective_group_size();
+        let group_separator = self.group_separator.as_bytes();
+        let byte_separator = self.byte_separator.as_bytes();
+        let separator = group_separator.len().max(byte_separator.len());
+        let mut hex = [0u8; 2 * BATCH];
+        // Bytes written so far in the current group, counted rather than
+        // computed so the loop needs no division.
+        let mut in_group = 0;
+        for batch in line.chunks(BATCH) {
+            let hex = &mut hex[..2 * batch.len()];
+            encode::hex(batch, hex, self.uppercase);
+            let Some(spare) = out.spare(batch.len() * (2 + separator))? else {
+                // Separators too long to stage; write piece by piece.
+                for pair in hex.chunks_exact(2) {
+                    if in_group == group {
+                        out.push(self.group_separator)?;
+                        in_group = 0;
+                    } else if in_group > 0 {
+                        out.push(self.byte_separator)?;
+                    }
+                    out.push_ascii(pair)?;
+                    in_group += 1;
+                }
+                continue;
+            };
+            if byte_separator.is_empty() && group >= self.bytes_per_line {
+                // A single group: the digits go out as they are.
+                spare[..hex.len()].copy_from_slice(hex);
+                out.advance(hex.len());
+                continue;
+            }
+            let mut at = 0;
+            for pair in hex.chunks_exact(2) {
+                if in_group > 0 {
+                    let sep = if in_group == group {
+                        in_group = 0;
+                        group_separator
+                    } else {
+                        byte_separator
+                    };
+                    for &c in sep {
+                        spare[at] = c;
+                        at += 1;
+                    }
+                }
+                spare[at] = pair[0];
+                spare[at + 1] = pair[1];
+                at += 2;
+                in_group += 1;
+            }
+            out.advance(at);
+        }
+        Ok(())
+    }
+
+    /// Writes the offset column for the byte at `offset` into the input.
+    pub(crate) fn write_offset<W: Write>(&self, out: &mut W, offset: u64) -> fmt::Result {
+        let (sign, value, width) = self.displayed_offset(offset);
+        if let Some(sign) = sign {
+            out.write_char(sign)?;
+        }
+        match self.offset_base {
+            OffsetBase::Hex => write_hex_offset(out, value, width, LOWER),
+            OffsetBase::UpperHex => write_hex_offset(out, value, width, UPPER),
+            OffsetBase::Decimal => write!(out, "{value:0width$}"),
+            OffsetBase::Octal => write!(out, "{value:0width$o}"),
+        }
+    }
+
+    /// Splits the offset column for the byte at `offset` into its sign, the
+    /// value printed and the width it is padded to.
+    fn displayed_offset(&self, offset: u64) -> (Option<char>, u64, usize) {
+        if self.base_address.is_none() && self.anchor.is_none() {
+            return (None, offset, self.offset_width);
+        }
+        let address = self.base_address.unwrap_or(0).wrapping_add(offset);
+        let (sign, value) = match self.anchor {
+            Some(anchor) if address < anchor => (Some('-'), anchor - address),
+            Some(anchor) => (Some('+'), address - anchor),
+            None => (None, address),
+        };
+        let width = if value > u64::from(u32::MAX) {
+            self.offset_width.max(digits(u64::MAX, self.offset_radix()))
+        } else {
+            self.offset_width
+        };
+        (sign, value, width)
+    }
+
+    fn offset_radix(&self) -> u64 {
+        match self.offset_base {
+            OffsetBase::Hex | OffsetBase::UpperHex => 16,
+            OffsetBase::Decimal => 10,
+            OffsetBase::Octal => 8,
+        }
+    }
+
+    fn write_hex<W: Write, M: Markup>(
+        &self,
+        out: &mut W,
+        markup: &mut M,
+        line: &[u8],
+        highlight: &impl Fn(usize) -> Option<Style>,
+    ) -> fmt::Result {
+        let digits = if self.uppercase { UPPER } else { LOWER };
+        let group = self.effective_group_size();
+        for (i, &b) in line.iter().enumerate() {
+            let style = highlight(i);
+            if i > 0 {
+                markup.separator(out, b, style)?;
+                markup.text(
+                    out,
+                    if i % group == 0 {
+                        self.group_separator
+                    } else {
+                        self.byte_separator
+                    },
+                )?;
+            }
+            markup.byte(out, b, style)?;
+            match self.byte_format {
+                ByteFormat::Hex => {
+                    out.write_char(digits[(b >> 4) as usize] as char)?;
+                    out.write_char(digits[(b & 0xf) as usize] as char)?;
+                }
+                ByteFormat::Binary => write!(out, "{b:08b}")?,
+            }
+        }
+        Ok(())
+    }
+
+    /// Pads the hex column of a line holding `len` bytes to full width.
+    fn pad_hex<W: Write>(&self, out: &mut W, len: usize) -> fmt::Result {
+        if len == self.bytes_per_line {
+            return Ok(());
+        }
+        let pad = self.hex_width() - self.hex_width_for(len);
+        for _ in 0..pad {
+            out.write_char(' ')?;
+        }
+        Ok(())
+    }
+
+    /// Visible width in characters of a line holding `len` bytes at
+    /// `offset`, not counting colour escape codes.
+    #[cfg(feature = "std")]
+    pub(crate) fn line_width(&self, offset: u64, len: usize) -> usize {
+        let mut width = 0;
+        if self.show_offset {
+            let (sign, value, digits_width) = self.displayed_offset(offset);
+            width += usize::from(sign.is_some())
+                + digits(value, self.offset_radix()).max(digits_width)
+                + self.offset_separator.chars().count();
+        }
+        if self.show_ascii {
+            width += self.hex_width()
+                + self.ascii_separator.chars().count()
+                + self.ascii_left.chars().count()
+                + len
+                + self.ascii_right.chars().count();
+        } else {
+            width += self.hex_width_for(len);
+        }
+        width
+    }
+
+    /// Width in characters of the hex column of a full line.
+    pub(crate) fn hex_width(&self) -> usize {
+        self.hex_width_for(self.bytes_per_line)
+    }
+
+    fn hex_width_for(&self, len: usize) -> usize {
+        if len == 0 {
+            return 0;
+        }
+        let groups = len.div_ceil(self.effective_group_size());
+        len * self.byte_format.width()
+            + (len - groups) * self.byte_separator.chars().count()
+            + (groups - 1) * self.group_separator.chars().count()
+    }
+
+    pub(crate) fn effective_group_size(&self) -> usize {
+        if self.group_size == 0 {
+            self.bytes_per_line
+        } else {
+            self.group_size
+        }
+    }
+}
+
+/// Writes `value` in hex, zero padded to `width` digits, without going
+/// through the formatting machinery: offsets are written once per line.
+fn write_hex_offset<W: Write>(
+    out: &mut W,
+    value: u64,
+    width: usize,
+    table: &[u8; 16],
+) -> fmt::Result {
+    let mut buf = [b'0'; 16];
+    let len = digits(value, 16);
+    for (i, c) in buf[16 - len..].iter_mut().rev().enumerate() {
+        *c = table[(value >> (4 * i) & 0xf) as usize];
+    }
+    for _ in len..width {
+        out.write_char('0')?;
+    }
+    out.write_str(core::str::from_utf8(&buf[16 - len..]).expect("hex digits are ASCII"))
+}
+
+/// Number of digits in `value` written in base `radix`.
+fn digits(value: u64, radix: u64) -> usize {
+    value.checked_ilog(radix).unwrap_or(0) as usize + 1
+}
+
+/// Bytes encoded per call into [`encode`] when writing uncoloured lines.
+const BATCH: usize = 64;
+
+/// Collects text in a stack buffer so that it reaches the underlying writer
+/// in a few large writes rather than one per character.
+struct Staged<'a, W> {
+    out: &'a mut W,
+    buf: [u8; 256],
+    len: usize,
+}
+
+impl<'a, W: Write> Staged<'a, W> {
+    fn new(out: &'a mut W) -> Self {
+        Staged {
+            out,
+            buf: [0; 256],
+            len: 0,
+        }
+    }
+
+    fn push(&mut self, s: &str) -> fmt::Result {
+        if s.len() > self.buf.len() - self.len {
+            self.flush()?;
+            if s.len() > self.buf.len() {
+                return self.out.write_str(s);
+            }
+        }
+        self.buf[self.len..self.len + s.len()].copy_from_slice(s.as_bytes());
+        self.len += s.len();
+        Ok(())
+    }
+
+    /// Appends ASCII text, which may be split across flushes.
+    fn push_ascii(&mut self, mut text: &[u8]) -> fmt::Result {
+        debug_assert!(text.is_ascii());
+        while !text.is_empty() {
+            if self.len == self.buf.len() {
+                self.flush()?;
+            }
+            let n = text.len().min(self.buf.len() - self.len);
+            self.buf[self.len..self.len + n].copy_from_slice(&text[..n]);
+            self.len += n;
+            text = &text[n..];
+        }
+        Ok(())
+    }
+
+    /// Returns `n` bytes of buffer space to fill directly, to be committed
+    /// with [`advance`](Staged::advance), or `None` if `n` is more than the
+    /// buffer holds.
+    fn spare(&mut self, n: usize) -> Result<Option<&mut [u8]>, fmt::Error> {
+        if n > self.buf.len() {
+            return Ok(None);
+        }
+        if n > self.buf.len() - self.len {
+            self.flush()?;
+        }
+        Ok(Some(&mut self.buf[self.len..self.len + n]))
+    }
+
+    /// Commits `n` bytes written to the space returned by
+    /// [`spare`](Staged::spare). They must end on a character boundary.
+    fn advance(&mut self, n: usize) {
+        self.len += n;
+    }
+
+    fn flush(&mut self) -> fmt::Result {
+        let text = core::str::from_utf8(&self.buf[..self.len]).expect("staged text is UTF-8");
+        self.len = 0;
+        self.out.write_str(text)
+    }
+}
+
+impl<W: Write> Write for Staged<'_, W> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        self.push(s)
+    }
+}
+
+/// Returns `true` for bytes shown as themselves in the ASCII gutter.
+pub fn is_printable(b: u8) -> bool {
+    (0x20..0x7f).contains(&b)
+}
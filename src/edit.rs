@@ -0,0 +1,567 @@
+//! Editing files of any size, enabled by the `mmap` feature.
+//!
+//! A [`Buffer`] is a piece table: the file is memory mapped and never
+//! copied, bytes typed or pasted go to a separate append-only buffer, and
+//! the contents are described by a list of pieces taken from one or the
+//! other. Editing a multi-gigabyte disk image therefore costs memory in
+//! proportion to the edits, not to the file.
+//!
+//! Every edit can be undone and redone. [`Buffer::save`] writes the new
+//! contents to a temporary file next to the original and renames it over
+//! the original, so a crash never leaves a half-written file behind, and it
+//! refuses to save if the file was changed by someone else in the meantime.
+//!
+//! ```no_run
+//! use hexdump::edit::Buffer;
+//!
+//! let mut config = Buffer::open("config.bin")?;
+//! config.replace(0x10, &[0x01, 0x00]);
+//! config.insert(0x40, b"extra");
+//! config.delete(0x80..0x84);
+//! config.undo();
+//! config.save()?;
+//! # Ok::<(), Box<dyn std::error::Error>>(())
+//! ```
+
+use std::error::Error;
+use std::ffi::OsStr;
+use std::fmt;
+use std::fs::{self, File, OpenOptions};
+use std::io::{self, BufWriter, Write};
+use std::ops::{Deref, Range};
+use std::path::{Path, PathBuf};
+use std::time::SystemTime;
+
+use memmap2::Mmap;
+
+/// Error from [`Buffer::save`].
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum SaveError {
+    /// The buffer was not opened from a file; use [`Buffer::save_as`].
+    NoFile,
+    /// The file was modified, replaced or removed since it was opened or
+    /// last saved. Saving would lose those changes.
+    ChangedOnDisk,
+    /// Writing the file failed. The original file is left as it was.
+    Io(io::Error),
+}
+
+impl fmt::Display for SaveError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SaveError::NoFile => f.write_str("the buffer has no file to save to"),
+            SaveError::ChangedOnDisk => f.write_str("the file changed on disk since it was opened"),
+            SaveError::Io(err) => err.fmt(f),
+        }
+    }
+}
+
+impl Error for SaveError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            SaveError::Io(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for SaveError {
+    fn from(err: io::Error) -> Self {
+        SaveError::Io(err)
+    }
+}
+
+/// An editable byte buffer backed by a piece table.
+pub struct Buffer {
+    original: Original,
+    /// Bytes added by edits. Only ever appended to, so pieces stay valid.
+    added: Vec<u8>,
+    state: State,
+    undo: Vec<Edit>,
+    redo: Vec<Edit>,
+    /// Version of the contents last loaded or saved.
+    saved: u64,
+    /// Source of unique version numbers.
+    versions: u64,
+    path: Option<PathBuf>,
+    stamp: Option<Stamp>,
+}
+
+/// The bytes a buffer was opened with.
+enum Original {
+    Mapped(Mmap),
+    Owned(Vec<u8>),
+}
+
+impl Deref for Original {
+    type Target = [u8];
+
+    fn deref(&self) -> &[u8] {
+        match self {
+            Original::Mapped(map) => map,
+            Original::Owned(bytes) => bytes,
+        }
+    }
+}
+
+/// The current contents.
+struct State {
+    pieces: Vec<Piece>,
+    /// Offset of the first byte of each piece, for lookups.
+    starts: Vec<u64>,
+    len: u64,
+    version: u64,
+}
+
+/// A step through the edit history: replaces `len` bytes at `at` with
+/// `pieces`. Undoing an edit applies its inverse, which holds only the
+/// pieces the edit removed.
+struct Edit {
+    at: u64,
+    len: u64,
+    pieces: Vec<Piece>,
+    /// Version of the contents once the step is taken.
+    version: u64,
+}
+
+/// A run of bytes from the original or the added buffer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Piece {
+    added: bool,
+    start: usize,
+    len: usize,
+}
+
+/// What a file looked like on disk, to notice changes by others.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Stamp {
+    len: u64,
+    modified: Option<SystemTime>,
+    /// Device and inode numbers, which tell a file replaced by another
+    /// apart even when its length and modification time were kept.
+    #[cfg(unix)]
+    id: (u64, u64),
+}
+
+impl Stamp {
+    fn of(path: &Path) -> io::Result<Stamp> {
+        #[cfg(unix)]
+        use std::os::unix::fs::MetadataExt;
+
+        let metadata = fs::metadata(path)?;
+        Ok(Stamp {
+            len: metadata.len(),
+            modified: metadata.modified().ok(),
+            #[cfg(unix)]
+            id: (metadata.dev(), metadata.ino()),
+        })
+    }
+}
+
+impl Buffer {
+    /// Opens the file at `path` for editing.
+    ///
+    /// The file is memory mapped and must not be truncated while the
+    /// buffer is open; see [`HexDumper::dump_file`](crate::HexDumper::dump_file).
+    /// Saving replaces the file rather than writing into it, so it does not
+    /// disturb the mapping.
+    pub fn open<P: AsRef<Path>>(path: P) -> io::Result<Buffer> {
+        let path = path.as_ref();
+        let file = File::open(path)?;
+        let original = if file.metadata()?.len() == 0 {
+            Original::Owned(Vec::new())
+        } else {
+            // SAFETY: the mapping is only read, and `open` documents that
+            // the file must not be truncated meanwhile.
+            Original::Mapped(unsafe { Mmap::map(&file)? })
+        };
+        let mut buffer = Buffer::with_original(original);
+        buffer.stamp = Some(Stamp::of(path)?);
+        buffer.path = Some(path.to_owned());
+        Ok(buffer)
+    }
+
+    /// Creates a buffer holding `bytes`, not tied to any file.
+    pub fn new(bytes: Vec<u8>) -> Buffer {
+        Buffer::with_original(Original::Owned(bytes))
+    }
+
+    fn with_original(original: Original) -> Buffer {
+        let len = original.len();
+        let pieces = if len == 0 {
+            Vec::new()
+        } else {
+            vec![Piece {
+                added: false,
+                start: 0,
+                len,
+            }]
+        };
+        Buffer {
+            original,
+            added: Vec::new(),
+            state: State {
+                starts: vec![0; pieces.len()],
+                pieces,
+                len: len as u64,
+                version: 0,
+            },
+            undo: Vec::new(),
+            redo: Vec::new(),
+            saved: 0,
+            versions: 0,
+            path: None,
+            stamp: None,
+        }
+    }
+
+    /// The file the buffer was opened from or last saved to.
+    pub fn path(&self) -> Option<&Path> {
+        self.path.as_deref()
+    }
+
+    /// The length of the contents.
+    pub fn len(&self) -> u64 {
+        self.state.len
+    }
+
+    /// Whether the buffer is empty.
+    pub fn is_empty(&self) -> bool {
+        self.state.len == 0
+    }
+
+    /// Whether the contents differ from the file as last opened or saved.
+    pub fn is_modified(&self) -> bool {
+        self.state.version != self.saved
+    }
+
+    /// The byte at `offset`, if there is one.
+    pub fn get(&self, offset: u64) -> Option<u8> {
+        let mut byte = [0];
+        (self.read_at(offset, &mut byte) == 1).then_some(byte[0])
+    }
+
+    /// Copies the bytes starting at `offset` into `buf`, returning how many
+    /// were copied: fewer than `buf.len()` only at the end of the contents.
+    pub fn read_at(&self, offset: u64, buf: &mut [u8]) -> usize {
+        if offset >= self.state.len {
+            return 0;
+        }
+        let first = self.state.starts.partition_point(|&start| start <= offset) - 1;
+        let mut copied = 0;
+        let mut skip = (offset - self.state.starts[first]) as usize;
+        for piece in &self.state.pieces[first..] {
+            if copied == buf.len() {
+                break;
+            }
+            let bytes = &self.bytes(piece)[skip..];
+            let n = bytes.len().min(buf.len() - copied);
+            buf[copied..copied + n].copy_from_slice(&bytes[..n]);
+            copied += n;
+            skip = 0;
+        }
+        copied
+    }
+
+    /// Copies the bytes in `range`, clamped to the contents.
+    pub fn slice(&self, range: Range<u64>) -> Vec<u8> {
+        let end = range.end.min(self.state.len);
+        let mut bytes = vec![0; end.saturating_sub(range.start) as usize];
+        let n = self.read_at(range.start, &mut bytes);
+        bytes.truncate(n);
+        bytes
+    }
+
+    /// Writes the whole contents to `writer`.
+    pub fn write_to<W: Write>(&self, mut writer: W) -> io::Result<()> {
+        for piece in &self.state.pieces {
+            writer.write_all(self.bytes(piece))?;
+        }
+        writer.flush()
+    }
+
+    /// Overwrites the bytes at `offset` with `bytes`, extending the
+    /// contents if they run past the end.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `offset` is past the end of the contents.
+    pub fn replace(&mut self, offset: u64, bytes: &[u8]) {
+        let removed = (self.state.len - offset.min(self.state.len)).min(bytes.len() as u64);
+        self.splice(offset, removed, bytes);
+    }
+
+    /// Overwrites the bytes at `offset` like [`replace`](Buffer::replace),
+    /// as part of the last edit if they lie within the bytes it wrote, so
+    /// that one undo reverts both. Typing a byte a digit at a time is one
+    /// edit this way.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `offset` is past the end of the contents.
+    pub fn amend(&mut self, offset: u64, bytes: &[u8]) {
+        let end = offset + bytes.len() as u64;
+        let merge = !bytes.is_empty()
+            && self.redo.is_empty()
+            && self
+                .undo
+                .last()
+                .is_some_and(|last| last.at <= offset && end <= last.at + last.len);
+        self.replace(offset, bytes);
+        if merge {
+            // Undoing the last edit already restores what these bytes cover.
+            self.undo.pop();
+        }
+    }
+
+    /// Inserts `bytes` before the byte at `offset`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `offset` is past the end of the contents.
+    pub fn insert(&mut self, offset: u64, bytes: &[u8]) {
+        self.splice(offset, 0, bytes);
+    }
+
+    /// Removes the bytes in `range`, clamped to the contents.
+    pub fn delete(&mut self, range: Range<u64>) {
+        let end = range.end.min(self.state.len);
+        if range.start < end {
+            self.splice(range.start, end - range.start, &[]);
+        }
+    }
+
+    /// Undoes the last edit, returning the offset where it was made, or
+    /// `None` if there is nothing to undo.
+    pub fn undo(&mut self) -> Option<u64> {
+        let edit = self.undo.pop()?;
+        let at = edit.at;
+        let inverse = self.apply(edit);
+        self.redo.push(inverse);
+        Some(at)
+    }
+
+    /// Redoes the last undone edit, returning the offset where it was made,
+    /// or `None` if there is nothing to redo.
+    pub fn redo(&mut self) -> Option<u64> {
+        let edit = self.redo.pop()?;
+        let at = edit.at;
+        let inverse = self.apply(edit);
+        self.undo.push(inverse);
+        Some(at)
+    }
+
+    /// Whether the file has been modified, replaced or removed since it was
+    /// opened or last saved, judged by its length and modification time
+    /// and, on Unix, by its device and inode numbers.
+    pub fn changed_on_disk(&self) -> io::Result<bool> {
+        let (Some(path), Some(stamp)) = (&self.path, self.stamp) else {
+            return Ok(false);
+        };
+        match Stamp::of(path) {
+            Ok(now) => Ok(now != stamp),
+            Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(true),
+            Err(err) => Err(err),
+        }
+    }
+
+    /// Saves the contents to the file they were opened from.
+    ///
+    /// The contents are written to a temporary file in the same directory,
+    /// flushed to disk and renamed over the original, which keeps its
+    /// permissions. A symbolic link is followed, so the file it points to is
+    /// replaced rather than the link. Fails with
+    /// [`SaveError::ChangedOnDisk`] if the file was changed since it was
+    /// opened or last saved.
+    pub fn save(&mut self) -> Result<(), SaveError> {
+        let path = self.path.clone().ok_or(SaveError::NoFile)?;
+        if self.changed_on_disk()? {
+            return Err(SaveError::ChangedOnDisk);
+        }
+        self.save_as(path)?;
+        Ok(())
+    }
+
+    /// Saves the contents to `path`, replacing any file there, the same way
+    /// [`save`](Buffer::save) does. Later saves go to `path`.
+    pub fn save_as<P: AsRef<Path>>(&mut self, path: P) -> io::Result<()> {
+        let path = path.as_ref();
+        self.write_atomically(path)?;
+        self.stamp = Some(Stamp::of(path)?);
+        self.path = Some(path.to_owned());
+        self.saved = self.state.version;
+        Ok(())
+    }
+
+    fn write_atomically(&self, path: &Path) -> io::Result<()> {
+        let path = match fs::canonicalize(path) {
+            Ok(path) => path,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => path.to_owned(),
+            Err(err) => return Err(err),
+        };
+        let path = path.as_path();
+        let dir = match path.parent() {
+            Some(dir) if !dir.as_os_str().is_empty() => dir,
+            _ => Path::new("."),
+        };
+        let name = path.file_name().ok_or_else(|| {
+            io::Error::new(io::ErrorKind::InvalidInput, "path does not name a file")
+        })?;
+        let (temp, file) = create_temp(dir, name)?;
+        let result = (|| {
+            if let Ok(metadata) = fs::metadata(path) {
+                file.set_permissions(metadata.permissions())?;
+            }
+            self.write_to(BufWriter::new(&file))?;
+            file.sync_all()?;
+            fs::rename(&temp, path)
+        })();
+        if result.is_err() {
+            let _ = fs::remove_file(&temp);
+            return result;
+        }
+        // Make the rename itself durable.
+        #[cfg(unix)]
+        if let Ok(dir) = File::open(dir) {
+            let _ = dir.sync_all();
+        }
+        Ok(())
+    }
+
+    fn bytes(&self, piece: &Piece) -> &[u8] {
+        let source = if piece.added {
+            &self.added[..]
+        } else {
+            &self.original[..]
+        };
+        &source[piece.start..piece.start + piece.len]
+    }
+
+    /// Replaces `removed` bytes at `offset` with `bytes`, as one step of
+    /// the edit history.
+    fn splice(&mut self, offset: u64, removed: u64, bytes: &[u8]) {
+        assert!(
+            offset <= self.state.len,
+            "offset {offset} is past the end of the buffer ({} bytes)",
+            self.state.len
+        );
+        if removed == 0 && bytes.is_empty() {
+            return;
+        }
+        let mut pieces = Vec::new();
+        if !bytes.is_empty() {
+            pieces.push(Piece {
+                added: true,
+                start: self.added.len(),
+                len: bytes.len(),
+            });
+            self.added.extend_from_slice(bytes);
+        }
+        self.versions += 1;
+        let inverse = self.apply(Edit {
+            at: offset,
+            len: removed,
+            pieces,
+            version: self.versions,
+        });
+        self.undo.push(inverse);
+        self.redo.clear();
+    }
+
+    /// Takes a step through the edit history, returning the step back.
+    fn apply(&mut self, edit: Edit) -> Edit {
+        let end = edit.at + edit.len;
+        let inserted = edit.pieces.iter().map(|piece| piece.len as u64).sum();
+        let mut pieces = Vec::with_capacity(self.state.pieces.len() + edit.pieces.len() + 1);
+        let mut removed = Vec::new();
+        let mut insert = Some(edit.pieces);
+        for (piece, &start) in self.state.pieces.iter().zip(&self.state.starts) {
+            let piece_end = start + piece.len as u64;
+            if start < edit.at {
+                let keep = (edit.at.min(piece_end) - start) as usize;
+                pieces.push(Piece {
+                    len: keep,
+                    ..*piece
+                });
+            }
+            if piece_end >= edit.at {
+                pieces.extend(insert.take().into_iter().flatten());
+            }
+            if start.max(edit.at) < end.min(piece_end) {
+                let skip = edit.at.saturating_sub(start) as usize;
+                removed.push(Piece {
+                    start: piece.start + skip,
+                    len: (end.min(piece_end) - start) as usize - skip,
+                    ..*piece
+                });
+            }
+            if piece_end > end {
+                let skip = end.saturating_sub(start) as usize;
+                pieces.push(Piece {
+                    start: piece.start + skip,
+                    len: piece.len - skip,
+                    ..*piece
+                });
+            }
+        }
+        pieces.extend(insert.into_iter().flatten());
+
+        let inverse = Edit {
+            at: edit.at,
+            len: inserted,
+            pieces: removed,
+            version: self.state.version,
+        };
+        self.state = State::new(pieces, edit.version);
+        inverse
+    }
+}
+
+/// Creates a temporary file in `dir` to save over `name` with. Names left
+/// behind by a crashed save are skipped.
+fn create_temp(dir: &Path, name: &OsStr) -> io::Result<(PathBuf, File)> {
+    let mut attempt = 0;
+    loop {
+        let temp = dir.join(format!(
+            ".{}.{}.{attempt}.tmp",
+            name.to_string_lossy(),
+            std::process::id()
+        ));
+        match OpenOptions::new().write(true).create_new(true).open(&temp) {
+            Ok(file) => return Ok((temp, file)),
+            Err(err) if err.kind() == io::ErrorKind::AlreadyExists && attempt < 100 => {
+                attempt += 1;
+            }
+            Err(err) => return Err(err),
+        }
+    }
+}
+
+impl State {
+    /// Builds a state from pieces, merging neighbours that continue each
+    /// other so that typing a run of bytes leaves one piece.
+    fn new(pieces: Vec<Piece>, version: u64) -> State {
+        let mut merged: Vec<Piece> = Vec::with_capacity(pieces.len());
+        for piece in pieces.into_iter().filter(|piece| piece.len > 0) {
+            match merged.last_mut() {
+                Some(last) if last.added == piece.added && last.start + last.len == piece.start => {
+                    last.len += piece.len;
+                }
+                _ => merged.push(piece),
+            }
+        }
+        let mut starts = Vec::with_capacity(merged.len());
+        let mut len = 0;
+        for piece in &merged {
+            starts.push(len);
+            len += piece.len as u64;
+        }
+        State {
+            pieces: merged,
+            starts,
+            len,
+            version,
+        }
+    }
+}
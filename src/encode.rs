@@ -0,0 +1,172 @@
+//! Bulk hex encoding and ASCII gutter rendering.
+//!
+//! These are the inner loops of every dump. [`hex`] and [`ascii`] run on
+//! the fastest [`Backend`] the CPU supports, picked at run time: AVX2 or
+//! SSE2 on x86_64 and NEON on aarch64, with a portable scalar loop
+//! everywhere else. All backends produce identical output.
+//!
+//! ```
+//! use hexdump::encode;
+//!
+//! let mut hex = [0u8; 8];
+//! encode::hex(b"\x00\x7f\xa0\xff", &mut hex, false);
+//! assert_eq!(&hex, b"007fa0ff");
+//!
+//! let mut text = [0u8; 4];
+//! encode::ascii(b"a\x00b\xff", &mut text, b'.');
+//! assert_eq!(&text, b"a.b.");
+//! ```
+
+#[cfg(target_arch = "aarch64")]
+mod neon;
+#[cfg(target_arch = "x86_64")]
+mod x86;
+
+/// Writes the two hex digits of each byte of `src` to `dst`, high digit
+/// first, in upper case if `upper` is set.
+///
+/// # Panics
+///
+/// Panics if `dst` is not exactly twice as long as `src`.
+pub fn hex(src: &[u8], dst: &mut [u8], upper: bool) {
+    Backend::detect().hex(src, dst, upper);
+}
+
+/// Copies printable ASCII bytes of `src` to `dst` and replaces all others
+/// with `placeholder`, as in the ASCII gutter of a dump.
+///
+/// # Panics
+///
+/// Panics if `dst` and `src` differ in length.
+pub fn ascii(src: &[u8], dst: &mut [u8], placeholder: u8) {
+    Backend::detect().ascii(src, dst, placeholder);
+}
+
+/// An implementation of the encoding loops.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum Backend {
+    /// Portable byte-at-a-time loops, available everywhere.
+    Scalar,
+    /// 16 bytes at a time with SSE2, available on every x86_64 CPU.
+    Sse2,
+    /// 32 bytes at a time with AVX2 on x86_64.
+    Avx2,
+    /// 16 bytes at a time with NEON, available on every aarch64 CPU.
+    Neon,
+}
+
+impl Backend {
+    /// Every backend, available or not.
+    pub const ALL: [Backend; 4] = [Backend::Scalar, Backend::Sse2, Backend::Avx2, Backend::Neon];
+
+    /// The fastest backend this CPU supports.
+    ///
+    /// Without the `std` feature, CPU features cannot be queried at run
+    /// time and only those enabled at compile time are used.
+    pub fn detect() -> Self {
+        if Backend::Avx2.is_available() {
+            Backend::Avx2
+        } else if Backend::Sse2.is_available() {
+            Backend::Sse2
+        } else if Backend::Neon.is_available() {
+            Backend::Neon
+        } else {
+            Backend::Scalar
+        }
+    }
+
+    /// Whether this backend can run on this CPU.
+    pub fn is_available(self) -> bool {
+        match self {
+            Backend::Scalar => true,
+            Backend::Sse2 => cfg!(target_arch = "x86_64"),
+            Backend::Avx2 => avx2_detected(),
+            Backend::Neon => cfg!(target_arch = "aarch64"),
+        }
+    }
+
+    /// Runs [`hex`] on this backend.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the backend is not available, or if `dst` is not exactly
+    /// twice as long as `src`.
+    pub fn hex(self, src: &[u8], dst: &mut [u8], upper: bool) {
+        assert_eq!(
+            dst.len(),
+            src.len() * 2,
+            "dst must hold two digits per byte"
+        );
+        match self {
+            #[cfg(target_arch = "x86_64")]
+            Backend::Sse2 => x86::hex_sse2(src, dst, upper),
+            #[cfg(target_arch = "x86_64")]
+            Backend::Avx2 if avx2_detected() => {
+                // SAFETY: AVX2 support was just checked.
+                unsafe { x86::hex_avx2(src, dst, upper) }
+            }
+            #[cfg(target_arch = "aarch64")]
+            Backend::Neon => neon::hex(src, dst, upper),
+            Backend::Scalar => scalar_hex(src, dst, upper),
+            _ => panic!("{self:?} is not available on this CPU"),
+        }
+    }
+
+    /// Runs [`ascii`] on this backend.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the backend is not available, or if `dst` and `src` differ
+    /// in length.
+    pub fn ascii(self, src: &[u8], dst: &mut [u8], placeholder: u8) {
+        assert_eq!(dst.len(), src.len(), "dst must hold one byte per byte");
+        match self {
+            #[cfg(target_arch = "x86_64")]
+            Backend::Sse2 => x86::ascii_sse2(src, dst, placeholder),
+            #[cfg(target_arch = "x86_64")]
+            Backend::Avx2 if avx2_detected() => {
+                // SAFETY: AVX2 support was just checked.
+                unsafe { x86::ascii_avx2(src, dst, placeholder) }
+            }
+            #[cfg(target_arch = "aarch64")]
+            Backend::Neon => neon::ascii(src, dst, placeholder),
+            Backend::Scalar => scalar_ascii(src, dst, placeholder),
+            _ => panic!("{self:?} is not available on this CPU"),
+        }
+    }
+}
+
+#[cfg(all(target_arch = "x86_64", feature = "std"))]
+fn avx2_detected() -> bool {
+    std::is_x86_feature_detected!("avx2")
+}
+
+#[cfg(not(all(target_arch = "x86_64", feature = "std")))]
+fn avx2_detected() -> bool {
+    cfg!(all(target_arch = "x86_64", target_feature = "avx2"))
+}
+
+pub(crate) const LOWER: &[u8; 16] = b"0123456789abcdef";
+pub(crate) const UPPER: &[u8; 16] = b"0123456789ABCDEF";
+
+/// The scalar [`hex`] loop, also used for the tails the vector loops leave.
+fn scalar_hex(src: &[u8], dst: &mut [u8], upper: bool) {
+    let digits = if upper { UPPER } else { LOWER };
+    for (&b, pair) in src.iter().zip(dst.chunks_exact_mut(2)) {
+        pair[0] = digits[(b >> 4) as usize];
+        pair[1] = digits[(b & 0xf) as usize];
+    }
+}
+
+/// The scalar [`ascii`] loop, also used for the tails the vector loops
+/// leave.
+fn scalar_ascii(src: &[u8], dst: &mut [u8], placeholder: u8) {
+    for (&b, out) in src.iter().zip(dst) {
+        *out = if crate::is_printable(b) {
+            b
+        } else {
+            placeholder
+        };
+    }
+}
@@ -0,0 +1,40 @@
+use core::arch::aarch64::*;
+
+use super::{scalar_ascii, scalar_hex, LOWER, UPPER};
+
+pub(super) fn hex(src: &[u8], dst: &mut [u8], upper: bool) {
+    let digits = if upper { UPPER } else { LOWER };
+    let mut chunks = src.chunks_exact(16);
+    let mut out = dst.chunks_exact_mut(32);
+    // SAFETY: NEON is part of the aarch64 baseline, and every load and
+    // store stays within a chunk of the right size.
+    unsafe {
+        let table = vld1q_u8(digits.as_ptr());
+        let mask = vdupq_n_u8(0x0f);
+        for (s, d) in (&mut chunks).zip(&mut out) {
+            let v = vld1q_u8(s.as_ptr());
+            let hi = vqtbl1q_u8(table, vshrq_n_u8::<4>(v));
+            let lo = vqtbl1q_u8(table, vandq_u8(v, mask));
+            // Stores the two vectors interleaved: hi[0], lo[0], hi[1], ...
+            vst2q_u8(d.as_mut_ptr(), uint8x16x2_t(hi, lo));
+        }
+    }
+    scalar_hex(chunks.remainder(), out.into_remainder(), upper);
+}
+
+pub(super) fn ascii(src: &[u8], dst: &mut [u8], placeholder: u8) {
+    let mut chunks = src.chunks_exact(16);
+    let mut out = dst.chunks_exact_mut(16);
+    // SAFETY: as in `hex`.
+    unsafe {
+        let fill = vdupq_n_u8(placeholder);
+        let space = vdupq_n_u8(0x20);
+        let del = vdupq_n_u8(0x7f);
+        for (s, d) in (&mut chunks).zip(&mut out) {
+            let v = vld1q_u8(s.as_ptr());
+            let printable = vandq_u8(vcgeq_u8(v, space), vcltq_u8(v, del));
+            vst1q_u8(d.as_mut_ptr(), vbslq_u8(printable, v, fill));
+        }
+    }
+    scalar_ascii(chunks.remainder(), out.into_remainder(), placeholder);
+}
@@ -0,0 +1,120 @@
+use core::arch::x86_64::*;
+
+use super::{scalar_ascii, scalar_hex};
+
+/// Turns a vector of nibbles into their hex digits.
+#[inline(always)]
+fn sse2_digits(nibbles: __m128i, letters: __m128i) -> __m128i {
+    // SAFETY: SSE2 is part of the x86_64 baseline.
+    unsafe {
+        let over_nine = _mm_cmpgt_epi8(nibbles, _mm_set1_epi8(9));
+        let base = _mm_add_epi8(nibbles, _mm_set1_epi8(b'0' as i8));
+        _mm_add_epi8(base, _mm_and_si128(over_nine, letters))
+    }
+}
+
+/// Offset from `'0' + 10` to the first letter digit.
+fn letter_offset(upper: bool) -> i8 {
+    (if upper { b'A' } else { b'a' } - b'0' - 10) as i8
+}
+
+pub(super) fn hex_sse2(src: &[u8], dst: &mut [u8], upper: bool) {
+    let mut chunks = src.chunks_exact(16);
+    let mut out = dst.chunks_exact_mut(32);
+    // SAFETY: SSE2 is part of the x86_64 baseline, and every load and store
+    // stays within a chunk of the right size.
+    unsafe {
+        let letters = _mm_set1_epi8(letter_offset(upper));
+        let mask = _mm_set1_epi8(0x0f);
+        for (s, d) in (&mut chunks).zip(&mut out) {
+            let v = _mm_loadu_si128(s.as_ptr().cast());
+            let hi = sse2_digits(_mm_and_si128(_mm_srli_epi16(v, 4), mask), letters);
+            let lo = sse2_digits(_mm_and_si128(v, mask), letters);
+            let d = d.as_mut_ptr().cast::<__m128i>();
+            _mm_storeu_si128(d, _mm_unpacklo_epi8(hi, lo));
+            _mm_storeu_si128(d.add(1), _mm_unpackhi_epi8(hi, lo));
+        }
+    }
+    scalar_hex(chunks.remainder(), out.into_remainder(), upper);
+}
+
+pub(super) fn ascii_sse2(src: &[u8], dst: &mut [u8], placeholder: u8) {
+    let mut chunks = src.chunks_exact(16);
+    let mut out = dst.chunks_exact_mut(16);
+    // SAFETY: as in `hex_sse2`.
+    unsafe {
+        let fill = _mm_set1_epi8(placeholder as i8);
+        let space = _mm_set1_epi8(0x1f);
+        let del = _mm_set1_epi8(0x7f);
+        for (s, d) in (&mut chunks).zip(&mut out) {
+            let v = _mm_loadu_si128(s.as_ptr().cast());
+            // Bytes from 0x80 up are negative, so fail the first test.
+            let printable = _mm_and_si128(_mm_cmpgt_epi8(v, space), _mm_cmplt_epi8(v, del));
+            let mixed = _mm_or_si128(
+                _mm_and_si128(printable, v),
+                _mm_andnot_si128(printable, fill),
+            );
+            _mm_storeu_si128(d.as_mut_ptr().cast(), mixed);
+        }
+    }
+    scalar_ascii(chunks.remainder(), out.into_remainder(), placeholder);
+}
+
+#[inline]
+#[target_feature(enable = "avx2")]
+fn avx2_digits(nibbles: __m256i, letters: __m256i) -> __m256i {
+    let over_nine = _mm256_cmpgt_epi8(nibbles, _mm256_set1_epi8(9));
+    let base = _mm256_add_epi8(nibbles, _mm256_set1_epi8(b'0' as i8));
+    _mm256_add_epi8(base, _mm256_and_si256(over_nine, letters))
+}
+
+/// # Safety
+///
+/// The CPU must support AVX2.
+#[target_feature(enable = "avx2")]
+pub(super) unsafe fn hex_avx2(src: &[u8], dst: &mut [u8], upper: bool) {
+    let mut chunks = src.chunks_exact(32);
+    let mut out = dst.chunks_exact_mut(64);
+    // SAFETY: the caller guarantees AVX2, and every load and store stays
+    // within a chunk of the right size.
+    unsafe {
+        let letters = _mm256_set1_epi8(letter_offset(upper));
+        let mask = _mm256_set1_epi8(0x0f);
+        for (s, d) in (&mut chunks).zip(&mut out) {
+            let v = _mm256_loadu_si256(s.as_ptr().cast());
+            let hi = avx2_digits(_mm256_and_si256(_mm256_srli_epi16(v, 4), mask), letters);
+            let lo = avx2_digits(_mm256_and_si256(v, mask), letters);
+            // Unpacking works within 128-bit lanes: `a` holds bytes 0-7 and
+            // 16-23, `b` bytes 8-15 and 24-31.
+            let a = _mm256_unpacklo_epi8(hi, lo);
+            let b = _mm256_unpackhi_epi8(hi, lo);
+            let d = d.as_mut_ptr().cast::<__m256i>();
+            _mm256_storeu_si256(d, _mm256_permute2x128_si256(a, b, 0x20));
+            _mm256_storeu_si256(d.add(1), _mm256_permute2x128_si256(a, b, 0x31));
+        }
+    }
+    hex_sse2(chunks.remainder(), out.into_remainder(), upper);
+}
+
+/// # Safety
+///
+/// The CPU must support AVX2.
+#[target_feature(enable = "avx2")]
+pub(super) unsafe fn ascii_avx2(src: &[u8], dst: &mut [u8], placeholder: u8) {
+    let mut chunks = src.chunks_exact(32);
+    let mut out = dst.chunks_exact_mut(32);
+    // SAFETY: as in `hex_avx2`.
+    unsafe {
+        let fill = _mm256_set1_epi8(placeholder as i8);
+        let space = _mm256_set1_epi8(0x1f);
+        let del = _mm256_set1_epi8(0x7f);
+        for (s, d) in (&mut chunks).zip(&mut out) {
+            let v = _mm256_loadu_si256(s.as_ptr().cast());
+            let printable =
+                _mm256_and_si256(_mm256_cmpgt_epi8(v, space), _mm256_cmpgt_epi8(del, v));
+            let mixed = _mm256_blendv_epi8(fill, v, printable);
+            _mm256_storeu_si256(d.as_mut_ptr().cast(), mixed);
+        }
+    }
+    ascii_sse2(chunks.remainder(), out.into_remainder(), placeholder);
+}
@@ -0,0 +1,235 @@
+use std::fs::File;
+use std::io::{self, Write};
+use std::ops::{Bound, RangeBounds};
+use std::path::Path;
+
+use memmap2::MmapOptions;
+
+use crate::render::Renderer;
+use crate::{HexDumper, Squeeze, STRING_WRITE};
+
+/// Most bytes mapped at once. Mapping a window at a time keeps address space
+/// use bounded, so files larger than memory, or than the address space of a
+/// 32-bit target, can be dumped.
+const WINDOW: u64 = 1 << 30;
+
+/// Text rendered before it is passed to the writer.
+const TEXT_BUFFER: usize = 1 << 16;
+
+impl HexDumper {
+    /// Dumps the bytes of the file at `path` that lie in `range`, writing
+    /// the dump to `writer`. Returns the number of bytes dumped.
+    ///
+    /// The file is memory mapped, so a dump starting deep inside a large
+    /// disk image reads nothing before the range, and the file is mapped a
+    /// window at a time, so it may be larger than memory. Lines are
+    /// numbered by their offset in the file. On Linux, holes in sparse files
+    /// are found with `SEEK_HOLE` and, when repeated lines are squeezed,
+    /// skipped without being read at all.
+    ///
+    /// The range is clamped to the end of the file; a range starting past
+    /// the end is an [`io::ErrorKind::InvalidInput`] error.
+    ///
+    /// The file must not be truncated while it is being dumped: on most
+    /// systems, touching mapped pages past the new end of a file kills the
+    /// process with `SIGBUS`.
+    ///
+    /// Requires the `mmap` feature.
+    ///
+    /// ```no_run
+    /// use hexdump::{HexDumper, Preset};
+    ///
+    /// let dumper = HexDumper::new().preset(Preset::HexdumpCanonical);
+    /// dumper.dump_file("disk.img", 0x7_4000_0000..0x7_4000_1000, std::io::stdout().lock())?;
+    /// # Ok::<(), std::io::Error>(())
+    /// ```
+    pub fn dump_file<P, R, W>(&self, path: P, range: R, mut writer: W) -> io::Result<u64>
+    where
+        P: AsRef<Path>,
+        R: RangeBounds<u64>,
+        W: Write,
+    {
+        let file = File::open(path)?;
+        let len = file.metadata()?.len();
+        let start = match range.start_bound() {
+            Bound::Included(&n) => n,
+            Bound::Excluded(&n) => n.saturating_add(1),
+            Bound::Unbounded => 0,
+        };
+        let end = match range.end_bound() {
+            Bound::Included(&n) => n.saturating_add(1),
+            Bound::Excluded(&n) => n,
+            Bound::Unbounded => u64::MAX,
+        };
+        if start > len {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "range starts past the end of the file",
+            ));
+        }
+        let end = end.clamp(start, len);
+
+        let mut dump = FileDump {
+            dumper: self,
+            file: &file,
+            renderer: Renderer::new(self, start),
+            text: String::new(),
+            last: Vec::new(),
+            start,
+        };
+        let mut pos = start;
+        while pos < end {
+            match dump.next_hole(pos, end)? {
+                Some((hole, hole_end)) => {
+                    dump.mapped(pos, hole, &mut writer)?;
+                    dump.zeros(hole, hole_end, &mut writer)?;
+                    pos = hole_end;
+                }
+                None => {
+                    dump.mapped(pos, end, &mut writer)?;
+                    pos = end;
+                }
+            }
+        }
+        let FileDump {
+            renderer,
+            mut text,
+            last,
+            ..
+        } = dump;
+        let prev = (!last.is_empty()).then_some(&last[..]);
+        let total = renderer.finish(&mut text, prev).expect(STRING_WRITE);
+        writer.write_all(text.as_bytes())?;
+        writer.flush()?;
+        Ok(total)
+    }
+}
+
+struct FileDump<'a> {
+    dumper: &'a HexDumper,
+    file: &'a File,
+    renderer: Renderer<'a>,
+    text: String,
+    /// The last line rendered, for squeezing across windows and holes.
+    last: Vec<u8>,
+    /// Offset of the first byte dumped, which lines are aligned to.
+    start: u64,
+}
+
+impl FileDump<'_> {
+    /// Renders the bytes from `from` to `to`, mapping them a window at a
+    /// time. `from` must start a line.
+    fn mapped<W: Write>(&mut self, from: u64, to: u64, writer: &mut W) -> io::Result<()> {
+        let bpl = self.dumper.bytes_per_line;
+        let window = (WINDOW / bpl as u64).max(1) * bpl as u64;
+        let mut pos = from;
+        while pos < to {
+            let len = usize::try_from(window.min(to - pos)).unwrap_or(usize::MAX);
+            // SAFETY: the mapping is only read, and `dump_file` documents
+            // that the file must not be truncated meanwhile.
+            let map = unsafe { MmapOptions::new().offset(pos).len(len).map(self.file)? };
+            #[cfg(unix)]
+            let _ = map.advise(memmap2::Advice::Sequential);
+            let mut prev = (!self.last.is_empty()).then_some(&self.last[..]);
+            for line in map.chunks(bpl) {
+                self.renderer
+                    .line(&mut self.text, prev, line)
+                    .expect(STRING_WRITE);
+                prev = Some(line);
+                if self.text.len() >= TEXT_BUFFER {
+                    writer.write_all(self.text.as_bytes())?;
+                    self.text.clear();
+                }
+            }
+            if let Some(line) = map.chunks(bpl).next_back() {
+                self.last.clear();
+                self.last.extend_from_slice(line);
+            }
+            pos += len as u64;
+        }
+        Ok(())
+    }
+
+    /// Renders the whole lines of zeros from `from` to `to` without reading
+    /// them.
+    fn zeros<W: Write>(&mut self, from: u64, to: u64, writer: &mut W) -> io::Result<()> {
+        let bpl = self.dumper.bytes_per_line;
+        let zeros = vec![0; bpl];
+        let prev = (!self.last.is_empty()).then_some(&self.last[..]);
+        self.renderer
+            .line(&mut self.text, prev, &zeros)
+            .expect(STRING_WRITE);
+        // Squeezed, any number of lines costs the same; otherwise every line
+        // is rendered, so pass them on a buffer at a time.
+        let batch = match self.dumper.squeeze {
+            Squeeze::Off => (TEXT_BUFFER / bpl).max(1) as u64,
+            _ => u64::MAX,
+        };
+        let mut rest = (to - from) / bpl as u64 - 1;
+        while rest > 0 {
+            let n = rest.min(batch);
+            self.renderer
+                .repeat(&mut self.text, &zeros, n)
+                .expect(STRING_WRITE);
+            rest -= n;
+            if self.text.len() >= TEXT_BUFFER {
+                writer.write_all(self.text.as_bytes())?;
+                self.text.clear();
+            }
+        }
+        self.last = zeros;
+        Ok(())
+    }
+
+    /// Finds the first run of whole lines at or after `pos` and before `end`
+    /// that lies in a hole of a sparse file, as a range of offsets.
+    #[cfg(any(target_os = "linux", target_os = "android"))]
+    fn next_hole(&self, pos: u64, end: u64) -> io::Result<Option<(u64, u64)>> {
+        use std::os::fd::AsRawFd;
+
+        let bpl = self.dumper.bytes_per_line as u64;
+        let fd = self.file.as_raw_fd();
+        let seek = |offset: u64, whence| -> io::Result<Option<u64>> {
+            let Ok(offset) = libc::off_t::try_from(offset) else {
+                return Ok(None);
+            };
+            // SAFETY: lseek only moves the file position of `fd`, which
+            // stays open for the duration of the call; the mapping does not
+            // depend on it.
+            match unsafe { libc::lseek(fd, offset, whence) } {
+                -1 => match io::Error::last_os_error() {
+                    // Past the last data: the rest of the file is a hole.
+                    e if e.raw_os_error() == Some(libc::ENXIO) => Ok(None),
+                    // Holes are not supported here; read everything.
+                    e if e.raw_os_error() == Some(libc::EINVAL) => Ok(Some(u64::MAX)),
+                    e => Err(e),
+                },
+                n => Ok(Some(n as u64)),
+            }
+        };
+        let mut from = pos;
+        while from < end {
+            let Some(hole) = seek(from, libc::SEEK_HOLE)? else {
+                return Ok(None);
+            };
+            if hole >= end {
+                return Ok(None);
+            }
+            let data = seek(hole, libc::SEEK_DATA)?.unwrap_or(end).min(end);
+            // Only whole lines, aligned to the start of the dump, are
+            // skipped; partial lines at the edges are read as usual.
+            let first = hole + (bpl - (hole - self.start) % bpl) % bpl;
+            let last = data - (data - self.start) % bpl;
+            if last > first {
+                return Ok(Some((first, last)));
+            }
+            from = data;
+        }
+        Ok(None)
+    }
+
+    #[cfg(not(any(target_os = "linux", target_os = "android")))]
+    fn next_hole(&self, _pos: u64, _end: u64) -> io::Result<Option<(u64, u64)>> {
+        Ok(None)
+    }
+}
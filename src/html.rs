@@ -0,0 +1,491 @@
+//! Dumps as a single, self-contained HTML page.
+//!
+//! [`HtmlPage`] writes one file with its styles and script inline, so it
+//! opens offline in any browser and can be attached to a bug report as it
+//! is. The page shows the dump with bytes coloured by class from the
+//! dumper's [`Theme`](crate::color::Theme), and:
+//!
+//! - hovering over a byte marks it in both the hex and ASCII columns and
+//!   shows its address and the unsigned and signed integers of each width
+//!   starting there, in both byte orders;
+//! - dragging across either column selects bytes in both, shows the range
+//!   in the header, and copying copies them as hex;
+//! - the [`Annotation`]s are listed beside the dump, with field values
+//!   decoded, and clicking one selects its bytes and scrolls to them.
+//!
+//! ```
+//! use hexdump::annotate::Annotation;
+//! use hexdump::html::HtmlPage;
+//!
+//! let page = HtmlPage::new()
+//!     .title("boot sector")
+//!     .annotate(Annotation::new(0x1fe..0x200, "signature"))
+//!     .dump(&[0x55; 512]);
+//! assert!(page.starts_with("<!DOCTYPE html>"));
+//! assert!(page.contains("<title>boot sector</title>"));
+//! assert!(page.contains("<a href=\"#\">signature</a>"));
+//! ```
+
+use std::fmt::{self, Write as _};
+use std::io::{self, Write};
+
+use crate::annotate::{Annotation, Value};
+use crate::color::{ByteClass, Color, Column, Markup, Style};
+use crate::render::Renderer;
+use crate::{ByteFormat, HexDumper, STRING_WRITE};
+
+/// Text rendered before it is passed to the writer.
+const TEXT_BUFFER: usize = 1 << 16;
+
+/// The byte classes in the order of their `c0` to `c5` CSS classes.
+const CLASSES: [ByteClass; 6] = [
+    ByteClass::Null,
+    ByteClass::Printable,
+    ByteClass::Whitespace,
+    ByteClass::Control,
+    ByteClass::HighBit,
+    ByteClass::Ff,
+];
+
+/// Configurable HTML page writer.
+///
+/// The [`dumper`](HtmlPage::dumper) sets the layout of the lines, which are
+/// written by the same code as [`HexDumper::dump`] and match its text, and
+/// the colour theme. Colour is on whatever the dumper's
+/// [`ColorChoice`](crate::color::ColorChoice).
+///
+/// The page holds the markup of every byte, so it is best kept to inputs of
+/// a few megabytes at most.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HtmlPage {
+    dumper: HexDumper,
+    title: String,
+    annotations: Vec<Annotation>,
+}
+
+impl Default for HtmlPage {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl HtmlPage {
+    /// Creates a page titled `hexdump`, with the default dumper and no
+    /// annotations.
+    pub fn new() -> Self {
+        HtmlPage {
+            dumper: HexDumper::new(),
+            title: "hexdump".to_owned(),
+            annotations: Vec::new(),
+        }
+    }
+
+    /// Sets the dumper whose layout the lines follow.
+    pub fn dumper(mut self, dumper: HexDumper) -> Self {
+        self.dumper = dumper;
+        self
+    }
+
+    /// Sets the page's title, shown in its header and the browser tab.
+    pub fn title(mut self, title: impl Into<String>) -> Self {
+        self.title = title.into();
+        self
+    }
+
+    /// Adds an annotation to the list beside the dump.
+    pub fn annotate(mut self, annotation: Annotation) -> Self {
+        self.annotations.push(annotation);
+        self
+    }
+
+    /// Adds several annotations.
+    pub fn annotations(mut self, annotations: impl IntoIterator<Item = Annotation>) -> Self {
+        self.annotations.extend(annotations);
+        self
+    }
+
+    /// Returns the page for `data`.
+    pub fn dump(&self, data: &[u8]) -> String {
+        let mut out = Vec::new();
+        self.write_to(data, &mut out)
+            .expect("writing to a Vec cannot fail");
+        String::from_utf8(out).expect("the page is UTF-8")
+    }
+
+    /// Writes the page for `data` to `writer`, passing the dump on a few
+    /// lines at a time.
+    pub fn write_to<W: Write>(&self, data: &[u8], mut writer: W) -> io::Result<()> {
+        let mut text = String::new();
+        self.write_head(&mut text, data).expect(STRING_WRITE);
+        let mut renderer = Renderer::with_markup(&self.dumper, 0, Tags::default());
+        let mut prev = None;
+        for line in data.chunks(self.dumper.bytes_per_line) {
+            renderer.line(&mut text, prev, line).expect(STRING_WRITE);
+            prev = Some(line);
+            if text.len() >= TEXT_BUFFER {
+                writer.write_all(text.as_bytes())?;
+                text.clear();
+            }
+        }
+        renderer.finish(&mut text, prev).expect(STRING_WRITE);
+        self.write_tail(&mut text, data).expect(STRING_WRITE);
+        writer.write_all(text.as_bytes())?;
+        writer.flush()
+    }
+
+    /// Writes the page up to the first line of the dump.
+    fn write_head(&self, out: &mut String, data: &[u8]) -> fmt::Result {
+        out.push_str("<!DOCTYPE html>\n<html lang=\"en\">\n<head>\n<meta charset=\"utf-8\">\n");
+        out.push_str("<title>");
+        escape(out, &self.title)?;
+        out.push_str("</title>\n<style>\n");
+        out.push_str(CSS);
+        self.write_theme(out)?;
+        out.push_str("</style>\n</head>\n<body>\n<header><h1>");
+        escape(out, &self.title)?;
+        let s = if data.len() == 1 { "" } else { "s" };
+        writeln!(
+            out,
+            "</h1><p id=\"status\">{} byte{s}</p></header>",
+            data.len()
+        )?;
+        let radix = match self.dumper.byte_format {
+            ByteFormat::Hex => 16,
+            ByteFormat::Binary => 2,
+        };
+        writeln!(
+            out,
+            "<main>\n<pre id=\"dump\" data-base=\"{}\" data-len=\"{}\" \
+             data-line=\"{}\" data-radix=\"{radix}\">",
+            self.dumper.base_address.unwrap_or(0),
+            data.len(),
+            self.dumper.bytes_per_line,
+        )
+    }
+
+    /// Writes the page after the last line of the dump.
+    fn write_tail(&self, out: &mut String, data: &[u8]) -> fmt::Result {
+        out.push_str("</pre>\n");
+        if !self.annotations.is_empty() {
+            self.write_annotations(out, data, self.dumper.base_address.unwrap_or(0))?;
+        }
+        out.push_str("</main>\n<div id=\"tip\" hidden></div>\n<script>\n");
+        out.push_str(SCRIPT);
+        out.push_str("</script>\n</body>\n</html>\n");
+        Ok(())
+    }
+
+    /// Writes a CSS rule for each byte class, and for the offset column if
+    /// the theme styles it.
+    fn write_theme(&self, out: &mut String) -> fmt::Result {
+        let theme = &self.dumper.theme;
+        for (i, class) in CLASSES.iter().enumerate() {
+            write!(out, "#dump .c{i}{{")?;
+            write_style(out, theme.style(*class))?;
+            out.push_str("}\n");
+        }
+        if theme.offset != Style::new() {
+            out.push_str("#dump .off{");
+            write_style(out, theme.offset)?;
+            out.push_str("}\n");
+        }
+        Ok(())
+    }
+
+    /// Writes the list of annotations. Those covering bytes of `data` carry
+    /// the range of indices they cover, for the script to select.
+    fn write_annotations(&self, out: &mut String, data: &[u8], base: u64) -> fmt::Result {
+        out.push_str("<aside>\n<h2>Annotations</h2>\n<ol id=\"annotations\">\n");
+        for annotation in &self.annotations {
+            let len = data.len() as u64;
+            let start = annotation.range.start.saturating_sub(base).min(len);
+            let end = annotation.range.end.saturating_sub(base).min(len);
+            if start < end {
+                write!(out, "<li data-start=\"{start}\" data-end=\"{end}\">")?;
+            } else {
+                out.push_str("<li class=\"out\">");
+            }
+            out.push_str("<a href=\"#\">");
+            escape(out, &annotation.label)?;
+            write!(
+                out,
+                "</a> <span class=\"range\">{:#x}..{:#x}</span>",
+                annotation.range.start, annotation.range.end
+            )?;
+            if let Some(field) = annotation.field {
+                let value = annotation
+                    .range
+                    .start
+                    .checked_sub(base)
+                    .and_then(|at| data.get(usize::try_from(at).ok()?..))
+                    .and_then(|bytes| field.decode(bytes));
+                write!(out, " <span class=\"value\">{field} = ")?;
+                match value {
+                    Some(Value::Unsigned(n)) => write!(out, "{n} ({n:#x})")?,
+                    Some(value) => write!(out, "{value}")?,
+                    None => out.push('?'),
+                }
+                out.push_str("</span>");
+            }
+            out.push_str("</li>\n");
+        }
+        out.push_str("</ol>\n</aside>\n");
+        Ok(())
+    }
+}
+
+/// Marks up the lines of the dump: a span for each column, and an element
+/// for each byte whose CSS class is its byte class. The hex and ASCII spans
+/// carry the index of their first byte, since squeezed lines leave gaps.
+#[derive(Default)]
+struct Tags {
+    /// Index of the first byte of the line being written.
+    at: u64,
+    /// Whether the element of a byte is open.
+    open: bool,
+}
+
+impl Tags {
+    fn close<W: fmt::Write>(&mut self, out: &mut W) -> fmt::Result {
+        if std::mem::take(&mut self.open) {
+            out.write_str("</i>")?;
+        }
+        Ok(())
+    }
+}
+
+impl Markup for Tags {
+    fn line(&mut self, offset: u64) {
+        self.at = offset;
+    }
+
+    fn start<W: fmt::Write>(&mut self, out: &mut W, column: Column) -> fmt::Result {
+        match column {
+            Column::Offset => out.write_str("<span class=\"off\">"),
+            Column::Hex => write!(out, "<span class=\"hex\" data-at=\"{}\">", self.at),
+            Column::Ascii => write!(out, "<span class=\"asc\" data-at=\"{}\">", self.at),
+        }
+    }
+
+    fn end<W: fmt::Write>(&mut self, out: &mut W) -> fmt::Result {
+        self.close(out)?;
+        out.write_str("</span>")
+    }
+
+    fn byte<W: fmt::Write>(&mut self, out: &mut W, b: u8, _: Option<Style>) -> fmt::Result {
+        self.close(out)?;
+        self.open = true;
+        write!(out, "<i class=\"c{}\">", class_index(b))
+    }
+
+    fn separator<W: fmt::Write>(&mut self, out: &mut W, _: u8, _: Option<Style>) -> fmt::Result {
+        self.close(out)
+    }
+
+    fn text<W: fmt::Write>(&mut self, out: &mut W, text: &str) -> fmt::Result {
+        escape(out, text)
+    }
+}
+
+fn class_index(b: u8) -> usize {
+    match ByteClass::of(b) {
+        ByteClass::Null => 0,
+        ByteClass::Printable => 1,
+        ByteClass::Whitespace => 2,
+        ByteClass::Control => 3,
+        ByteClass::HighBit => 4,
+        ByteClass::Ff => 5,
+    }
+}
+
+/// Writes the CSS declarations for `style`.
+fn write_style(out: &mut String, style: Style) -> fmt::Result {
+    let css = |color: Color| {
+        let (r, g, b) = color.to_rgb();
+        format!("#{r:02x}{g:02x}{b:02x}")
+    };
+    if let Some(fg) = style.fg {
+        write!(out, "color:{};", css(fg))?;
+    }
+    if let Some(bg) = style.bg {
+        write!(out, "background:{};", css(bg))?;
+    }
+    if style.bold {
+        out.push_str("font-weight:bold;");
+    }
+    if style.dim {
+        out.push_str("opacity:.6;");
+    }
+    Ok(())
+}
+
+/// Writes `text` with the characters HTML gives meaning to escaped.
+fn escape<W: fmt::Write>(out: &mut W, text: &str) -> fmt::Result {
+    for c in text.chars() {
+        match c {
+            '&' => out.write_str("&amp;")?,
+            '<' => out.write_str("&lt;")?,
+            '>' => out.write_str("&gt;")?,
+            '"' => out.write_str("&quot;")?,
+            c => out.write_char(c)?,
+        }
+    }
+    Ok(())
+}
+
+const CSS: &str = "\
+body{margin:0;background:#1e1e1e;color:#d4d4d4;\
+font:14px/1.45 ui-monospace,SFMono-Regular,Menlo,Consolas,monospace}
+header{position:sticky;top:0;display:flex;gap:2em;align-items:baseline;\
+padding:.5em 1em;background:#252526;border-bottom:1px solid #3c3c3c}
+h1,h2{font-size:1em;margin:0}
+#status{margin:0;color:#9d9d9d}
+main{display:flex;align-items:flex-start;gap:3em;padding:1em}
+#dump{margin:0;user-select:none;cursor:default}
+#dump i{font-style:normal}
+#dump .off{color:#858585}
+#dump i.an{text-decoration:underline dotted}
+#dump i.hover{outline:1px solid #d4d4d4}
+#dump i.sel{background:#264f78}
+aside{position:sticky;top:3em}
+aside ol{margin:.5em 0 0;padding-left:2em}
+aside a{color:#4fc1ff}
+aside li.out a{color:#858585;pointer-events:none}
+aside .range,aside .value{color:#9d9d9d}
+#tip{position:fixed;pointer-events:none;margin:0;padding:.4em .6em;white-space:pre;\
+background:#252526;border:1px solid #454545}
+";
+
+const SCRIPT: &str = r##""use strict";
+(() => {
+  const dump = document.getElementById("dump");
+  const status = document.getElementById("status");
+  const tip = document.getElementById("tip");
+  // Elements by byte index. Squeezed lines have none.
+  const hex = [];
+  const asc = [];
+  for (const [elements, column] of [[hex, ".hex"], [asc, ".asc"]]) {
+    for (const span of dump.querySelectorAll(column)) {
+      const at = Number(span.dataset.at);
+      Array.from(span.children).forEach((e, k) => { elements[at + k] = e; });
+    }
+  }
+  const radix = Number(dump.dataset.radix);
+  const line = Number(dump.dataset.line);
+  const bytes = [];
+  for (let i = 0; i < Number(dump.dataset.len); i++) {
+    // A squeezed line repeats the one before it.
+    bytes.push(hex[i] ? parseInt(hex[i].textContent, radix) : bytes[i - line]);
+  }
+  const base = BigInt(dump.dataset.base);
+  const size = status.textContent;
+  const index = new Map();
+  hex.forEach((e, i) => index.set(e, i));
+  asc.forEach((e, i) => index.set(e, i));
+  const both = i => [hex[i], asc[i]].filter(Boolean);
+  const address = i => "0x" + (base + BigInt(i)).toString(16);
+
+  const notes = Array.from(document.querySelectorAll("#annotations li")).map(li => ({
+    li,
+    label: li.querySelector("a").textContent,
+    start: li.dataset.start === undefined ? 0 : Number(li.dataset.start),
+    end: li.dataset.end === undefined ? 0 : Number(li.dataset.end),
+  }));
+  for (const note of notes) {
+    for (let i = note.start; i < note.end; i++) both(i).forEach(e => e.classList.add("an"));
+  }
+
+  let selection = null;
+  let anchor = null;
+  function select(start, end) {
+    if (selection) {
+      for (let i = selection[0]; i < selection[1]; i++) both(i).forEach(e => e.classList.remove("sel"));
+    }
+    selection = start < end ? [start, end] : null;
+    if (!selection) {
+      status.textContent = size;
+      return;
+    }
+    for (let i = start; i < end; i++) both(i).forEach(e => e.classList.add("sel"));
+    const n = end - start;
+    status.textContent = `${address(start)}..${address(end)}: ${n} byte${n === 1 ? "" : "s"} selected`;
+  }
+
+  function readings(i) {
+    const column = (text, width) => String(text).padEnd(width);
+    const lines = [address(i), column("", 6) + column("le", 22) + "be"];
+    for (const width of [1, 2, 4, 8]) {
+      if (i + width > bytes.length) break;
+      let le = 0n;
+      let be = 0n;
+      for (let k = 0; k < width; k++) {
+        le |= BigInt(bytes[i + k]) << BigInt(8 * k);
+        be = (be << 8n) | BigInt(bytes[i + k]);
+      }
+      const bits = 8 * width;
+      const signed = n => BigInt.asIntN(bits, n);
+      if (width === 1) {
+        lines.push(column("u8", 6) + le, column("i8", 6) + signed(le));
+      } else {
+        lines.push(column(`u${bits}`, 6) + column(le, 22) + be);
+        lines.push(column(`i${bits}`, 6) + column(signed(le), 22) + signed(be));
+      }
+    }
+    for (const note of notes) {
+      if (note.start <= i && i < note.end) lines.push(note.label);
+    }
+    return lines.join("\n");
+  }
+
+  function place(event) {
+    const x = event.clientX + 16;
+    const y = event.clientY + 16;
+    tip.style.left = (x + tip.offsetWidth > innerWidth ? event.clientX - tip.offsetWidth - 8 : x) + "px";
+    tip.style.top = (y + tip.offsetHeight > innerHeight ? event.clientY - tip.offsetHeight - 8 : y) + "px";
+  }
+
+  dump.addEventListener("mousedown", event => {
+    const i = index.get(event.target);
+    if (i === undefined || event.button !== 0) return;
+    event.preventDefault();
+    anchor = i;
+    select(i, i + 1);
+  });
+  dump.addEventListener("mouseover", event => {
+    const i = index.get(event.target);
+    if (i === undefined) return;
+    both(i).forEach(e => e.classList.add("hover"));
+    if (anchor !== null) select(Math.min(anchor, i), Math.max(anchor, i) + 1);
+    tip.textContent = readings(i);
+    tip.hidden = false;
+    place(event);
+  });
+  dump.addEventListener("mouseout", event => {
+    const i = index.get(event.target);
+    if (i === undefined) return;
+    both(i).forEach(e => e.classList.remove("hover"));
+    tip.hidden = true;
+  });
+  dump.addEventListener("mousemove", place);
+  document.addEventListener("mouseup", () => { anchor = null; });
+  document.addEventListener("keydown", event => {
+    if (event.key === "Escape") select(0, 0);
+  });
+  document.addEventListener("copy", event => {
+    if (!selection) return;
+    const text = bytes.slice(selection[0], selection[1])
+      .map(b => b.toString(16).padStart(2, "0")).join(" ");
+    event.clipboardData.setData("text/plain", text);
+    event.preventDefault();
+  });
+
+  for (const note of notes) {
+    note.li.querySelector("a").addEventListener("click", event => {
+      event.preventDefault();
+      select(note.start, note.end);
+      const shown = hex.slice(0, note.start + 1).findLast(Boolean);
+      if (note.start < note.end && shown) shown.scrollIntoView({ block: "center" });
+    });
+  }
+})();
+"##;
@@ -0,0 +1,299 @@
+//! Reading and writing Intel HEX files.
+//!
+//! [`parse`] reads every record type, 00 to 05, into an [`Image`],
+//! checking each record's checksum, and [`Encoder`] writes an image back as
+//! records.
+//!
+//! ```
+//! use hexdump::ihex::{self, StartAddress};
+//!
+//! let text = "\
+//! :020000040800F2
+//! :04000000005000208C
+//! :0410000063666700BC
+//! :0400000508000131BD
+//! :00000001FF
+//! ";
+//! let file = ihex::parse(text)?;
+//! assert_eq!(file.image.get(0x0800_1001), Some(b'f'));
+//! assert_eq!(file.image.segments().len(), 2);
+//! assert_eq!(file.start, Some(StartAddress::Linear(0x0800_0131)));
+//! assert_eq!(ihex::Encoder::new().encode(&file)?, text);
+//! # Ok::<(), Box<dyn std::error::Error>>(())
+//! ```
+
+use std::error::Error;
+use std::fmt;
+
+use crate::image::{decode_hex, write_hex, AddressTooLarge, Image, Overlap};
+
+/// Where execution starts, from a type 03 or 05 record.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum StartAddress {
+    /// Type 03: an 8086 `CS:IP` pair.
+    Segment { cs: u16, ip: u16 },
+    /// Type 05: a 32-bit linear address.
+    Linear(u32),
+}
+
+impl StartAddress {
+    /// The start as a linear address; `CS:IP` is `CS * 16 + IP`.
+    pub fn linear(self) -> u32 {
+        match self {
+            StartAddress::Segment { cs, ip } => u32::from(cs) * 16 + u32::from(ip),
+            StartAddress::Linear(address) => address,
+        }
+    }
+}
+
+/// The contents of an Intel HEX file.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct IhexFile {
+    pub image: Image,
+    pub start: Option<StartAddress>,
+}
+
+/// Error from parsing an Intel HEX file. Lines count from 1.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum IhexError {
+    /// A record does not start with `:`.
+    MissingColon { line: usize },
+    /// A record holds something other than pairs of hex digits.
+    InvalidHex { line: usize },
+    /// A record's length does not match its byte count, or its type needs
+    /// a different byte count.
+    BadLength { line: usize },
+    /// A record's checksum does not match its contents.
+    Checksum {
+        line: usize,
+        expected: u8,
+        found: u8,
+    },
+    /// A record type other than 00 to 05.
+    UnknownType { line: usize, kind: u8 },
+    /// A data record gives bytes for addresses an earlier one gave.
+    Overlap { line: usize, address: u64 },
+}
+
+impl IhexError {
+    /// The line the error was found on.
+    pub fn line(&self) -> usize {
+        match *self {
+            IhexError::MissingColon { line }
+            | IhexError::InvalidHex { line }
+            | IhexError::BadLength { line }
+            | IhexError::Checksum { line, .. }
+            | IhexError::UnknownType { line, .. }
+            | IhexError::Overlap { line, .. } => line,
+        }
+    }
+}
+
+impl fmt::Display for IhexError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "line {}: ", self.line())?;
+        match self {
+            IhexError::MissingColon { .. } => f.write_str("record does not start with ':'"),
+            IhexError::InvalidHex { .. } => f.write_str("invalid hex digit"),
+            IhexError::BadLength { .. } => f.write_str("record length does not match"),
+            IhexError::Checksum {
+                expected, found, ..
+            } => write!(f, "checksum is {found:02X}, expected {expected:02X}"),
+            IhexError::UnknownType { kind, .. } => write!(f, "unknown record type {kind:02X}"),
+            IhexError::Overlap { address, .. } => {
+                write!(f, "{}", Overlap { address: *address })
+            }
+        }
+    }
+}
+
+impl Error for IhexError {}
+
+/// Parses Intel HEX text.
+///
+/// Blank lines are skipped, as is everything after the end-of-file record;
+/// a file without one is accepted. Data addresses follow the latest type 02
+/// or 04 record: a type 02 segment base wraps offsets within its 64 KiB
+/// segment, a type 04 linear base does not.
+pub fn parse(text: &str) -> Result<IhexFile, IhexError> {
+    let mut file = IhexFile::default();
+    // The base from the last type 02 or 04 record, and whether it is a
+    // segment, whose offsets wrap at 64 KiB.
+    let mut base = 0u32;
+    let mut segmented = false;
+    for (i, line) in text.lines().enumerate() {
+        let line_no = i + 1;
+        let line = line.trim();
+        if line.is_empty() {
+            continue;
+        }
+        let record = line
+            .strip_prefix(':')
+            .ok_or(IhexError::MissingColon { line: line_no })?;
+        let bytes = decode_hex(record).ok_or(IhexError::InvalidHex { line: line_no })?;
+        let bad_length = IhexError::BadLength { line: line_no };
+        if bytes.len() < 5 || bytes.len() != usize::from(bytes[0]) + 5 {
+            return Err(bad_length);
+        }
+        let (body, checksum) = bytes.split_at(bytes.len() - 1);
+        let expected = checksum_of(body);
+        if checksum[0] != expected {
+            return Err(IhexError::Checksum {
+                line: line_no,
+                expected,
+                found: checksum[0],
+            });
+        }
+        let offset = u16::from_be_bytes([body[1], body[2]]);
+        let data = &body[4..];
+        let word = |data: &[u8]| u16::from_be_bytes([data[0], data[1]]);
+        match (body[3], data.len()) {
+            (0x00, _) => {
+                let overlap = |err: Overlap| IhexError::Overlap {
+                    line: line_no,
+                    address: err.address,
+                };
+                if segmented {
+                    // Split where the offset wraps around the segment.
+                    let split = (0x1_0000 - usize::from(offset)).min(data.len());
+                    let (head, tail) = data.split_at(split);
+                    let address = u64::from(base) + u64::from(offset);
+                    file.image.insert(address, head).map_err(overlap)?;
+                    file.image.insert(u64::from(base), tail).map_err(overlap)?;
+                } else {
+                    let address = u64::from(base.wrapping_add(u32::from(offset)));
+                    file.image.insert(address, data).map_err(overlap)?;
+                }
+            }
+            (0x01, 0) => break,
+            (0x02, 2) => {
+                base = u32::from(word(data)) << 4;
+                segmented = true;
+            }
+            (0x03, 4) => {
+                file.start = Some(StartAddress::Segment {
+                    cs: word(data),
+                    ip: word(&data[2..]),
+                });
+            }
+            (0x04, 2) => {
+                base = u32::from(word(data)) << 16;
+                segmented = false;
+            }
+            (0x05, 4) => {
+                file.start = Some(StartAddress::Linear(u32::from_be_bytes([
+                    data[0], data[1], data[2], data[3],
+                ])));
+            }
+            (0x01..=0x05, _) => return Err(bad_length),
+            (kind, _) => {
+                return Err(IhexError::UnknownType {
+                    line: line_no,
+                    kind,
+                })
+            }
+        }
+    }
+    Ok(file)
+}
+
+/// Configurable Intel HEX writer.
+///
+/// Addresses above 64 KiB are written with type 04 extended linear address
+/// records; type 02 records are never written. Records do not cross 64 KiB
+/// boundaries.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Encoder {
+    record_len: usize,
+    uppercase: bool,
+}
+
+impl Default for Encoder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Encoder {
+    /// Creates a writer for records of 16 data bytes with upper case hex
+    /// digits, as most tools write them.
+    pub fn new() -> Self {
+        Encoder {
+            record_len: 16,
+            uppercase: true,
+        }
+    }
+
+    /// Sets the most data bytes in a record, from 1 to 255.
+    pub fn record_len(mut self, len: usize) -> Self {
+        self.record_len = len.clamp(1, 255);
+        self
+    }
+
+    /// Upper case hex digits, the default.
+    pub fn uppercase(mut self, upper: bool) -> Self {
+        self.uppercase = upper;
+        self
+    }
+
+    /// Writes `file` as Intel HEX text. Fails if the image holds addresses
+    /// above 32 bits.
+    pub fn encode(&self, file: &IhexFile) -> Result<String, AddressTooLarge> {
+        if let Some(end) = file.image.end().filter(|&end| end > 1 << 32) {
+            return Err(AddressTooLarge { address: end - 1 });
+        }
+        let mut out = String::new();
+        let mut upper = 0u16;
+        for segment in file.image.segments() {
+            let mut address = segment.address;
+            let mut data = &segment.data[..];
+            while !data.is_empty() {
+                let high = (address >> 16) as u16;
+                if high != upper {
+                    self.record(&mut out, 0, 0x04, &high.to_be_bytes());
+                    upper = high;
+                }
+                let room = 0x1_0000 - (address & 0xffff) as usize;
+                let (record, rest) = data.split_at(self.record_len.min(room).min(data.len()));
+                self.record(&mut out, address as u16, 0x00, record);
+                address += record.len() as u64;
+                data = rest;
+            }
+        }
+        match file.start {
+            Some(StartAddress::Segment { cs, ip }) => {
+                let mut data = [0; 4];
+                data[..2].copy_from_slice(&cs.to_be_bytes());
+                data[2..].copy_from_slice(&ip.to_be_bytes());
+                self.record(&mut out, 0, 0x03, &data);
+            }
+            Some(StartAddress::Linear(address)) => {
+                self.record(&mut out, 0, 0x05, &address.to_be_bytes());
+            }
+            None => {}
+        }
+        self.record(&mut out, 0, 0x01, &[]);
+        Ok(out)
+    }
+
+    fn record(&self, out: &mut String, offset: u16, kind: u8, data: &[u8]) {
+        let mut bytes = Vec::with_capacity(data.len() + 5);
+        bytes.push(data.len() as u8);
+        bytes.extend_from_slice(&offset.to_be_bytes());
+        bytes.push(kind);
+        bytes.extend_from_slice(data);
+        bytes.push(checksum_of(&bytes));
+        out.push(':');
+        write_hex(out, &bytes, self.uppercase);
+        out.push('\n');
+    }
+}
+
+/// The two's complement of the sum of `bytes`.
+fn checksum_of(bytes: &[u8]) -> u8 {
+    bytes
+        .iter()
+        .fold(0u8, |sum, &b| sum.wrapping_add(b))
+        .wrapping_neg()
+}
@@ -0,0 +1,260 @@
+//! Bytes placed at addresses, as loaded from firmware files.
+//!
+//! An [`Image`] holds the contents of an Intel HEX or S-record file, see the
+//! [`ihex`](crate::ihex) and [`srec`](crate::srec) modules: runs of bytes at
+//! the addresses the file gives them, with gaps between them.
+//! [`HexDumper::dump_image`] dumps an image in that address space, each run
+//! numbered from its own address and gaps marked:
+//!
+//! ```
+//! use hexdump::image::Image;
+//! use hexdump::HexDumper;
+//!
+//! let mut image = Image::new();
+//! image.insert(0x0800_0000, b"\x00\x50\x00\x20")?;
+//! image.insert(0x0800_1000, b"cfg")?;
+//! assert_eq!(
+//!     HexDumper::new().dump_image(&image),
+//!     "\
+//! 08000000: 00 50 00 20                                      |.P. |
+//! -- gap: 4092 bytes --
+//! 08001000: 63 66 67                                         |cfg|
+//! ",
+//! );
+//! # Ok::<(), hexdump::image::Overlap>(())
+//! ```
+
+use std::error::Error;
+use std::fmt::{self, Write};
+
+use crate::{HexDumper, TrailingOffset, STRING_WRITE};
+
+/// A run of bytes at consecutive addresses.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Segment {
+    /// The address of the first byte.
+    pub address: u64,
+    pub data: Vec<u8>,
+}
+
+impl Segment {
+    /// The address after the last byte.
+    pub fn end(&self) -> u64 {
+        self.address + self.data.len() as u64
+    }
+}
+
+/// Error from placing bytes where an [`Image`] already has some.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Overlap {
+    /// The first address given twice.
+    pub address: u64,
+}
+
+impl fmt::Display for Overlap {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "data for address {:#x} is given twice", self.address)
+    }
+}
+
+impl Error for Overlap {}
+
+/// Error from writing an [`Image`] in a format whose addresses are too
+/// narrow for it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AddressTooLarge {
+    /// The first address that cannot be written.
+    pub address: u64,
+}
+
+impl fmt::Display for AddressTooLarge {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "address {:#x} does not fit in 32 bits", self.address)
+    }
+}
+
+impl Error for AddressTooLarge {}
+
+/// Bytes at addresses, kept as sorted, non-overlapping segments. Adjacent
+/// runs are merged into one segment.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct Image {
+    segments: Vec<Segment>,
+}
+
+impl Image {
+    /// Creates an empty image.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates an image holding `data` from `address` on.
+    pub fn from_bytes(address: u64, data: impl Into<Vec<u8>>) -> Self {
+        let data = data.into();
+        let segments = if data.is_empty() {
+            Vec::new()
+        } else {
+            vec![Segment { address, data }]
+        };
+        Image { segments }
+    }
+
+    /// Places `data` at `address`, unless the image already holds any of
+    /// those addresses.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the data would run past `u64::MAX`.
+    pub fn insert(&mut self, address: u64, data: &[u8]) -> Result<(), Overlap> {
+        if data.is_empty() {
+            return Ok(());
+        }
+        let end = address
+            .checked_add(data.len() as u64)
+            .expect("image data runs past the end of the address space");
+        let at = self
+            .segments
+            .partition_point(|segment| segment.address < address);
+        if let Some(next) = self.segments.get(at) {
+            if next.address < end {
+                return Err(Overlap {
+                    address: next.address,
+                });
+            }
+        }
+        if let Some(prev) = at.checked_sub(1).map(|i| &self.segments[i]) {
+            if prev.end() > address {
+                return Err(Overlap { address });
+            }
+        }
+        let joins_prev = at > 0 && self.segments[at - 1].end() == address;
+        let joins_next = self
+            .segments
+            .get(at)
+            .is_some_and(|next| next.address == end);
+        match (joins_prev, joins_next) {
+            (true, true) => {
+                let next = self.segments.remove(at);
+                let prev = &mut self.segments[at - 1];
+                prev.data.extend_from_slice(data);
+                prev.data.extend_from_slice(&next.data);
+            }
+            (true, false) => self.segments[at - 1].data.extend_from_slice(data),
+            (false, true) => {
+                let next = &mut self.segments[at];
+                next.data.splice(0..0, data.iter().copied());
+                next.address = address;
+            }
+            (false, false) => self.segments.insert(
+                at,
+                Segment {
+                    address,
+                    data: data.to_vec(),
+                },
+            ),
+        }
+        Ok(())
+    }
+
+    /// The segments, in address order.
+    pub fn segments(&self) -> &[Segment] {
+        &self.segments
+    }
+
+    /// The number of bytes held, not counting gaps.
+    pub fn len(&self) -> u64 {
+        self.segments.iter().map(|s| s.data.len() as u64).sum()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.segments.is_empty()
+    }
+
+    /// The lowest address held.
+    pub fn start(&self) -> Option<u64> {
+        self.segments.first().map(|s| s.address)
+    }
+
+    /// The address after the highest one held.
+    pub fn end(&self) -> Option<u64> {
+        self.segments.last().map(Segment::end)
+    }
+
+    /// The byte at `address`, if the image holds it.
+    pub fn get(&self, address: u64) -> Option<u8> {
+        let at = self.segments.partition_point(|s| s.end() <= address);
+        let segment = self.segments.get(at)?;
+        let index = address.checked_sub(segment.address)?;
+        segment.data.get(index as usize).copied()
+    }
+
+    /// The bytes from the lowest address to the highest, with gaps filled
+    /// with `fill`, as `objcopy -O binary --gap-fill` writes them.
+    pub fn to_bytes(&self, fill: u8) -> Vec<u8> {
+        let (Some(start), Some(end)) = (self.start(), self.end()) else {
+            return Vec::new();
+        };
+        let mut out = vec![fill; (end - start) as usize];
+        for segment in &self.segments {
+            let at = (segment.address - start) as usize;
+            out[at..at + segment.data.len()].copy_from_slice(&segment.data);
+        }
+        out
+    }
+}
+
+impl HexDumper {
+    /// Dumps `image` in its own address space: each segment is dumped with
+    /// its address as [`base_address`](HexDumper::base_address), and a
+    /// `-- gap: N bytes --` line stands for the addresses between segments.
+    /// Squeezing applies within segments, and the trailing offset, if any,
+    /// follows the last one.
+    pub fn dump_image(&self, image: &Image) -> String {
+        let mut out = String::new();
+        self.write_image(&mut out, image).expect(STRING_WRITE);
+        out
+    }
+
+    /// Writes the dump of `image` to `out`, as
+    /// [`dump_image`](HexDumper::dump_image) returns it.
+    pub fn write_image<W: Write>(&self, out: &mut W, image: &Image) -> fmt::Result {
+        let mut prev_end = None;
+        let last = image.segments.len().saturating_sub(1);
+        for (i, segment) in image.segments.iter().enumerate() {
+            if let Some(end) = prev_end {
+                writeln!(out, "-- gap: {} bytes --", segment.address - end)?;
+            }
+            let mut dumper = self.clone().base_address(segment.address);
+            if i < last {
+                dumper = dumper.trailing_offset(TrailingOffset::Never);
+            }
+            dumper.write_dump(out, &segment.data)?;
+            prev_end = Some(segment.end());
+        }
+        Ok(())
+    }
+}
+
+/// Decodes the pairs of hex digits of an Intel HEX or S-record record; `None` if there is anything else.
+pub(crate) fn decode_hex(text: &str) -> Option<Vec<u8>> {
+    let text = text.as_bytes();
+    if !text.len().is_multiple_of(2) {
+        return None;
+    }
+    let digit = |c: u8| (c as char).to_digit(16).map(|d| d as u8);
+    text.chunks(2)
+        .map(|pair| Some(digit(pair[0])? << 4 | digit(pair[1])?))
+        .collect()
+}
+
+/// Writes `bytes` as pairs of hex digits, for the record formats.
+pub(crate) fn write_hex(out: &mut String, bytes: &[u8], uppercase: bool) {
+    for b in bytes {
+        if uppercase {
+            write!(out, "{b:02X}")
+        } else {
+            write!(out, "{b:02x}")
+        }
+        .expect(STRING_WRITE);
+    }
+}
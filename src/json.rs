@@ -0,0 +1,329 @@
+//! Dumps as JSON Lines, for other programs to read.
+//!
+//! [`JsonLines`] writes one JSON object per line, each with a `type`:
+//!
+//! - `header`, always first: the `format`, `"hexdump"`, its `version`,
+//!   [`VERSION`], and the `bytes_per_line`.
+//! - `line`: a dump line's `offset`, its bytes as `hex` digits, its `ascii`
+//!   column and the `annotations` covering any of its bytes, each with its
+//!   `label`, `start` and `end`, and for fields, the `field` type and
+//!   decoded `value`, `null` if the input stops short of it.
+//! - `squeeze`: a run of `lines` repeating the line before, which started
+//!   at `offset`, when the dumper squeezes. Lines with annotations are never
+//!   squeezed.
+//! - `end`, always last: the `offset` just past the input and its `length`.
+//!
+//! Offsets and annotation ranges are numbered like the dump's offset
+//! column, from the [`base_address`](crate::HexDumper::base_address) on;
+//! [`relative_to`](crate::HexDumper::relative_to) is ignored. Fields and
+//! record types are only ever added within a version, so readers should
+//! ignore what they do not know.
+//!
+//! ```
+//! use hexdump::annotate::{Annotation, Endian, Field};
+//! use hexdump::json::JsonLines;
+//! use hexdump::HexDumper;
+//!
+//! let json = JsonLines::new()
+//!     .dumper(HexDumper::new().bytes_per_line(4))
+//!     .annotate(Annotation::field(4, Field::U16(Endian::Little), "count"));
+//! assert_eq!(
+//!     json.dump(b"DATA\x02\x01"),
+//!     "\
+//! {\"type\":\"header\",\"format\":\"hexdump\",\"version\":1,\"bytes_per_line\":4}
+//! {\"type\":\"line\",\"offset\":0,\"hex\":\"44415441\",\"ascii\":\"DATA\",\"annotations\":[]}
+//! {\"type\":\"line\",\"offset\":4,\"hex\":\"0201\",\"ascii\":\"..\",\"annotations\":\
+//! [{\"label\":\"count\",\"start\":4,\"end\":6,\"field\":\"u16le\",\"value\":258}]}
+//! {\"type\":\"end\",\"offset\":6,\"length\":6}
+//! ",
+//! );
+//! ```
+
+use std::fmt::Write as _;
+use std::io::{self, Read, Write};
+use std::ops::Range;
+
+use crate::annotate::{Annotation, Value};
+use crate::render::Repeats;
+use crate::stream::{read_full, CHUNK_SIZE};
+use crate::{is_printable, HexDumper, STRING_WRITE};
+
+/// The version in the header record. It changes only when a record loses
+/// a field or a field changes meaning.
+pub const VERSION: u32 = 1;
+
+/// The widest [`Field`](crate::annotate::Field), and so the most bytes a
+/// line's field decodes can need from the lines next to it.
+const MAX_FIELD: usize = 8;
+
+/// Configurable JSON Lines writer.
+///
+/// The [`dumper`](JsonLines::dumper) sets the bytes per line, the base
+/// address, squeezing, the case of the hex digits and the placeholder for
+/// bytes outside printable ASCII; its other settings only apply to text.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct JsonLines {
+    dumper: HexDumper,
+    annotations: Vec<Annotation>,
+}
+
+impl JsonLines {
+    /// Creates a writer with the default dumper and no annotations.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the dumper whose layout the lines follow.
+    pub fn dumper(mut self, dumper: HexDumper) -> Self {
+        self.dumper = dumper;
+        self
+    }
+
+    /// Adds an annotation, listed on every line it covers.
+    pub fn annotate(mut self, annotation: Annotation) -> Self {
+        self.annotations.push(annotation);
+        self
+    }
+
+    /// Adds several annotations.
+    pub fn annotations(mut self, annotations: impl IntoIterator<Item = Annotation>) -> Self {
+        self.annotations.extend(annotations);
+        self
+    }
+
+    /// Returns the records for `data`.
+    pub fn dump(&self, data: &[u8]) -> String {
+        let mut out = String::new();
+        let mut emitter = Emitter::new(self, &mut out);
+        for line in data.chunks(self.dumper.bytes_per_line) {
+            emitter.line(&mut out, line, data, 0);
+        }
+        emitter.finish(&mut out);
+        out
+    }
+
+    /// Writes the records for everything read from `reader` to `writer`,
+    /// reading in fixed-size chunks. The output is the same as
+    /// [`dump`](JsonLines::dump) gives. Returns the number of bytes read.
+    pub fn dump_reader<R: Read, W: Write>(&self, mut reader: R, mut writer: W) -> io::Result<u64> {
+        let bpl = self.dumper.bytes_per_line;
+        let mut chunk = vec![0u8; (CHUNK_SIZE / bpl).max(1) * bpl];
+        // The input from `buf_start` on, kept from a few bytes before the
+        // next line so fields crossing lines can be decoded.
+        let mut buf = Vec::new();
+        let mut buf_start = 0u64;
+        let mut next = 0;
+        let mut out = String::new();
+        let mut emitter = Emitter::new(self, &mut out);
+        loop {
+            let n = read_full(&mut reader, &mut chunk)?;
+            buf.extend_from_slice(&chunk[..n]);
+            let done = n < chunk.len();
+            while next < buf.len() {
+                let end = (next + bpl).min(buf.len());
+                if !done && (end - next < bpl || buf.len() - end < MAX_FIELD) {
+                    break;
+                }
+                emitter.line(&mut out, &buf[next..end], &buf, buf_start);
+                next = end;
+            }
+            if done {
+                let total = emitter.finish(&mut out);
+                writer.write_all(out.as_bytes())?;
+                writer.flush()?;
+                return Ok(total);
+            }
+            writer.write_all(out.as_bytes())?;
+            out.clear();
+            let keep = next.saturating_sub(MAX_FIELD);
+            buf.drain(..keep);
+            buf_start += keep as u64;
+            next -= keep;
+        }
+    }
+}
+
+/// Writes the records of one dump, squeezing as the dumper asks.
+struct Emitter<'a> {
+    dumper: &'a HexDumper,
+    /// The annotations, sorted by where they start.
+    annotations: Vec<&'a Annotation>,
+    base: u64,
+    /// The input offset of the next line.
+    offset: u64,
+    prev: Option<Vec<u8>>,
+    /// The lines held back because they repeat `prev`.
+    repeats: Repeats,
+}
+
+impl<'a> Emitter<'a> {
+    /// Creates an emitter and writes the header record.
+    fn new(json: &'a JsonLines, out: &mut String) -> Self {
+        let mut annotations: Vec<_> = json.annotations.iter().collect();
+        annotations.sort_by_key(|a| a.range.start);
+        let dumper = &json.dumper;
+        writeln!(
+            out,
+            "{{\"type\":\"header\",\"format\":\"hexdump\",\"version\":{VERSION},\
+             \"bytes_per_line\":{}}}",
+            dumper.bytes_per_line
+        )
+        .expect(STRING_WRITE);
+        Emitter {
+            dumper,
+            annotations,
+            base: dumper.base_address.unwrap_or(0),
+            offset: 0,
+            prev: None,
+            repeats: Repeats::new(dumper.squeeze),
+        }
+    }
+
+    /// Writes the next line, or holds it back if it repeats the one before.
+    /// `window` holds input from offset `window_start` on, including at
+    /// least [`MAX_FIELD`] bytes around the line unless the input ends.
+    fn line(&mut self, out: &mut String, line: &[u8], window: &[u8], window_start: u64) {
+        let offset = self.offset;
+        self.offset += line.len() as u64;
+        let covering = self.covering(offset, line.len());
+        if self.repeats.is_on() && covering.is_empty() && self.prev.as_deref() == Some(line) {
+            self.repeats.hold(offset, 1);
+            return;
+        }
+        self.end_run(out);
+        self.write_line(out, offset, line, &covering, window, window_start);
+        let prev = self.prev.get_or_insert_with(Vec::new);
+        prev.clear();
+        prev.extend_from_slice(line);
+    }
+
+    /// Ends the dump. Returns the number of bytes dumped.
+    fn finish(mut self, out: &mut String) -> u64 {
+        self.end_run(out);
+        writeln!(
+            out,
+            "{{\"type\":\"end\",\"offset\":{},\"length\":{}}}",
+            self.base.wrapping_add(self.offset),
+            self.offset
+        )
+        .expect(STRING_WRITE);
+        self.offset
+    }
+
+    /// The annotations covering any of the `len` bytes at input `offset`.
+    fn covering(&self, offset: u64, len: usize) -> Vec<&'a Annotation> {
+        let start = self.base.wrapping_add(offset);
+        let range = start..start.saturating_add(len as u64);
+        let before_end = self
+            .annotations
+            .partition_point(|a| a.range.start < range.end);
+        self.annotations[..before_end]
+            .iter()
+            .filter(|a| a.overlaps(&range))
+            .copied()
+            .collect()
+    }
+
+    /// Writes out the lines held back by the current run of repeats.
+    fn end_run(&mut self, out: &mut String) {
+        let Some(run) = self.repeats.end() else {
+            return;
+        };
+        let prev = self.prev.take().unwrap_or_default();
+        if run.squeezed {
+            writeln!(
+                out,
+                "{{\"type\":\"squeeze\",\"offset\":{},\"lines\":{}}}",
+                self.base.wrapping_add(run.start),
+                run.lines
+            )
+            .expect(STRING_WRITE);
+        } else {
+            let mut offset = run.start;
+            for _ in 0..run.lines {
+                self.write_line(out, offset, &prev, &[], &[], 0);
+                offset += prev.len() as u64;
+            }
+        }
+        self.prev = Some(prev);
+    }
+
+    fn write_line(
+        &self,
+        out: &mut String,
+        offset: u64,
+        line: &[u8],
+        covering: &[&Annotation],
+        window: &[u8],
+        window_start: u64,
+    ) {
+        let digits: &[u8; 16] = if self.dumper.uppercase {
+            b"0123456789ABCDEF"
+        } else {
+            b"0123456789abcdef"
+        };
+        write!(
+            out,
+            "{{\"type\":\"line\",\"offset\":{},\"hex\":\"",
+            self.base.wrapping_add(offset)
+        )
+        .expect(STRING_WRITE);
+        for &b in line {
+            out.push(digits[usize::from(b >> 4)] as char);
+            out.push(digits[usize::from(b & 0xf)] as char);
+        }
+        out.push_str("\",\"ascii\":");
+        let ascii: String = line
+            .iter()
+            .map(|&b| {
+                if is_printable(b) {
+                    b as char
+                } else {
+                    self.dumper.placeholder
+                }
+            })
+            .collect();
+        write_string(out, &ascii);
+        out.push_str(",\"annotations\":[");
+        for (i, annotation) in covering.iter().enumerate() {
+            if i > 0 {
+                out.push(',');
+            }
+            out.push_str("{\"label\":");
+            write_string(out, &annotation.label);
+            let Range { start, end } = annotation.range;
+            write!(out, ",\"start\":{start},\"end\":{end}").expect(STRING_WRITE);
+            if let Some(field) = annotation.field {
+                write!(out, ",\"field\":\"{field}\",\"value\":").expect(STRING_WRITE);
+                let bytes = start
+                    .checked_sub(self.base)
+                    .and_then(|offset| offset.checked_sub(window_start))
+                    .and_then(|at| window.get(usize::try_from(at).ok()?..));
+                match bytes.and_then(|bytes| field.decode(bytes)) {
+                    Some(Value::Float(x)) if !x.is_finite() => out.push_str("null"),
+                    Some(value) => write!(out, "{value}").expect(STRING_WRITE),
+                    None => out.push_str("null"),
+                }
+            }
+            out.push('}');
+        }
+        out.push_str("]}\n");
+    }
+}
+
+/// Writes `text` as a JSON string.
+fn write_string(out: &mut String, text: &str) {
+    out.push('"');
+    for c in text.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            c if c < ' ' => write!(out, "\\u{:04x}", c as u32).expect(STRING_WRITE),
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+}
@@ -0,0 +1,117 @@
+//! Configurable hex dump formatting.
+//!
+//! The crate is built around [`HexDumper`], a builder describing a dump
+//! layout: bytes per line, grouping, the offset column and the ASCII
+//! gutter. The same line formatter backs every output path, so a slice
+//! dumped with [`HexDumper::dump`] and a stream dumped with
+//! [`HexDumper::dump_reader`] produce identical text, and
+//! [`HexDumper::lines`] yields the same lines one at a time.
+//!
+//! [`Preset`]s reproduce the layouts of familiar tools, and the [`xxd`]
+//! module matches `xxd` output byte for byte, flags included. The
+//! [`undump`] module turns dumps back into bytes.
+//!
+//! Terminal output can be coloured by byte class with the themes in
+//! [`color`], and [`diff`] shows two inputs side by side with their
+//! differences marked. [`search`] finds byte patterns, wildcards and regexes
+//! included, and dumps the lines around them, and [`strings`] pulls out runs
+//! of text in ASCII, UTF-8, UTF-16 or UTF-32. [`codegen`] embeds a buffer
+//! as a C, Rust, Python, Go or Zig array. [`ihex`] and [`srec`] convert
+//! between bytes and Intel HEX or S-record files, which
+//! [`HexDumper::dump_image`] dumps in their own address space. [`json`]
+//! writes dumps as JSON Lines for other programs, listing on each line the
+//! labelled ranges and fields from [`annotate`] that cover it, and [`html`]
+//! as a single web page to explore them in. The
+//! vectorised loops behind every dump are available on their own in
+//! [`encode`].
+//!
+//! # Features
+//!
+//! The formatting core is `no_std` and never allocates: [`HexDumper`]
+//! writes to any [`core::fmt::Write`], and [`HexDumper::dump_into`] and
+//! [`SliceWriter`] render into a caller-provided buffer. Everything that
+//! needs the standard library sits behind the `std` feature, on by default:
+//! dumping to a `String`, readers and writers, [`HexDumper::lines`], the
+//! `xxd`, `undump`, `diff`, `search`, `strings`, `codegen`, `image`, `ihex`,
+//! `srec`, `annotate`, `json` and `html` modules and detecting terminal
+//! colour support. Build with `default-features = false` for embedded targets.
+//!
+//! The `serde` feature adds `serde` helpers that store byte fields as dump
+//! text, and the `mmap` feature adds `HexDumper::dump_file`, which dumps
+//! any part of a file by memory mapping it, and the `edit` module, which
+//! edits files of any size without copying them. The `tui` feature adds the
+//! `tui` module, a full-screen hex viewer and editor, and the `hexdump-tui`
+//! binary. The `regex` feature adds regex patterns to `search`, and the
+//! `visualize` feature adds the `visualize` module, which draws buffers as
+//! PNG pictures.
+//!
+//! ```
+//! # #[cfg(feature = "std")] {
+//! use hexdump::HexDumper;
+//!
+//! let text = HexDumper::new().dump(b"hexdump\x00");
+//! assert_eq!(
+//!     text,
+//!     "00000000: 68 65 78 64 75 6d 70 00                          |hexdump.|\n",
+//! );
+//! # }
+//! ```
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+#[cfg(feature = "std")]
+pub mod annotate;
+#[cfg(feature = "std")]
+pub mod codegen;
+pub mod color;
+#[cfg(feature = "std")]
+pub mod diff;
+mod display;
+mod dumper;
+#[cfg(feature = "mmap")]
+pub mod edit;
+pub mod encode;
+#[cfg(feature = "mmap")]
+mod file;
+#[cfg(feature = "std")]
+pub mod html;
+#[cfg(feature = "std")]
+pub mod ihex;
+#[cfg(feature = "std")]
+pub mod image;
+#[cfg(feature = "std")]
+pub mod json;
+#[cfg(feature = "std")]
+mod lines;
+mod preset;
+mod render;
+#[cfg(feature = "std")]
+pub mod search;
+#[cfg(feature = "serde")]
+pub mod serde;
+mod slice;
+#[cfg(feature = "std")]
+pub mod srec;
+#[cfg(feature = "std")]
+mod stream;
+#[cfg(feature = "std")]
+pub mod strings;
+#[cfg(feature = "tui")]
+pub mod tui;
+#[cfg(feature = "std")]
+pub mod undump;
+#[cfg(feature = "visualize")]
+pub mod visualize;
+#[cfg(feature = "std")]
+pub mod xxd;
+
+/// Why writing a dump to a `String` is sure to succeed.
+#[cfg(feature = "std")]
+const STRING_WRITE: &str = "writing to a String cannot fail";
+
+pub use display::{HexDump, HexDumpWith};
+pub use dumper::{is_printable, ByteFormat, HexDumper, OffsetBase, Squeeze, TrailingOffset};
+#[cfg(feature = "std")]
+pub use lines::Lines;
+pub use preset::{Preset, UnknownPreset};
+pub use slice::SliceWriter;
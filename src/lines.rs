@@ -0,0 +1,90 @@
+use core::iter::FusedIterator;
+use core::slice::Chunks;
+
+use crate::{HexDumper, STRING_WRITE};
+
+impl HexDumper {
+    /// Returns an iterator over the formatted lines of the dump of `data`.
+    ///
+    /// Lines are formatted lazily, one per call to `next`, and carry no
+    /// trailing newline. Nothing is rendered for lines that are never
+    /// requested, so `take(n)` on a large buffer only pays for `n` lines.
+    /// Every line is yielded: squeezing and the trailing offset line only
+    /// apply to whole dumps.
+    ///
+    /// ```
+    /// use hexdump::HexDumper;
+    ///
+    /// let dumper = HexDumper::new().bytes_per_line(4).show_ascii(false);
+    /// let mut lines = dumper.lines(b"abcdefghij");
+    /// assert_eq!(lines.len(), 3);
+    /// assert_eq!(lines.next().as_deref(), Some("00000000: 61 62 63 64"));
+    /// assert_eq!(lines.next_back().as_deref(), Some("00000008: 69 6a"));
+    /// ```
+    pub fn lines<'a>(&'a self, data: &'a [u8]) -> Lines<'a> {
+        Lines {
+            dumper: self,
+            chunks: data.chunks(self.bytes_per_line),
+            front: 0,
+            len: data.len() as u64,
+        }
+    }
+}
+
+/// Iterator over the lines of a dump, created by [`HexDumper::lines`].
+#[derive(Debug, Clone)]
+pub struct Lines<'a> {
+    dumper: &'a HexDumper,
+    chunks: Chunks<'a, u8>,
+    /// Offset of the next line yielded from the front.
+    front: u64,
+    /// Total length of the dumped data, used to place lines taken from the
+    /// back.
+    len: u64,
+}
+
+impl Lines<'_> {
+    fn render(&self, offset: u64, line: &[u8]) -> String {
+        let mut text = String::new();
+        self.dumper
+            .write_line(&mut text, offset, line)
+            .expect(STRING_WRITE);
+        text
+    }
+}
+
+impl Iterator for Lines<'_> {
+    type Item = String;
+
+    fn next(&mut self) -> Option<String> {
+        let line = self.chunks.next()?;
+        let offset = self.front;
+        self.front += line.len() as u64;
+        Some(self.render(offset, line))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.chunks.size_hint()
+    }
+
+    fn nth(&mut self, n: usize) -> Option<String> {
+        let skip = (n as u64).saturating_mul(self.dumper.bytes_per_line as u64);
+        let line = self.chunks.nth(n)?;
+        self.front += skip;
+        let offset = self.front;
+        self.front += line.len() as u64;
+        Some(self.render(offset, line))
+    }
+}
+
+impl DoubleEndedIterator for Lines<'_> {
+    fn next_back(&mut self) -> Option<String> {
+        let line = self.chunks.next_back()?;
+        self.len -= line.len() as u64;
+        Some(self.render(self.len, line))
+    }
+}
+
+impl ExactSizeIterator for Lines<'_> {}
+
+impl FusedIterator for Lines<'_> {}
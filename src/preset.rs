@@ -0,0 +1,158 @@
+use core::fmt;
+use core::str::FromStr;
+
+use crate::{ByteFormat, HexDumper, OffsetBase, Squeeze, TrailingOffset};
+
+/// Named layouts reproducing the output of common command line tools.
+///
+/// Apply one with [`HexDumper::preset`]. A preset sets every layout option,
+/// so setters chained after it adjust the preset rather than the other way
+/// round.
+///
+/// ```
+/// # #[cfg(feature = "std")] {
+/// use hexdump::{HexDumper, Preset};
+///
+/// let dumper = HexDumper::new().preset(Preset::Xxd).bytes_per_line(8);
+/// assert_eq!(dumper.dump(b"xxd\n"), "00000000: 7878 640a            xxd.\n");
+/// # }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum Preset {
+    /// Default `xxd` output: 16 bytes per line in pairs, `00000000: ` offsets
+    /// and an undelimited ASCII gutter.
+    Xxd,
+    /// `xxd -p`: 30 bytes per line of bare hex digits.
+    XxdPlain,
+    /// `xxd -b`: 6 bytes per line written as binary digits.
+    XxdBits,
+    /// `hexdump -C`: two groups of 8 bytes, a `|`-delimited ASCII gutter,
+    /// repeated lines squeezed into `*` and a trailing offset line.
+    HexdumpCanonical,
+    /// `od -A x -t x1z`: 6 digit hex offsets.
+    OdHex,
+    /// `od -A d -t x1z`: 7 digit decimal offsets.
+    OdDecimal,
+    /// `od -A o -t x1z`: 7 digit octal offsets.
+    OdOctal,
+}
+
+/// Error from parsing a [`Preset`] name.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct UnknownPreset;
+
+impl fmt::Display for UnknownPreset {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(
+            "unknown preset: expected xxd, xxd-plain, xxd-bits, hexdump, od-hex, od-decimal or \
+             od-octal",
+        )
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for UnknownPreset {}
+
+impl FromStr for Preset {
+    type Err = UnknownPreset;
+
+    /// Reads the names `hexdump-rs --preset` takes: `xxd`, `xxd-plain`,
+    /// `xxd-bits`, `hexdump`, `od-hex`, `od-decimal` and `od-octal`.
+    ///
+    /// ```
+    /// use hexdump::Preset;
+    ///
+    /// assert_eq!("od-hex".parse(), Ok(Preset::OdHex));
+    /// ```
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "xxd" => Preset::Xxd,
+            "xxd-plain" => Preset::XxdPlain,
+            "xxd-bits" => Preset::XxdBits,
+            "hexdump" => Preset::HexdumpCanonical,
+            "od-hex" => Preset::OdHex,
+            "od-decimal" => Preset::OdDecimal,
+            "od-octal" => Preset::OdOctal,
+            _ => return Err(UnknownPreset),
+        })
+    }
+}
+
+impl HexDumper {
+    /// Replaces the current layout with `preset`.
+    pub fn preset(mut self, preset: Preset) -> Self {
+        let base = HexDumper::new()
+            .offset_base(OffsetBase::Hex)
+            .offset_width(8)
+            .offset_separator(": ")
+            .group_separator(" ")
+            .ascii_separator("  ")
+            .ascii_delimiters("", "")
+            .byte_separator("")
+            .placeholder('.')
+            .uppercase(false)
+            .byte_format(ByteFormat::Hex)
+            .show_offset(true)
+            .show_ascii(true)
+            .squeeze(Squeeze::Off)
+            .squeeze_annotation(false)
+            .trailing_offset(TrailingOffset::Never);
+        let od = base
+            .clone()
+            .bytes_per_line(16)
+            .group_size(1)
+            .offset_separator(" ")
+            .ascii_delimiters(">", "<")
+            .squeeze(Squeeze::Always)
+            .trailing_offset(TrailingOffset::Always);
+        let layout = match preset {
+            Preset::Xxd => base.bytes_per_line(16).group_size(2),
+            Preset::XxdPlain => base
+                .bytes_per_line(30)
+                .group_size(0)
+                .show_offset(false)
+                .show_ascii(false),
+            Preset::XxdBits => base
+                .bytes_per_line(6)
+                .group_size(1)
+                .byte_format(ByteFormat::Binary),
+            Preset::HexdumpCanonical => base
+                .bytes_per_line(16)
+                .group_size(8)
+                .offset_separator("  ")
+                .group_separator("  ")
+                .byte_separator(" ")
+                .ascii_delimiters("|", "|")
+                .squeeze(Squeeze::Always)
+                .trailing_offset(TrailingOffset::NonEmpty),
+            Preset::OdHex => od.offset_width(6),
+            Preset::OdDecimal => od.offset_base(OffsetBase::Decimal).offset_width(7),
+            Preset::OdOctal => od.offset_base(OffsetBase::Octal).offset_width(7),
+        };
+        self.set_layout(&layout);
+        self
+    }
+
+    /// Copies every layout option from `other`.
+    fn set_layout(&mut self, other: &HexDumper) {
+        self.bytes_per_line = other.bytes_per_line;
+        self.group_size = other.group_size;
+        self.offset_width = other.offset_width;
+        self.offset_base = other.offset_base;
+        self.show_offset = other.show_offset;
+        self.uppercase = other.uppercase;
+        self.byte_format = other.byte_format;
+        self.offset_separator = other.offset_separator;
+        self.group_separator = other.group_separator;
+        self.byte_separator = other.byte_separator;
+        self.ascii_separator = other.ascii_separator;
+        self.ascii_left = other.ascii_left;
+        self.ascii_right = other.ascii_right;
+        self.show_ascii = other.show_ascii;
+        self.placeholder = other.placeholder;
+        self.squeeze = other.squeeze;
+        self.squeeze_annotation = other.squeeze_annotation;
+        self.trailing_offset = other.trailing_offset;
+    }
+}
@@ -0,0 +1,192 @@
+use core::fmt::{self, Write};
+
+use crate::color::{Markup, Painter};
+use crate::{HexDumper, Squeeze, TrailingOffset};
+
+/// Turns a sequence of lines into a complete dump.
+///
+/// Both the slice and the streaming paths feed their lines through a
+/// `Renderer`, which owns everything that spans more than one line: the
+/// running offset, squeezing of repeated lines and the trailing offset.
+///
+/// The renderer does not keep a copy of the previous line: callers pass it
+/// back in with every call, so rendering a slice needs no allocation.
+///
+/// Lines are marked up by `M`: coloured for a terminal by default.
+pub(crate) struct Renderer<'a, M = Painter<'a>> {
+    dumper: &'a HexDumper,
+    markup: M,
+    start: u64,
+    offset: u64,
+    repeats: Repeats,
+}
+
+impl<'a> Renderer<'a> {
+    /// Creates a renderer whose first byte is numbered `start`.
+    pub(crate) fn new(dumper: &'a HexDumper, start: u64) -> Self {
+        Renderer::with_markup(dumper, start, Painter::new(dumper))
+    }
+}
+
+impl<'a, M: Markup> Renderer<'a, M> {
+    /// Creates a renderer marking up its lines with `markup`.
+    pub(crate) fn with_markup(dumper: &'a HexDumper, start: u64, markup: M) -> Self {
+        Renderer {
+            dumper,
+            markup,
+            start,
+            offset: start,
+            repeats: Repeats::new(dumper.squeeze),
+        }
+    }
+
+    /// Renders the next line of input, which must be full unless it is the
+    /// last. `prev` is the line passed to the previous call, if any.
+    ///
+    /// Repeated lines are held back until the run ends, since whether and
+    /// how they are collapsed depends on its length.
+    pub(crate) fn line<W: Write>(
+        &mut self,
+        out: &mut W,
+        prev: Option<&[u8]>,
+        line: &[u8],
+    ) -> fmt::Result {
+        let offset = self.offset;
+        self.offset += line.len() as u64;
+        if self.repeats.is_on() {
+            if prev == Some(line) {
+                self.repeats.hold(offset, 1);
+                return Ok(());
+            }
+            self.end_run(out, prev)?;
+        }
+        self.write_line(out, offset, line)
+    }
+
+    /// Renders `count` more copies of `line`, the line just passed to
+    /// [`line`](Renderer::line). With squeezing on, this costs the same
+    /// however large `count` is.
+    #[cfg(feature = "mmap")]
+    pub(crate) fn repeat<W: Write>(&mut self, out: &mut W, line: &[u8], count: u64) -> fmt::Result {
+        if !self.repeats.is_on() {
+            for _ in 0..count {
+                self.line(out, Some(line), line)?;
+            }
+            return Ok(());
+        }
+        self.repeats.hold(self.offset, count);
+        self.offset += count * line.len() as u64;
+        Ok(())
+    }
+
+    /// Writes out the lines held back by the current run of repeats of
+    /// `prev`.
+    fn end_run<W: Write>(&mut self, out: &mut W, prev: Option<&[u8]>) -> fmt::Result {
+        let Some(run) = self.repeats.end() else {
+            return Ok(());
+        };
+        if run.squeezed {
+            out.write_char('*')?;
+            if self.dumper.squeeze_annotation {
+                let lines = run.lines;
+                let s = if lines == 1 { "" } else { "s" };
+                write!(out, " ({lines} identical line{s})")?;
+            }
+            return out.write_char('\n');
+        }
+        let prev = prev.unwrap_or_default();
+        let mut offset = run.start;
+        for _ in 0..run.lines {
+            self.write_line(out, offset, prev)?;
+            offset += prev.len() as u64;
+        }
+        Ok(())
+    }
+
+    fn write_line<W: Write>(&mut self, out: &mut W, offset: u64, line: &[u8]) -> fmt::Result {
+        self.dumper
+            .write_marked_line(out, offset, line, &mut self.markup, |_| None)?;
+        out.write_char('\n')
+    }
+
+    /// Ends the dump after `last`, the line passed to the final call to
+    /// [`line`](Renderer::line). Returns the number of bytes rendered.
+    pub(crate) fn finish<W: Write>(
+        mut self,
+        out: &mut W,
+        last: Option<&[u8]>,
+    ) -> Result<u64, fmt::Error> {
+        self.end_run(out, last)?;
+        let len = self.offset - self.start;
+        let show = match self.dumper.trailing_offset {
+            TrailingOffset::Never => false,
+            TrailingOffset::NonEmpty => len > 0,
+            TrailingOffset::Always => true,
+        };
+        if show {
+            self.dumper.write_offset(out, self.offset)?;
+            out.write_char('\n')?;
+        }
+        Ok(len)
+    }
+}
+
+/// Tracks the runs of repeated lines a dump holds back while squeezing,
+/// and decides which of them collapse into a single marker.
+pub(crate) struct Repeats {
+    squeeze: Squeeze,
+    /// Number of lines held back because they repeat the line before them.
+    lines: u64,
+    /// Offset of the first held back line.
+    start: u64,
+}
+
+/// A run of repeated lines, ended by a different line or the input's end.
+pub(crate) struct Run {
+    /// Offset of the first line of the run.
+    pub(crate) start: u64,
+    pub(crate) lines: u64,
+    /// Whether the run is long enough to collapse; otherwise its lines are
+    /// written out.
+    pub(crate) squeezed: bool,
+}
+
+impl Repeats {
+    pub(crate) fn new(squeeze: Squeeze) -> Self {
+        Repeats {
+            squeeze,
+            lines: 0,
+            start: 0,
+        }
+    }
+
+    /// Whether repeated lines are held back at all.
+    pub(crate) fn is_on(&self) -> bool {
+        self.squeeze != Squeeze::Off
+    }
+
+    /// Holds back `count` repeats of the line before, the first at `offset`.
+    pub(crate) fn hold(&mut self, offset: u64, count: u64) {
+        if count > 0 && self.lines == 0 {
+            self.start = offset;
+        }
+        self.lines += count;
+    }
+
+    /// Ends the current run, if lines are held back.
+    pub(crate) fn end(&mut self) -> Option<Run> {
+        let lines = core::mem::take(&mut self.lines);
+        if lines == 0 {
+            return None;
+        }
+        let min = match self.squeeze {
+            Squeeze::Off | Squeeze::Always => 1,
+            Squeeze::After(n) => n as u64,
+        };
+        Some(Run {
+            start: self.start,
+            lines,
+            squeezed: lines >= min,
+        })
+    }
+}
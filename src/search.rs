@@ -0,0 +1,669 @@
+//! Finding byte patterns in buffers and streams, and dumping around them.
+//!
+//! A [`Pattern`] is one of:
+//!
+//! | Constructor             | Matches                                          |
+//! |-------------------------|--------------------------------------------------|
+//! | [`Pattern::hex`]        | hex bytes with nibble wildcards: `4D 5A ?? ?0`   |
+//! | [`Pattern::bytes`]      | the bytes given                                  |
+//! | [`Pattern::text`]       | a string as UTF-8, which is ASCII for ASCII text |
+//! | [`Pattern::utf16le`], [`Pattern::utf16be`] | a string as UTF-16            |
+//! | `Pattern::regex`        | a `regex::bytes` expression, with the `regex` feature |
+//!
+//! Patterns find matches in a slice with [`Pattern::find_iter`] or in a
+//! reader with [`Pattern::find_reader`], and a [`Searcher`] takes a stream
+//! one chunk at a time. Streams are searched in bounded memory, and matches
+//! are found wherever the chunk boundaries fall. Matches do not overlap.
+//!
+//! [`SearchDumper`] dumps a buffer with the matches drawn in a highlight
+//! style, or only the lines around them, like `grep -C`. Without colour, a
+//! line of carets under the matched bytes follows each line with matches:
+//!
+//! ```
+//! use hexdump::search::{Pattern, SearchDumper};
+//!
+//! let pattern = Pattern::hex("4D 5A ?? ?0")?;
+//! let mut data = vec![0u8; 64];
+//! data[40..44].copy_from_slice(b"MZ\x90\x00");
+//! assert_eq!(pattern.find_iter(&data).collect::<Vec<_>>(), [40..44]);
+//!
+//! let dump = SearchDumper::new(pattern).context(0).dump(&data);
+//! assert_eq!(
+//!     dump,
+//!     concat!(
+//!         "00000020: 00 00 00 00 00 00 00 00 4d 5a 90 00 00 00 00 00  |........MZ......|\n",
+//!         "                                  ^^ ^^ ^^ ^^                       ^^^^\n",
+//!     ),
+//! );
+//! # Ok::<(), hexdump::search::PatternError>(())
+//! ```
+
+use std::cell::Cell;
+use std::collections::VecDeque;
+use std::error::Error;
+use std::fmt;
+use std::io::{self, Read, Write};
+use std::ops::Range;
+
+use crate::color::{Color, Column, Markup, Painter, Style};
+use crate::diff::Context;
+use crate::stream::{read_full, CHUNK_SIZE};
+use crate::{HexDumper, STRING_WRITE};
+
+/// The longest regex match a stream search finds whole, unless set with
+/// [`Pattern::max_len`].
+#[cfg(feature = "regex")]
+const REGEX_MAX_LEN: usize = 4096;
+
+/// Error from parsing a [`Pattern`].
+#[derive(Debug, Clone, PartialEq)]
+#[non_exhaustive]
+pub enum PatternError {
+    /// The pattern has no bytes to match.
+    Empty,
+    /// A hex pattern has a character other than a hex digit, `?` or
+    /// whitespace, at the given character index.
+    InvalidChar { index: usize, found: char },
+    /// A hex pattern has an odd number of nibbles.
+    UnpairedNibble,
+    /// A regex does not compile.
+    #[cfg(feature = "regex")]
+    Regex(regex::Error),
+}
+
+impl fmt::Display for PatternError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PatternError::Empty => f.write_str("empty pattern"),
+            PatternError::InvalidChar { index, found } => {
+                write!(f, "invalid character {found:?} at index {index}")
+            }
+            PatternError::UnpairedNibble => f.write_str("odd number of hex digits"),
+            #[cfg(feature = "regex")]
+            PatternError::Regex(err) => err.fmt(f),
+        }
+    }
+}
+
+impl Error for PatternError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            #[cfg(feature = "regex")]
+            PatternError::Regex(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+/// A byte pattern to search for.
+#[derive(Debug, Clone)]
+pub struct Pattern {
+    kind: Kind,
+    max_len: usize,
+}
+
+#[derive(Debug, Clone)]
+enum Kind {
+    /// Bytes compared under a mask: a byte `b` matches at `i` when
+    /// `b & mask[i] == value[i]`.
+    Masked {
+        value: Vec<u8>,
+        mask: Vec<u8>,
+        /// The first byte without wildcards, to scan for.
+        anchor: Option<usize>,
+    },
+    #[cfg(feature = "regex")]
+    Regex(regex::bytes::Regex),
+}
+
+impl Pattern {
+    /// Parses hex bytes in which any nibble may be a `?` wildcard: `??`
+    /// matches any byte and `?0` any byte whose low nibble is zero.
+    /// Whitespace between bytes is optional.
+    ///
+    /// ```
+    /// use hexdump::search::Pattern;
+    ///
+    /// let pattern = Pattern::hex("ca fe ?? b?")?;
+    /// assert!(pattern.find(b"\xca\xfe\x00\xbe").is_some());
+    /// assert!(pattern.find(b"\xca\xfe\x00\xce").is_none());
+    /// # Ok::<(), hexdump::search::PatternError>(())
+    /// ```
+    pub fn hex(text: &str) -> Result<Pattern, PatternError> {
+        let mut value = Vec::new();
+        let mut mask = Vec::new();
+        let mut high = None;
+        for (index, c) in text.chars().enumerate() {
+            if c.is_whitespace() {
+                continue;
+            }
+            let nibble = match c {
+                '?' => None,
+                _ => match c.to_digit(16) {
+                    Some(d) => Some(d as u8),
+                    None => return Err(PatternError::InvalidChar { index, found: c }),
+                },
+            };
+            match high.take() {
+                None => high = Some(nibble),
+                Some(hi) => {
+                    let (hv, hm) = hi.map_or((0, 0), |d| (d << 4, 0xf0));
+                    let (lv, lm) = nibble.map_or((0, 0), |d| (d, 0x0f));
+                    value.push(hv | lv);
+                    mask.push(hm | lm);
+                }
+            }
+        }
+        if high.is_some() {
+            return Err(PatternError::UnpairedNibble);
+        }
+        if value.is_empty() {
+            return Err(PatternError::Empty);
+        }
+        Ok(Pattern::masked(value, mask))
+    }
+
+    /// Matches `bytes` exactly. An empty pattern matches nothing.
+    pub fn bytes(bytes: impl Into<Vec<u8>>) -> Pattern {
+        let value = bytes.into();
+        let mask = vec![0xff; value.len()];
+        Pattern::masked(value, mask)
+    }
+
+    /// Matches `text` encoded as UTF-8, which for ASCII text is the text
+    /// itself.
+    pub fn text(text: &str) -> Pattern {
+        Pattern::bytes(text.as_bytes())
+    }
+
+    /// Matches `text` encoded as little-endian UTF-16, as in Windows
+    /// binaries.
+    pub fn utf16le(text: &str) -> Pattern {
+        Pattern::bytes(
+            text.encode_utf16()
+                .flat_map(u16::to_le_bytes)
+                .collect::<Vec<_>>(),
+        )
+    }
+
+    /// Matches `text` encoded as big-endian UTF-16.
+    pub fn utf16be(text: &str) -> Pattern {
+        Pattern::bytes(
+            text.encode_utf16()
+                .flat_map(u16::to_be_bytes)
+                .collect::<Vec<_>>(),
+        )
+    }
+
+    /// Compiles a [`regex::bytes::Regex`]. Unicode is on by default as
+    /// usual; write `(?-u)` to match arbitrary bytes with `\xff` and `.`.
+    ///
+    /// ```
+    /// use hexdump::search::Pattern;
+    ///
+    /// let pattern = Pattern::regex(r"(?-u)\x7fELF[\x01\x02]")?;
+    /// assert_eq!(pattern.find(b"..\x7fELF\x02\x01"), Some(2..7));
+    /// # Ok::<(), hexdump::search::PatternError>(())
+    /// ```
+    #[cfg(feature = "regex")]
+    pub fn regex(expr: &str) -> Result<Pattern, PatternError> {
+        let regex = regex::bytes::Regex::new(expr).map_err(PatternError::Regex)?;
+        Ok(Pattern {
+            kind: Kind::Regex(regex),
+            max_len: REGEX_MAX_LEN,
+        })
+    }
+
+    /// Sets the longest regex match a stream search is sure to find whole,
+    /// 4096 bytes by default. Longer matches may be cut short or missed
+    /// where they cross a chunk boundary. The memory a stream search uses
+    /// grows with this length. Other patterns have a fixed length and
+    /// ignore it.
+    pub fn max_len(mut self, len: usize) -> Self {
+        if !matches!(self.kind, Kind::Masked { .. }) {
+            self.max_len = len.max(1);
+        }
+        self
+    }
+
+    fn masked(value: Vec<u8>, mask: Vec<u8>) -> Pattern {
+        let anchor = mask.iter().position(|&m| m == 0xff);
+        Pattern {
+            max_len: value.len(),
+            kind: Kind::Masked {
+                value,
+                mask,
+                anchor,
+            },
+        }
+    }
+
+    /// The first match in `haystack`.
+    pub fn find(&self, haystack: &[u8]) -> Option<Range<usize>> {
+        self.find_at(haystack, 0)
+    }
+
+    /// The matches in `haystack`, in order.
+    pub fn find_iter<'a>(&'a self, haystack: &'a [u8]) -> impl Iterator<Item = Range<usize>> + 'a {
+        let mut from = 0;
+        std::iter::from_fn(move || {
+            let found = self.find_at(haystack, from)?;
+            from = next_start(&found);
+            Some(found)
+        })
+    }
+
+    /// The matches in everything read from `reader`, in order, with their
+    /// offsets in the stream.
+    ///
+    /// ```
+    /// use hexdump::search::Pattern;
+    ///
+    /// let pattern = Pattern::utf16le("key");
+    /// let data = b"\0k\0k\0e\0y\0\0";
+    /// let found: Vec<_> = pattern.find_reader(&data[..]).collect::<Result<_, _>>()?;
+    /// assert_eq!(found, [3..9]);
+    /// # Ok::<(), std::io::Error>(())
+    /// ```
+    pub fn find_reader<R: Read>(&self, reader: R) -> Matches<'_, R> {
+        Matches {
+            searcher: Searcher::new(self),
+            reader,
+            found: VecDeque::new(),
+            chunk: vec![0; CHUNK_SIZE],
+            done: false,
+        }
+    }
+
+    /// The length of the longest match.
+    #[cfg(feature = "tui")]
+    pub(crate) fn longest(&self) -> usize {
+        self.max_len
+    }
+
+    /// The first match in `haystack` starting at or after `from`.
+    pub(crate) fn find_at(&self, haystack: &[u8], from: usize) -> Option<Range<usize>> {
+        match &self.kind {
+            Kind::Masked {
+                value,
+                mask,
+                anchor,
+            } => {
+                let n = value.len();
+                if n == 0 || haystack.len() < n {
+                    return None;
+                }
+                let last = haystack.len() - n;
+                let matches_at = |start: usize| {
+                    haystack[start..start + n]
+                        .iter()
+                        .zip(value.iter().zip(mask))
+                        .all(|(&b, (&v, &m))| b & m == v)
+                };
+                let mut start = from;
+                while start <= last {
+                    if let Some(a) = *anchor {
+                        let skip = haystack[start + a..=last + a]
+                            .iter()
+                            .position(|&b| b == value[a])?;
+                        start += skip;
+                    }
+                    if matches_at(start) {
+                        return Some(start..start + n);
+                    }
+                    start += 1;
+                }
+                None
+            }
+            #[cfg(feature = "regex")]
+            Kind::Regex(regex) => regex.find_at(haystack, from).map(|found| found.range()),
+        }
+    }
+}
+
+/// Where to look for the match after `found`. Empty regex matches must not
+/// be found again.
+fn next_start(found: &Range<usize>) -> usize {
+    if found.is_empty() {
+        found.end + 1
+    } else {
+        found.end
+    }
+}
+
+/// Searches a stream pushed one chunk at a time.
+///
+/// The searcher keeps just enough of the stream to find matches that cross
+/// from one chunk into the next.
+///
+/// ```
+/// use hexdump::search::{Pattern, Searcher};
+///
+/// let pattern = Pattern::text("needle");
+/// let mut searcher = Searcher::new(&pattern);
+/// assert!(searcher.push(b"hay nee").is_empty());
+/// assert_eq!(searcher.push(b"dle hay"), [4..10]);
+/// assert!(searcher.finish().is_empty());
+/// ```
+#[derive(Debug)]
+pub struct Searcher<'p> {
+    pattern: &'p Pattern,
+    /// The unsearched end of the stream, and a byte before it for the
+    /// sake of regex word boundaries.
+    buf: Vec<u8>,
+    /// Offset of `buf[0]` in the stream.
+    base: u64,
+    /// Where in `buf` to search from.
+    pos: usize,
+}
+
+impl<'p> Searcher<'p> {
+    /// Creates a searcher at the start of a stream.
+    pub fn new(pattern: &'p Pattern) -> Self {
+        Searcher {
+            pattern,
+            buf: Vec::new(),
+            base: 0,
+            pos: 0,
+        }
+    }
+
+    /// Searches the next chunk of the stream. Returns the matches that are
+    /// now certain, which may have started in earlier chunks.
+    pub fn push(&mut self, chunk: &[u8]) -> Vec<Range<u64>> {
+        self.buf.extend_from_slice(chunk);
+        self.search(false)
+    }
+
+    /// Ends the stream, returning the matches still held back.
+    pub fn finish(&mut self) -> Vec<Range<u64>> {
+        let found = self.search(true);
+        self.base += self.buf.len() as u64;
+        self.buf.clear();
+        self.pos = 0;
+        found
+    }
+
+    /// The offset before which every match has been returned.
+    pub fn searched(&self) -> u64 {
+        self.base + self.pos as u64
+    }
+
+    fn search(&mut self, end: bool) -> Vec<Range<u64>> {
+        let len = self.buf.len();
+        let max_len = self.pattern.max_len;
+        let mut found = Vec::new();
+        while self.pos <= len {
+            let Some(m) = self.pattern.find_at(&self.buf, self.pos) else {
+                // More input can only complete matches starting this close
+                // to the end, since others would be longer than `max_len`.
+                self.pos = self.pos.max((len + 1).saturating_sub(max_len));
+                break;
+            };
+            // A match this close to the end might grow with more input.
+            if !end && m.start + max_len > len {
+                self.pos = m.start;
+                break;
+            }
+            self.pos = next_start(&m);
+            found.push(self.base + m.start as u64..self.base + m.end as u64);
+        }
+        self.pos = self.pos.min(len);
+        let drop = self.pos.saturating_sub(1);
+        self.buf.drain(..drop);
+        self.base += drop as u64;
+        self.pos -= drop;
+        found
+    }
+}
+
+/// Iterator over the matches in a reader, from [`Pattern::find_reader`].
+#[derive(Debug)]
+pub struct Matches<'p, R> {
+    searcher: Searcher<'p>,
+    reader: R,
+    found: VecDeque<Range<u64>>,
+    chunk: Vec<u8>,
+    done: bool,
+}
+
+impl<R: Read> Iterator for Matches<'_, R> {
+    type Item = io::Result<Range<u64>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(found) = self.found.pop_front() {
+                return Some(Ok(found));
+            }
+            if self.done {
+                return None;
+            }
+            let n = match read_full(&mut self.reader, &mut self.chunk) {
+                Ok(n) => n,
+                Err(err) => {
+                    self.done = true;
+                    return Some(Err(err));
+                }
+            };
+            self.found.extend(self.searcher.push(&self.chunk[..n]));
+            if n < self.chunk.len() {
+                self.found.extend(self.searcher.finish());
+                self.done = true;
+            }
+        }
+    }
+}
+
+/// Dumps with the matches of a pattern highlighted, or only the lines
+/// around them. Without colour, each line with matches is followed by a line
+/// with carets under the matched bytes.
+#[derive(Debug, Clone)]
+pub struct SearchDumper {
+    pattern: Pattern,
+    dumper: HexDumper,
+    context: Option<usize>,
+    highlight: Style,
+}
+
+impl SearchDumper {
+    /// Creates a dumper for the matches of `pattern`, with the default
+    /// layout.
+    pub fn new(pattern: Pattern) -> Self {
+        SearchDumper {
+            pattern,
+            dumper: HexDumper::new(),
+            context: None,
+            highlight: Style::new().fg(Color::BLACK).bg(Color::YELLOW),
+        }
+    }
+
+    /// Sets the layout.
+    ///
+    /// Squeezing and the trailing offset line do not apply.
+    pub fn dumper(mut self, dumper: HexDumper) -> Self {
+        self.dumper = dumper;
+        self
+    }
+
+    /// Shows only the lines with matches and `lines` lines of context
+    /// around them. Separate groups of lines are divided by a `--` line.
+    pub fn context(mut self, lines: usize) -> Self {
+        self.context = Some(lines);
+        self
+    }
+
+    /// Sets the style of matched bytes when colour is on.
+    pub fn highlight(mut self, style: Style) -> Self {
+        self.highlight = style;
+        self
+    }
+
+    /// Renders the dump of a buffer.
+    pub fn dump(&self, data: &[u8]) -> String {
+        let mut out = Vec::new();
+        self.dump_reader(data, &mut out)
+            .expect("reading from and writing to memory cannot fail");
+        String::from_utf8(out).expect("dumps are UTF-8")
+    }
+
+    /// Streams the dump of everything read from `reader` to `writer`.
+    /// Returns the number of matches.
+    pub fn dump_reader<R: Read, W: Write>(&self, mut reader: R, mut writer: W) -> io::Result<u64> {
+        let bpl = self.dumper.bytes_per_line;
+        let mut searcher = Searcher::new(&self.pattern);
+        let mut chunk = vec![0u8; CHUNK_SIZE];
+        // Bytes read but not dumped yet, and the offset of the first.
+        let mut pending = Vec::new();
+        let mut start = 0u64;
+        let mut hits = VecDeque::new();
+        let mut count = 0;
+        let mut context = Context::new(self.context);
+        let mut text = String::new();
+        let plain = Painter::new(&self.dumper).is_plain();
+        loop {
+            let n = read_full(&mut reader, &mut chunk)?;
+            let end = n < chunk.len();
+            let mut found = searcher.push(&chunk[..n]);
+            if end {
+                found.extend(searcher.finish());
+            }
+            let before = hits.len();
+            hits.extend(found.into_iter().filter(|hit| !hit.is_empty()));
+            count += (hits.len() - before) as u64;
+            pending.extend_from_slice(&chunk[..n]);
+
+            // Lines are dumped once every match touching them is known.
+            let ready = searcher.searched();
+            let mut done = 0;
+            while done < pending.len() {
+                let line = &pending[done..pending.len().min(done + bpl)];
+                let line_end = start + (done + line.len()) as u64;
+                if !end && (line.len() < bpl || line_end > ready) {
+                    break;
+                }
+                let offset = start + done as u64;
+                let highlight = |i| {
+                    let at = offset + i as u64;
+                    hits.iter()
+                        .any(|hit: &Range<u64>| hit.contains(&at))
+                        .then_some(self.highlight)
+                };
+                text.clear();
+                self.dumper
+                    .write_line_with(&mut text, offset, line, highlight)
+                    .expect(STRING_WRITE);
+                text.push('\n');
+                let hit = hits.iter().any(|hit| hit.start < line_end);
+                if hit && plain {
+                    self.write_carets(&mut text, offset, line, highlight);
+                }
+                context.row(&mut writer, &text, hit)?;
+                while hits.front().is_some_and(|hit| hit.end <= line_end) {
+                    hits.pop_front();
+                }
+                done += line.len();
+            }
+            pending.drain(..done);
+            start += done as u64;
+            if end {
+                break;
+            }
+        }
+        writer.flush()?;
+        Ok(count)
+    }
+
+    /// Writes a line with carets under the bytes of `line` that `highlight`
+    /// picks out.
+    fn write_carets<H>(&self, out: &mut String, offset: u64, line: &[u8], highlight: H)
+    where
+        H: Fn(usize) -> Option<Style>,
+    {
+        let written = Cell::new(0);
+        let mut carets = Carets {
+            written: &written,
+            marks: Vec::new(),
+            open: None,
+        };
+        self.dumper
+            .write_marked_line(
+                &mut Counter { written: &written },
+                offset,
+                line,
+                &mut carets,
+                highlight,
+            )
+            .expect(STRING_WRITE);
+        let mut column = 0;
+        for mark in carets.marks {
+            out.extend(std::iter::repeat_n(' ', mark.start - column));
+            out.extend(std::iter::repeat_n('^', mark.len()));
+            column = mark.end;
+        }
+        out.push('\n');
+    }
+}
+
+/// Counts the characters of a line instead of writing them.
+struct Counter<'a> {
+    written: &'a Cell<usize>,
+}
+
+impl fmt::Write for Counter<'_> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        self.written.set(self.written.get() + s.chars().count());
+        Ok(())
+    }
+}
+
+/// Notes which characters of a line draw highlighted bytes, as counted by
+/// a [`Counter`].
+struct Carets<'a> {
+    written: &'a Cell<usize>,
+    marks: Vec<Range<usize>>,
+    /// Where the highlighted byte being drawn starts.
+    open: Option<usize>,
+}
+
+impl Carets<'_> {
+    fn close(&mut self) {
+        if let Some(start) = self.open.take() {
+            self.marks.push(start..self.written.get());
+        }
+    }
+}
+
+impl Markup for Carets<'_> {
+    fn start<W: fmt::Write>(&mut self, _out: &mut W, _column: Column) -> fmt::Result {
+        self.close();
+        Ok(())
+    }
+
+    fn end<W: fmt::Write>(&mut self, _out: &mut W) -> fmt::Result {
+        self.close();
+        Ok(())
+    }
+
+    fn byte<W: fmt::Write>(
+        &mut self,
+        _out: &mut W,
+        _b: u8,
+        highlight: Option<Style>,
+    ) -> fmt::Result {
+        self.close();
+        if highlight.is_some() {
+            self.open = Some(self.written.get());
+        }
+        Ok(())
+    }
+
+    fn separator<W: fmt::Write>(
+        &mut self,
+        _out: &mut W,
+        _next: u8,
+        _highlight: Option<Style>,
+    ) -> fmt::Result {
+        self.close();
+        Ok(())
+    }
+}
@@ -0,0 +1,162 @@
+//! Serde helpers for byte fields, enabled by the `serde` feature.
+//!
+//! Use the module with `#[serde(with = "hexdump::serde")]` to write bytes
+//! as dump text, which reads well and can be edited by hand in YAML or TOML
+//! fixtures, or [`compact`] to write them as one string of hex digits.
+//! Either way the field is read back through the [`undump`](crate::undump)
+//! parser, so hand edits only need to keep the hex column right: the ASCII
+//! gutter is ignored.
+//!
+//! Any field type that converts from a `Vec<u8>` and to a byte slice works,
+//! including `Vec<u8>`, `[u8; N]` and `bytes::Bytes`. Formats that are not
+//! human readable, such as bincode, get plain bytes instead of text.
+//!
+//! ```
+//! use serde::{Deserialize, Serialize};
+//!
+//! #[derive(Serialize, Deserialize, PartialEq, Debug)]
+//! struct Fixture {
+//!     #[serde(with = "hexdump::serde")]
+//!     payload: Vec<u8>,
+//!     #[serde(with = "hexdump::serde::compact")]
+//!     key: [u8; 4],
+//! }
+//!
+//! let fixture = Fixture {
+//!     payload: b"hello".to_vec(),
+//!     key: [0xde, 0xad, 0xbe, 0xef],
+//! };
+//! let json = serde_json::to_string(&fixture)?;
+//! assert_eq!(
+//!     json,
+//!     r#"{"payload":"00000000: 68 65 6c 6c 6f                                   |hello|\n","key":"deadbeef"}"#,
+//! );
+//! assert_eq!(serde_json::from_str::<Fixture>(&json)?, fixture);
+//! # Ok::<(), serde_json::Error>(())
+//! ```
+
+use core::fmt;
+use core::marker::PhantomData;
+
+use ::serde::de::{self, Deserializer, Visitor};
+use ::serde::Serializer;
+
+use crate::undump::Undumper;
+use crate::HexDumper;
+
+/// Serialises bytes as dump text in the default [`HexDumper`] layout.
+pub fn serialize<T, S>(bytes: &T, serializer: S) -> Result<S::Ok, S::Error>
+where
+    T: AsRef<[u8]> + ?Sized,
+    S: Serializer,
+{
+    let bytes = bytes.as_ref();
+    if serializer.is_human_readable() {
+        serializer.serialize_str(&HexDumper::new().dump(bytes))
+    } else {
+        serializer.serialize_bytes(bytes)
+    }
+}
+
+/// Deserialises bytes from dump text in any layout the
+/// [`undump`](crate::undump) parser reads.
+/// Offsets past [`DEFAULT_MAX_LEN`](crate::undump::DEFAULT_MAX_LEN) are
+/// refused.
+pub fn deserialize<'de, T, D>(deserializer: D) -> Result<T, D::Error>
+where
+    T: TryFrom<Vec<u8>>,
+    D: Deserializer<'de>,
+{
+    read(deserializer, Undumper::new(), "a hex dump")
+}
+
+/// Helpers writing bytes as a single string of lower case hex digits, as in
+/// `"deadbeef"`.
+///
+/// Whitespace between digits is accepted when reading, so long values can
+/// be broken up by hand.
+pub mod compact {
+    use ::serde::{Deserializer, Serializer};
+
+    use crate::undump::Undumper;
+    use crate::HexDump;
+
+    /// Serialises bytes as hex digits.
+    pub fn serialize<T, S>(bytes: &T, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        T: AsRef<[u8]> + ?Sized,
+        S: Serializer,
+    {
+        let bytes = bytes.as_ref();
+        if serializer.is_human_readable() {
+            serializer.collect_str(&format_args!("{:x}", HexDump(bytes)))
+        } else {
+            serializer.serialize_bytes(bytes)
+        }
+    }
+
+    /// Deserialises bytes from hex digits.
+    pub fn deserialize<'de, T, D>(deserializer: D) -> Result<T, D::Error>
+    where
+        T: TryFrom<Vec<u8>>,
+        D: Deserializer<'de>,
+    {
+        super::read(deserializer, Undumper::new().plain(true), "hex digits")
+    }
+}
+
+fn read<'de, T, D>(
+    deserializer: D,
+    parser: Undumper,
+    expecting: &'static str,
+) -> Result<T, D::Error>
+where
+    T: TryFrom<Vec<u8>>,
+    D: Deserializer<'de>,
+{
+    let visitor = BytesVisitor {
+        parser,
+        expecting,
+        target: PhantomData,
+    };
+    if deserializer.is_human_readable() {
+        deserializer.deserialize_str(visitor)
+    } else {
+        deserializer.deserialize_byte_buf(visitor)
+    }
+}
+
+struct BytesVisitor<T> {
+    parser: Undumper,
+    expecting: &'static str,
+    target: PhantomData<T>,
+}
+
+impl<T: TryFrom<Vec<u8>>> BytesVisitor<T> {
+    fn convert<E: de::Error>(&self, bytes: Vec<u8>) -> Result<T, E> {
+        let len = bytes.len();
+        let expected = &"as many bytes as the field holds";
+        T::try_from(bytes).map_err(|_| E::invalid_length(len, expected))
+    }
+}
+
+impl<T: TryFrom<Vec<u8>>> Visitor<'_> for BytesVisitor<T> {
+    type Value = T;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.expecting)
+    }
+
+    fn visit_str<E: de::Error>(self, text: &str) -> Result<T, E> {
+        let bytes = self.parser.parse(text).map_err(E::custom)?;
+        self.convert(bytes)
+    }
+
+    fn visit_bytes<E: de::Error>(self, bytes: &[u8]) -> Result<T, E> {
+        self.convert(bytes.to_vec())
+    }
+
+    fn visit_byte_buf<E: de::Error>(self, bytes: Vec<u8>) -> Result<T, E> {
+        self.convert(bytes)
+    }
+}
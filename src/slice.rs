@@ -0,0 +1,95 @@
+use core::fmt::{self, Write};
+use core::str;
+
+use crate::HexDumper;
+
+/// A [`fmt::Write`] sink over a caller-provided byte buffer.
+///
+/// Writes that do not fit fail with [`fmt::Error`] and leave the buffer
+/// holding only the text written before them, so a dump can be rendered
+/// without any heap allocation, e.g. one line at a time into a small buffer
+/// on a microcontroller.
+///
+/// ```
+/// use core::fmt::Write;
+/// use hexdump::{HexDumper, SliceWriter};
+///
+/// let mut buf = [0u8; 80];
+/// let mut out = SliceWriter::new(&mut buf);
+/// HexDumper::new().bytes_per_line(4).write_line(&mut out, 0x20, b"uart")?;
+/// out.write_str("\r\n")?;
+/// assert_eq!(out.as_str(), "00000020: 75 61 72 74  |uart|\r\n");
+/// # Ok::<(), core::fmt::Error>(())
+/// ```
+#[derive(Debug)]
+pub struct SliceWriter<'a> {
+    buf: &'a mut [u8],
+    len: usize,
+}
+
+impl<'a> SliceWriter<'a> {
+    /// Creates a writer that fills `buf` from the start.
+    pub fn new(buf: &'a mut [u8]) -> Self {
+        SliceWriter { buf, len: 0 }
+    }
+
+    /// Number of bytes written so far.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Whether nothing has been written yet.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Discards everything written, to reuse the buffer for the next line.
+    pub fn clear(&mut self) {
+        self.len = 0;
+    }
+
+    /// The bytes written so far.
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.buf[..self.len]
+    }
+
+    /// The text written so far.
+    pub fn as_str(&self) -> &str {
+        str::from_utf8(self.as_bytes()).expect("only whole strs are written")
+    }
+
+    /// Returns the written part of the buffer.
+    pub fn into_written(self) -> &'a mut [u8] {
+        &mut self.buf[..self.len]
+    }
+}
+
+impl Write for SliceWriter<'_> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        let end = self.len + s.len();
+        let dest = self.buf.get_mut(self.len..end).ok_or(fmt::Error)?;
+        dest.copy_from_slice(s.as_bytes());
+        self.len = end;
+        Ok(())
+    }
+}
+
+impl HexDumper {
+    /// Writes the dump of `data` into `buf` without allocating. Returns the
+    /// number of bytes written, or [`fmt::Error`] if the dump does not fit.
+    ///
+    /// ```
+    /// use hexdump::HexDumper;
+    ///
+    /// let mut buf = [0u8; 64];
+    /// let n = HexDumper::new().bytes_per_line(4).dump_into(b"abc", &mut buf)?;
+    /// assert_eq!(&buf[..n], b"00000000: 61 62 63     |abc|\n");
+    /// assert!(HexDumper::new().dump_into(b"abc", &mut buf[..10]).is_err());
+    /// # Ok::<(), core::fmt::Error>(())
+    /// ```
+    pub fn dump_into(&self, data: &[u8], buf: &mut [u8]) -> Result<usize, fmt::Error> {
+        let mut out = SliceWriter::new(buf);
+        self.write_dump(&mut out, data)?;
+        Ok(out.len())
+    }
+}
@@ -0,0 +1,269 @@
+//! Reading and writing Motorola S-record files.
+//!
+//! [`parse`] reads every record type, S0 to S9, into an [`Image`], checking
+//! each record's checksum and the record counts in S5 and S6 records, and
+//! [`Encoder`] writes an image back as records.
+//!
+//! ```
+//! use hexdump::srec;
+//!
+//! let text = "\
+//! S00600004844521B
+//! S10700006865782192
+//! S5030001FB
+//! S9030000FC
+//! ";
+//! let file = srec::parse(text)?;
+//! assert_eq!(file.header, b"HDR");
+//! assert_eq!(file.image.segments()[0].data, b"hex!");
+//! assert_eq!(file.start, Some(0));
+//! assert_eq!(srec::Encoder::new().encode(&file)?, text);
+//! # Ok::<(), Box<dyn std::error::Error>>(())
+//! ```
+
+use std::error::Error;
+use std::fmt;
+
+use crate::image::{decode_hex, write_hex, AddressTooLarge, Image, Overlap};
+
+/// The contents of an S-record file.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SrecFile {
+    /// The data of the S0 header record, often a file name or version. Only
+    /// the first 251 bytes are written.
+    pub header: Vec<u8>,
+    pub image: Image,
+    /// The start address from the S7, S8 or S9 record.
+    pub start: Option<u32>,
+}
+
+/// Error from parsing an S-record file. Lines count from 1.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum SrecError {
+    /// A record does not start with `S` and a type digit.
+    InvalidType { line: usize },
+    /// A record holds something other than pairs of hex digits.
+    InvalidHex { line: usize },
+    /// A record's length does not match its byte count, or is too short for
+    /// its address.
+    BadLength { line: usize },
+    /// A record's checksum does not match its contents.
+    Checksum {
+        line: usize,
+        expected: u8,
+        found: u8,
+    },
+    /// An S5 or S6 record counts a different number of data records than
+    /// came before it.
+    Count {
+        line: usize,
+        expected: u32,
+        found: u32,
+    },
+    /// A data record gives bytes for addresses an earlier one gave.
+    Overlap { line: usize, address: u64 },
+}
+
+impl SrecError {
+    /// The line the error was found on.
+    pub fn line(&self) -> usize {
+        match *self {
+            SrecError::InvalidType { line }
+            | SrecError::InvalidHex { line }
+            | SrecError::BadLength { line }
+            | SrecError::Checksum { line, .. }
+            | SrecError::Count { line, .. }
+            | SrecError::Overlap { line, .. } => line,
+        }
+    }
+}
+
+impl fmt::Display for SrecError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "line {}: ", self.line())?;
+        match self {
+            SrecError::InvalidType { .. } => f.write_str("not an S0 to S9 record"),
+            SrecError::InvalidHex { .. } => f.write_str("invalid hex digit"),
+            SrecError::BadLength { .. } => f.write_str("record length does not match"),
+            SrecError::Checksum {
+                expected, found, ..
+            } => write!(f, "checksum is {found:02X}, expected {expected:02X}"),
+            SrecError::Count {
+                expected, found, ..
+            } => write!(f, "record count is {found}, expected {expected}"),
+            SrecError::Overlap { address, .. } => {
+                write!(f, "{}", Overlap { address: *address })
+            }
+        }
+    }
+}
+
+impl Error for SrecError {}
+
+/// Bytes of address in each record type.
+fn address_len(kind: u8) -> Option<usize> {
+    match kind {
+        b'0' | b'1' | b'5' | b'9' => Some(2),
+        b'2' | b'6' | b'8' => Some(3),
+        b'3' | b'7' => Some(4),
+        _ => None,
+    }
+}
+
+/// Parses S-record text.
+///
+/// Blank lines are skipped, and so are S4 records, which are reserved.
+/// Records after the S7, S8 or S9 termination record are read as well.
+pub fn parse(text: &str) -> Result<SrecFile, SrecError> {
+    let mut file = SrecFile::default();
+    let mut count = 0u32;
+    for (i, line) in text.lines().enumerate() {
+        let line_no = i + 1;
+        let line = line.trim();
+        if line.is_empty() {
+            continue;
+        }
+        let invalid_type = SrecError::InvalidType { line: line_no };
+        let kind = match line.as_bytes() {
+            [b'S' | b's', kind @ b'0'..=b'9', ..] => *kind,
+            _ => return Err(invalid_type),
+        };
+        if kind == b'4' {
+            continue;
+        }
+        let bytes = decode_hex(&line[2..]).ok_or(SrecError::InvalidHex { line: line_no })?;
+        let address_len = address_len(kind).expect("S4 is skipped above");
+        if bytes.len() < address_len + 2 || bytes.len() != usize::from(bytes[0]) + 1 {
+            return Err(SrecError::BadLength { line: line_no });
+        }
+        let (body, checksum) = bytes.split_at(bytes.len() - 1);
+        let expected = !body.iter().fold(0u8, |sum, &b| sum.wrapping_add(b));
+        if checksum[0] != expected {
+            return Err(SrecError::Checksum {
+                line: line_no,
+                expected,
+                found: checksum[0],
+            });
+        }
+        let address = body[1..=address_len]
+            .iter()
+            .fold(0u32, |n, &b| n << 8 | u32::from(b));
+        let data = &body[address_len + 1..];
+        match kind {
+            b'0' => file.header = data.to_vec(),
+            b'1'..=b'3' => {
+                file.image
+                    .insert(u64::from(address), data)
+                    .map_err(|err| SrecError::Overlap {
+                        line: line_no,
+                        address: err.address,
+                    })?;
+                count += 1;
+            }
+            b'5' | b'6' => {
+                if address != count {
+                    return Err(SrecError::Count {
+                        line: line_no,
+                        expected: count,
+                        found: address,
+                    });
+                }
+            }
+            _ => file.start = Some(address),
+        }
+    }
+    Ok(file)
+}
+
+/// Configurable S-record writer.
+///
+/// Data records are S1, S2 or S3, whichever is the narrowest that fits the
+/// highest address, followed by an S5 or S6 record count and the matching
+/// S9, S8 or S7 termination record, with a start address of zero if the
+/// file has none.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Encoder {
+    record_len: usize,
+    uppercase: bool,
+}
+
+impl Default for Encoder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Encoder {
+    /// Creates a writer for records of 16 data bytes with upper case hex
+    /// digits, as most tools write them.
+    pub fn new() -> Self {
+        Encoder {
+            record_len: 16,
+            uppercase: true,
+        }
+    }
+
+    /// Sets the most data bytes in a record, from 1 to 250.
+    pub fn record_len(mut self, len: usize) -> Self {
+        self.record_len = len.clamp(1, 250);
+        self
+    }
+
+    /// Upper case hex digits, the default.
+    pub fn uppercase(mut self, upper: bool) -> Self {
+        self.uppercase = upper;
+        self
+    }
+
+    /// Writes `file` as S-record text. Fails if the image holds addresses
+    /// above 32 bits.
+    pub fn encode(&self, file: &SrecFile) -> Result<String, AddressTooLarge> {
+        let end = file.image.end().unwrap_or(0);
+        if end > 1 << 32 {
+            return Err(AddressTooLarge { address: end - 1 });
+        }
+        let highest = end
+            .saturating_sub(1)
+            .max(u64::from(file.start.unwrap_or(0)));
+        let (data_kind, end_kind) = match highest {
+            0..=0xffff => (b'1', b'9'),
+            0x1_0000..=0xff_ffff => (b'2', b'8'),
+            _ => (b'3', b'7'),
+        };
+        let mut out = String::new();
+        // The header shares the record's 255 bytes with the count, address
+        // and checksum.
+        let header = &file.header[..file.header.len().min(251)];
+        self.record(&mut out, b'0', 0, header);
+        let mut count = 0u32;
+        for segment in file.image.segments() {
+            let mut address = segment.address;
+            for data in segment.data.chunks(self.record_len) {
+                self.record(&mut out, data_kind, address as u32, data);
+                address += data.len() as u64;
+                count += 1;
+            }
+        }
+        if count <= 0xffff {
+            self.record(&mut out, b'5', count, &[]);
+        } else if count <= 0xff_ffff {
+            self.record(&mut out, b'6', count, &[]);
+        }
+        self.record(&mut out, end_kind, file.start.unwrap_or(0), &[]);
+        Ok(out)
+    }
+
+    fn record(&self, out: &mut String, kind: u8, address: u32, data: &[u8]) {
+        let address_len = address_len(kind).expect("written record types are known");
+        let mut bytes = Vec::with_capacity(data.len() + address_len + 2);
+        bytes.push((address_len + data.len() + 1) as u8);
+        bytes.extend_from_slice(&address.to_be_bytes()[4 - address_len..]);
+        bytes.extend_from_slice(data);
+        bytes.push(!bytes.iter().fold(0u8, |sum, &b| sum.wrapping_add(b)));
+        out.push('S');
+        out.push(kind as char);
+        write_hex(out, &bytes, self.uppercase);
+        out.push('\n');
+    }
+}
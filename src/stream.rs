@@ -0,0 +1,80 @@
+use std::io::{self, Read, Write};
+
+use crate::render::Renderer;
+use crate::{HexDumper, STRING_WRITE};
+
+/// Size of the read buffer used by [`HexDumper::dump_reader`], rounded down
+/// to a whole number of lines.
+pub(crate) const CHUNK_SIZE: usize = 8192;
+
+impl HexDumper {
+    /// Dumps everything read from `reader` to `writer`, line by line.
+    ///
+    /// Input is read in fixed-size chunks, so memory use does not depend on
+    /// the amount of data. Offsets carry over from one chunk to the next and
+    /// the output is byte-identical to [`dump`](HexDumper::dump) on the same
+    /// bytes. Returns the number of bytes dumped.
+    ///
+    /// ```
+    /// use hexdump::HexDumper;
+    ///
+    /// let dumper = HexDumper::new();
+    /// let mut out = Vec::new();
+    /// let n = dumper.dump_reader(&b"streamed"[..], &mut out)?;
+    /// assert_eq!(n, 8);
+    /// assert_eq!(out, dumper.dump(b"streamed").into_bytes());
+    /// # Ok::<(), std::io::Error>(())
+    /// ```
+    pub fn dump_reader<R: Read, W: Write>(&self, reader: R, writer: W) -> io::Result<u64> {
+        self.dump_reader_from(reader, writer, 0)
+    }
+
+    /// Streams a dump whose first byte is numbered `start`. Returns the
+    /// number of bytes dumped.
+    pub(crate) fn dump_reader_from<R: Read, W: Write>(
+        &self,
+        mut reader: R,
+        mut writer: W,
+        start: u64,
+    ) -> io::Result<u64> {
+        let bpl = self.bytes_per_line;
+        let mut buf = vec![0u8; (CHUNK_SIZE / bpl).max(1) * bpl];
+        // The last line of the previous chunk, for squeezing across chunks.
+        let mut carried = Vec::with_capacity(bpl);
+        let mut text = String::new();
+        let mut renderer = Renderer::new(self, start);
+        loop {
+            let n = read_full(&mut reader, &mut buf)?;
+            text.clear();
+            let mut prev = (!carried.is_empty()).then_some(&carried[..]);
+            for line in buf[..n].chunks(bpl) {
+                renderer.line(&mut text, prev, line).expect(STRING_WRITE);
+                prev = Some(line);
+            }
+            if n < buf.len() {
+                let total = renderer.finish(&mut text, prev).expect(STRING_WRITE);
+                writer.write_all(text.as_bytes())?;
+                writer.flush()?;
+                return Ok(total);
+            }
+            writer.write_all(text.as_bytes())?;
+            carried.clear();
+            carried.extend_from_slice(&buf[n - bpl..n]);
+        }
+    }
+}
+
+/// Reads until `buf` is full or the reader is exhausted, retrying on
+/// [`io::ErrorKind::Interrupted`]. Returns the number of bytes read.
+pub(crate) fn read_full<R: Read>(reader: &mut R, buf: &mut [u8]) -> io::Result<usize> {
+    let mut filled = 0;
+    while filled < buf.len() {
+        match reader.read(&mut buf[filled..]) {
+            Ok(0) => break,
+            Ok(n) => filled += n,
+            Err(e) if e.kind() == io::ErrorKind::Interrupted => {}
+            Err(e) => return Err(e),
+        }
+    }
+    Ok(filled)
+}
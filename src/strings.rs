@@ -0,0 +1,609 @@
+//! Extracting runs of text from binary data, like GNU `strings`.
+//!
+//! [`Strings`] finds runs of at least [`min_len`](Strings::min_len)
+//! printable characters in any of the [`Encoding`]s asked for, and reports
+//! where each starts and how it is encoded. A character is printable as
+//! ASCII when the dump shows it in the ASCII gutter, that is, when
+//! [`is_printable`] holds. Other characters are
+//! printable in UTF-8 unless they are control characters. Binary data is
+//! full of 16- and 32-bit values that happen to be valid characters, so in
+//! UTF-16 and UTF-32 only ASCII and the Latin-1 letters `À` to `þ` count,
+//! much as with `strings -e l`; `ÿ` is left out, as `ff` bytes are mostly
+//! padding. Text found in one byte order usually turns
+//! up one byte along in the other as well.
+//!
+//! ```
+//! use hexdump::strings::{Encoding, Strings};
+//!
+//! let data = b"\x00\x01GNU libc\x00\xffk\x00e\x00r\x00n\x00e\x00l\x00\x00";
+//! let found = Strings::new()
+//!     .encodings(&[Encoding::Ascii, Encoding::Utf16Le])
+//!     .find(data);
+//! assert_eq!(found.len(), 2);
+//! assert_eq!((found[0].offset, found[0].encoding), (2, Encoding::Ascii));
+//! assert_eq!(found[0].text, "GNU libc");
+//! assert_eq!((found[1].offset, found[1].encoding), (12, Encoding::Utf16Le));
+//! assert_eq!(found[1].text, "kernel");
+//! ```
+//!
+//! [`Strings::report`] writes one line per string, and with
+//! [`lines`](Strings::lines) follows each with the dump lines holding it:
+//!
+//! ```text
+//! 0000000c  utf-16le  kernel
+//!   00000000: 00 01 47 4e 55 20 6c 69 62 63 00 ff 6b 00 65 00  |..GNU libc..k.e.|
+//!   00000010: 72 00 6e 00 65 00 6c 00 00                       |r.n.e.l..|
+//! ```
+
+use std::collections::VecDeque;
+use std::fmt;
+use std::io::{self, Read, Write};
+use std::ops::Range;
+
+use crate::color::{Color, Style};
+use crate::stream::{read_full, CHUNK_SIZE};
+use crate::{is_printable, HexDumper, STRING_WRITE};
+
+/// A text encoding to look for strings in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[non_exhaustive]
+pub enum Encoding {
+    /// Printable ASCII, one byte per character.
+    #[default]
+    Ascii,
+    /// UTF-8. Runs that turn out to be plain ASCII are reported as
+    /// [`Ascii`](Encoding::Ascii).
+    Utf8,
+    /// Little-endian UTF-16, as in Windows binaries.
+    Utf16Le,
+    /// Big-endian UTF-16, as in Java class files.
+    Utf16Be,
+    /// Little-endian UTF-32.
+    Utf32Le,
+    /// Big-endian UTF-32.
+    Utf32Be,
+}
+
+impl Encoding {
+    /// Every encoding.
+    pub const ALL: [Encoding; 6] = [
+        Encoding::Ascii,
+        Encoding::Utf8,
+        Encoding::Utf16Le,
+        Encoding::Utf16Be,
+        Encoding::Utf32Le,
+        Encoding::Utf32Be,
+    ];
+
+    /// The name reports use: `ascii`, `utf-8`, `utf-16le` and so on.
+    pub fn name(self) -> &'static str {
+        match self {
+            Encoding::Ascii => "ascii",
+            Encoding::Utf8 => "utf-8",
+            Encoding::Utf16Le => "utf-16le",
+            Encoding::Utf16Be => "utf-16be",
+            Encoding::Utf32Le => "utf-32le",
+            Encoding::Utf32Be => "utf-32be",
+        }
+    }
+
+    /// Bytes per code unit.
+    fn unit(self) -> usize {
+        match self {
+            Encoding::Ascii | Encoding::Utf8 => 1,
+            Encoding::Utf16Le | Encoding::Utf16Be => 2,
+            Encoding::Utf32Le | Encoding::Utf32Be => 4,
+        }
+    }
+}
+
+impl fmt::Display for Encoding {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+/// A string found in the data.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Found {
+    /// Offset of the first byte of the string.
+    pub offset: u64,
+    /// Length of the string in bytes.
+    pub len: u64,
+    /// The encoding the string was found in.
+    pub encoding: Encoding,
+    /// The string decoded, which may have fewer characters than `len` has
+    /// bytes.
+    pub text: String,
+}
+
+impl Found {
+    /// The bytes the string occupies.
+    pub fn range(&self) -> Range<u64> {
+        self.offset..self.offset + self.len
+    }
+
+    /// The offset of the line that `dumper` shows the start of the string
+    /// on, for data dumped from offset zero.
+    pub fn line_offset(&self, dumper: &HexDumper) -> u64 {
+        self.offset - self.offset % dumper.bytes_per_line as u64
+    }
+}
+
+/// Configurable string extractor.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Strings {
+    min_len: usize,
+    encodings: Vec<Encoding>,
+    dumper: HexDumper,
+    lines: bool,
+    highlight: Style,
+}
+
+impl Default for Strings {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Strings {
+    /// Creates an extractor for ASCII strings of at least four characters,
+    /// like `strings`.
+    pub fn new() -> Self {
+        Strings {
+            min_len: 4,
+            encodings: vec![Encoding::Ascii],
+            dumper: HexDumper::new(),
+            lines: false,
+            highlight: Style::new().fg(Color::BLACK).bg(Color::YELLOW),
+        }
+    }
+
+    /// Sets the minimum number of characters in a string. Zero counts as
+    /// one.
+    pub fn min_len(mut self, chars: usize) -> Self {
+        self.min_len = chars.max(1);
+        self
+    }
+
+    /// Sets the encodings to look for, replacing the default of ASCII only.
+    pub fn encodings(mut self, encodings: &[Encoding]) -> Self {
+        self.encodings = encodings.to_vec();
+        self
+    }
+
+    /// Sets the layout of reports: the format of their offsets and of the
+    /// dump lines shown with [`lines`](Strings::lines).
+    pub fn dumper(mut self, dumper: HexDumper) -> Self {
+        self.dumper = dumper;
+        self
+    }
+
+    /// Follows each string in reports with the dump lines holding it, its
+    /// bytes drawn in the [`highlight`](Strings::highlight) style when
+    /// colour is on.
+    pub fn lines(mut self, lines: bool) -> Self {
+        self.lines = lines;
+        self
+    }
+
+    /// Sets the style of string bytes in the dump lines of reports.
+    pub fn highlight(mut self, style: Style) -> Self {
+        self.highlight = style;
+        self
+    }
+
+    /// Finds the strings in a buffer, in order of offset.
+    pub fn find(&self, data: &[u8]) -> Vec<Found> {
+        let mut scanner = self.scanner();
+        let mut found = scanner.push(data);
+        found.extend(scanner.finish());
+        found
+    }
+
+    /// The strings in everything read from `reader`, in order of offset.
+    pub fn find_reader<R: Read>(&self, reader: R) -> FoundIter<R> {
+        FoundIter {
+            scanner: self.scanner(),
+            reader,
+            found: VecDeque::new(),
+            chunk: vec![0; CHUNK_SIZE],
+            done: false,
+        }
+    }
+
+    /// Creates a scanner to push a stream through one chunk at a time.
+    pub fn scanner(&self) -> StringScanner {
+        StringScanner::new(self)
+    }
+
+    /// Writes a line for each string read from `reader` to `writer`: its
+    /// offset, in the dumper's format, its encoding and its text. Returns
+    /// the number of strings.
+    ///
+    /// ```
+    /// use hexdump::strings::Strings;
+    ///
+    /// let mut out = Vec::new();
+    /// Strings::new().report(&b"\x00\x00\x00hello\x00"[..], &mut out)?;
+    /// assert_eq!(out, b"00000003  ascii     hello\n");
+    /// # Ok::<(), std::io::Error>(())
+    /// ```
+    pub fn report<R: Read, W: Write>(&self, mut reader: R, mut writer: W) -> io::Result<u64> {
+        let bpl = self.dumper.bytes_per_line as u64;
+        let mut scanner = self.scanner();
+        let mut chunk = vec![0u8; CHUNK_SIZE];
+        // With `lines`, the bytes from the first line a string yet to be
+        // reported may start on.
+        let mut kept = Vec::new();
+        let mut kept_start = 0u64;
+        let mut count = 0;
+        let mut text = String::new();
+        loop {
+            let n = read_full(&mut reader, &mut chunk)?;
+            let end = n < chunk.len();
+            let mut found = scanner.push(&chunk[..n]);
+            if end {
+                found.extend(scanner.finish());
+            }
+            if self.lines {
+                kept.extend_from_slice(&chunk[..n]);
+            }
+            for found in &found {
+                text.clear();
+                self.write_found(&mut text, found, &kept, kept_start)
+                    .expect(STRING_WRITE);
+                writer.write_all(text.as_bytes())?;
+            }
+            count += found.len() as u64;
+            if end {
+                break;
+            }
+            if self.lines {
+                let from = scanner.pending_from();
+                let drop = (from - from % bpl - kept_start) as usize;
+                kept.drain(..drop);
+                kept_start += drop as u64;
+            }
+        }
+        writer.flush()?;
+        Ok(count)
+    }
+
+    fn write_found(
+        &self,
+        out: &mut String,
+        found: &Found,
+        kept: &[u8],
+        kept_start: u64,
+    ) -> fmt::Result {
+        use fmt::Write as _;
+
+        self.dumper.write_offset(out, found.offset)?;
+        writeln!(out, "  {:<8}  {}", found.encoding.name(), found.text)?;
+        if !self.lines {
+            return Ok(());
+        }
+        let bpl = self.dumper.bytes_per_line as u64;
+        let range = found.range();
+        let mut start = found.offset - found.offset % bpl;
+        while start < range.end {
+            let from = (start - kept_start) as usize;
+            let line = &kept[from..kept.len().min(from + bpl as usize)];
+            out.push_str("  ");
+            self.dumper.write_line_with(out, start, line, |i| {
+                range
+                    .contains(&(start + i as u64))
+                    .then_some(self.highlight)
+            })?;
+            out.push('\n');
+            start += bpl;
+        }
+        Ok(())
+    }
+}
+
+/// Finds strings in a stream pushed one chunk at a time, from
+/// [`Strings::scanner`].
+///
+/// ```
+/// use hexdump::strings::Strings;
+///
+/// let strings = Strings::new();
+/// let mut scanner = strings.scanner();
+/// assert!(scanner.push(b"\x00spl").is_empty());
+/// let found = scanner.push(b"it\x00");
+/// assert_eq!((found[0].offset, found[0].text.as_str()), (1, "split"));
+/// ```
+#[derive(Debug)]
+pub struct StringScanner {
+    min_len: usize,
+    decoders: Vec<Decoder>,
+    /// Offset of the next byte.
+    offset: u64,
+    /// Strings found but held back until those that may start before them
+    /// are complete.
+    held: Vec<Found>,
+}
+
+impl StringScanner {
+    fn new(strings: &Strings) -> Self {
+        let has = |e| strings.encodings.contains(&e);
+        let mut decoders = Vec::new();
+        if has(Encoding::Utf8) {
+            decoders.push(Decoder::new(Encoding::Utf8, 0));
+        } else if has(Encoding::Ascii) {
+            decoders.push(Decoder::new(Encoding::Ascii, 0));
+        }
+        for encoding in [
+            Encoding::Utf16Le,
+            Encoding::Utf16Be,
+            Encoding::Utf32Le,
+            Encoding::Utf32Be,
+        ] {
+            if has(encoding) {
+                for phase in 0..encoding.unit() {
+                    decoders.push(Decoder::new(encoding, phase as u64));
+                }
+            }
+        }
+        StringScanner {
+            min_len: strings.min_len,
+            decoders,
+            offset: 0,
+            held: Vec::new(),
+        }
+    }
+
+    /// Scans the next chunk of the stream. Returns the strings that are now
+    /// complete, in order of offset; a string still growing holds back those
+    /// after its start.
+    pub fn push(&mut self, chunk: &[u8]) -> Vec<Found> {
+        for &b in chunk {
+            for decoder in &mut self.decoders {
+                decoder.byte(b, self.offset, self.min_len, &mut self.held);
+            }
+            self.offset += 1;
+        }
+        self.release(self.active_from())
+    }
+
+    /// Ends the stream, returning the strings still held back.
+    pub fn finish(&mut self) -> Vec<Found> {
+        for decoder in &mut self.decoders {
+            decoder.end_run(self.min_len, &mut self.held);
+            decoder.reset_unit();
+        }
+        self.release(u64::MAX)
+    }
+
+    /// The offset before which every string has been returned.
+    pub fn pending_from(&self) -> u64 {
+        let held = self.held.iter().map(|found| found.offset).min();
+        self.active_from().min(held.unwrap_or(u64::MAX))
+    }
+
+    /// The earliest offset a string still being read may start at.
+    fn active_from(&self) -> u64 {
+        self.decoders
+            .iter()
+            .filter_map(Decoder::active_from)
+            .min()
+            .unwrap_or(self.offset)
+    }
+
+    fn release(&mut self, before: u64) -> Vec<Found> {
+        self.held.sort_by_key(|found| {
+            (
+                found.offset,
+                Encoding::ALL.iter().position(|&e| e == found.encoding),
+            )
+        });
+        let n = self.held.partition_point(|found| found.offset < before);
+        self.held.drain(..n).collect()
+    }
+}
+
+/// Decodes one encoding at one alignment, collecting a run of printable
+/// characters.
+#[derive(Debug)]
+struct Decoder {
+    encoding: Encoding,
+    /// Offset modulo the unit size at which code units start.
+    phase: u64,
+    /// The bytes of the code unit or UTF-8 sequence being read.
+    unit: [u8; 4],
+    filled: usize,
+    /// Bytes in the UTF-8 sequence being read.
+    need: usize,
+    /// Offset of `unit[0]`.
+    unit_start: u64,
+    run: String,
+    run_start: u64,
+    run_end: u64,
+    chars: usize,
+}
+
+impl Decoder {
+    fn new(encoding: Encoding, phase: u64) -> Self {
+        Decoder {
+            encoding,
+            phase,
+            unit: [0; 4],
+            filled: 0,
+            need: 0,
+            unit_start: 0,
+            run: String::new(),
+            run_start: 0,
+            run_end: 0,
+            chars: 0,
+        }
+    }
+
+    fn active_from(&self) -> Option<u64> {
+        if self.chars > 0 {
+            Some(self.run_start)
+        } else if self.filled > 0 {
+            Some(self.unit_start)
+        } else {
+            None
+        }
+    }
+
+    fn byte(&mut self, b: u8, offset: u64, min_len: usize, out: &mut Vec<Found>) {
+        match self.encoding {
+            Encoding::Ascii | Encoding::Utf8 => self.text_byte(b, offset, min_len, out),
+            _ => self.wide_byte(b, offset, min_len, out),
+        }
+    }
+
+    fn text_byte(&mut self, b: u8, offset: u64, min_len: usize, out: &mut Vec<Found>) {
+        if self.filled > 0 {
+            if b & 0xc0 == 0x80 {
+                self.unit[self.filled] = b;
+                self.filled += 1;
+                if self.filled == self.need {
+                    let c = std::str::from_utf8(&self.unit[..self.need])
+                        .ok()
+                        .and_then(|s| s.chars().next());
+                    self.filled = 0;
+                    self.char(c, offset + 1, false, min_len, out);
+                }
+                return;
+            }
+            // A sequence cut short: `b` may start something new.
+            self.filled = 0;
+            self.end_run(min_len, out);
+        }
+        if b.is_ascii() {
+            self.unit_start = offset;
+            self.char(Some(b as char), offset + 1, false, min_len, out);
+            return;
+        }
+        self.need = match b {
+            0xc2..=0xdf => 2,
+            0xe0..=0xef => 3,
+            0xf0..=0xf4 => 4,
+            _ => 0,
+        };
+        if self.encoding == Encoding::Utf8 && self.need > 0 {
+            self.unit[0] = b;
+            self.filled = 1;
+            self.unit_start = offset;
+        } else {
+            self.end_run(min_len, out);
+        }
+    }
+
+    fn wide_byte(&mut self, b: u8, offset: u64, min_len: usize, out: &mut Vec<Found>) {
+        let size = self.encoding.unit() as u64;
+        let index = ((offset % size) + size - self.phase) % size;
+        if index == 0 {
+            self.unit_start = offset;
+            self.filled = 0;
+        } else if self.filled == 0 {
+            // Not aligned yet.
+            return;
+        }
+        self.unit[self.filled] = b;
+        self.filled += 1;
+        if index + 1 < size {
+            return;
+        }
+        let bytes = &self.unit[..self.filled];
+        let value = match self.encoding {
+            Encoding::Utf16Le => u32::from(u16::from_le_bytes([bytes[0], bytes[1]])),
+            Encoding::Utf16Be => u32::from(u16::from_be_bytes([bytes[0], bytes[1]])),
+            Encoding::Utf32Le => u32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]),
+            _ => u32::from_be_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]),
+        };
+        self.filled = 0;
+        self.char(char::from_u32(value), offset + 1, true, min_len, out);
+    }
+
+    /// Adds a decoded character, ending `end`, to the run if it is
+    /// printable, or ends the run.
+    fn char(
+        &mut self,
+        c: Option<char>,
+        end: u64,
+        wide: bool,
+        min_len: usize,
+        out: &mut Vec<Found>,
+    ) {
+        let printable = match c {
+            Some(c) if c.is_ascii() => is_printable(c as u8),
+            Some(c) if wide => ('\u{c0}'..='\u{fe}').contains(&c) && c.is_alphabetic(),
+            Some(c) => !c.is_control(),
+            None => false,
+        };
+        let Some(c) = c.filter(|_| printable) else {
+            self.end_run(min_len, out);
+            return;
+        };
+        if self.chars == 0 {
+            self.run_start = self.unit_start;
+        }
+        self.run.push(c);
+        self.run_end = end;
+        self.chars += 1;
+    }
+
+    fn end_run(&mut self, min_len: usize, out: &mut Vec<Found>) {
+        if self.chars >= min_len {
+            let encoding = match self.encoding {
+                Encoding::Utf8 if self.run.is_ascii() => Encoding::Ascii,
+                encoding => encoding,
+            };
+            out.push(Found {
+                offset: self.run_start,
+                len: self.run_end - self.run_start,
+                encoding,
+                text: std::mem::take(&mut self.run),
+            });
+        }
+        self.run.clear();
+        self.chars = 0;
+    }
+
+    fn reset_unit(&mut self) {
+        self.filled = 0;
+    }
+}
+
+/// Iterator over the strings in a reader, from [`Strings::find_reader`].
+#[derive(Debug)]
+pub struct FoundIter<R> {
+    scanner: StringScanner,
+    reader: R,
+    found: VecDeque<Found>,
+    chunk: Vec<u8>,
+    done: bool,
+}
+
+impl<R: Read> Iterator for FoundIter<R> {
+    type Item = io::Result<Found>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(found) = self.found.pop_front() {
+                return Some(Ok(found));
+            }
+            if self.done {
+                return None;
+            }
+            let n = match read_full(&mut self.reader, &mut self.chunk) {
+                Ok(n) => n,
+                Err(err) => {
+                    self.done = true;
+                    return Some(Err(err));
+                }
+            };
+            self.found.extend(self.scanner.push(&self.chunk[..n]));
+            if n < self.chunk.len() {
+                self.found.extend(self.scanner.finish());
+                self.done = true;
+            }
+        }
+    }
+}
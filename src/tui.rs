@@ -0,0 +1,700 @@
+//! A full-screen, scrollable hex viewer for the terminal, enabled by the
+//! `tui` feature.
+//!
+//! [`Viewer`] shows a file with the lines of a [`HexDumper`], coloured with
+//! its theme, and a status area reading the bytes under the cursor as
+//! integers of every width in both byte orders, as floats and as a Unix
+//! time. Files are memory mapped, so opening a large one is instant.
+//!
+//! With [`editable`](Viewer::editable) the viewer is also an editor, built on
+//! an [`edit::Buffer`](crate::edit::Buffer): edits never copy the file, can
+//! be undone, and are saved atomically.
+//!
+//! | Key                      | Action                                 |
+//! |--------------------------|----------------------------------------|
+//! | arrows, `h` `j` `k` `l`  | move the cursor                        |
+//! | `PageUp`, `PageDown`     | move a screen up or down               |
+//! | `Home`, `End`            | go to the start or end of the line     |
+//! | `g`, `G`                 | go to the start or end of the file     |
+//! | `:`                      | go to an offset: `0x400`, `1024`, `+16`, `-0x10` |
+//! | `/`                      | search for hex bytes, `de ad ?? 0?`, or text, `"PNG"` or `PNG` |
+//! | `n`, `N`                 | go to the next or previous match       |
+//! | `q`, `Esc`, `Ctrl-C`     | quit                                   |
+//!
+//! When editing, these keys are added:
+//!
+//! | Key                      | Action                                 |
+//! |--------------------------|----------------------------------------|
+//! | `0`-`9`, `a`-`f`         | type a nibble in the hex column        |
+//! | `Tab`                    | switch between the hex and text columns; in the text column, characters are typed as UTF-8 and `Esc` switches back |
+//! | `i`, `Insert`            | switch between overwriting and inserting |
+//! | `x`, `Delete`            | delete the byte under the cursor       |
+//! | `Backspace`              | delete the byte before the cursor      |
+//! | `u`, `Ctrl-Z`            | undo                                   |
+//! | `U`, `Ctrl-Y`, `Ctrl-R`  | redo                                   |
+//! | `w`, `Ctrl-S`            | save                                   |
+//!
+//! Quitting with unsaved changes asks for a second `q` first.
+//!
+//! ```no_run
+//! use hexdump::tui::Viewer;
+//! use hexdump::{HexDumper, Preset};
+//!
+//! Viewer::open("firmware.bin")?
+//!     .dumper(HexDumper::new().preset(Preset::HexdumpCanonical))
+//!     .run()?;
+//! # Ok::<(), std::io::Error>(())
+//! ```
+
+mod values;
+
+use std::fmt::Write as _;
+use std::io::{self, Write};
+use std::ops::Range;
+use std::path::Path;
+
+use crossterm::cursor::{Hide, MoveTo, Show};
+use crossterm::event::{self, Event, KeyEventKind};
+use crossterm::terminal::{
+    self, Clear, ClearType, DisableLineWrap, EnableLineWrap, EnterAlternateScreen,
+    LeaveAlternateScreen,
+};
+use crossterm::{execute, queue};
+
+use crate::color::{Color, ColorChoice, Style};
+use crate::edit::{Buffer, SaveError};
+use crate::search::Pattern;
+use crate::{HexDumper, STRING_WRITE};
+
+/// The key events taken by [`Viewer::key`].
+pub use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+
+/// Rows below the dump: the status bar, two rows of values and the prompt.
+const STATUS_ROWS: usize = 4;
+
+const CURSOR: Style = Style::new().fg(Color::BLACK).bg(Color::WHITE);
+const MATCH: Style = Style::new().fg(Color::BLACK).bg(Color::YELLOW);
+
+const REVERSE: &str = "\x1b[7m";
+const RESET: &str = "\x1b[0m";
+
+const HINTS: &str = "q quit  : goto  / search  n/N next/previous  g/G start/end";
+const EDIT_HINTS: &str =
+    "q quit  w save  u/U undo/redo  i insert  Tab hex/text  : goto  / search  n/N next/previous";
+
+/// How many bytes a search reads at a time.
+const SEARCH_BLOCK: u64 = 1 << 16;
+
+/// An interactive hex viewer.
+///
+/// [`run`](Viewer::run) takes over the terminal until the user quits. The
+/// viewer can also be driven without a terminal, one key at a time, with
+/// [`resize`](Viewer::resize), [`key`](Viewer::key) and
+/// [`screen`](Viewer::screen).
+pub struct Viewer {
+    buffer: Buffer,
+    name: String,
+    /// The layout as configured.
+    dumper: HexDumper,
+    /// The layout narrowed to fit the terminal.
+    layout: HexDumper,
+    cursor: u64,
+    /// Offset of the first line on screen.
+    top: u64,
+    width: u16,
+    height: u16,
+    prompt: Option<Prompt>,
+    pattern: Option<Pattern>,
+    message: Option<String>,
+    done: bool,
+    editable: bool,
+    /// Whether typing inserts bytes rather than overwriting them.
+    insert: bool,
+    /// Whether typing goes to the text column rather than the hex column.
+    text: bool,
+    /// Whether the high nibble of the byte under the cursor was just typed.
+    nibble: bool,
+    /// Whether the user was just warned about quitting with unsaved changes.
+    quitting: bool,
+}
+
+/// A line being typed at the bottom of the screen.
+struct Prompt {
+    kind: PromptKind,
+    text: String,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum PromptKind {
+    Goto,
+    Search,
+}
+
+impl Viewer {
+    /// Opens the file at `path` for viewing.
+    ///
+    /// The file is memory mapped and must not be truncated while it is
+    /// shown; see [`HexDumper::dump_file`].
+    pub fn open<P: AsRef<Path>>(path: P) -> io::Result<Viewer> {
+        let path = path.as_ref();
+        let buffer = Buffer::open(path)?;
+        Ok(Viewer::with_buffer(buffer, path.display().to_string()))
+    }
+
+    /// Views bytes held in memory, such as standard input read to the end.
+    pub fn new(bytes: Vec<u8>, name: impl Into<String>) -> Viewer {
+        Viewer::with_buffer(Buffer::new(bytes), name.into())
+    }
+
+    /// Views the contents of a buffer, which is saved to its file when
+    /// editing.
+    pub fn with_buffer(buffer: Buffer, name: impl Into<String>) -> Viewer {
+        let dumper = HexDumper::new().color(ColorChoice::Always);
+        let mut viewer = Viewer {
+            buffer,
+            name: name.into(),
+            layout: dumper.clone(),
+            dumper,
+            cursor: 0,
+            top: 0,
+            width: 80,
+            height: 24,
+            prompt: None,
+            pattern: None,
+            message: None,
+            done: false,
+            editable: false,
+            insert: false,
+            text: false,
+            nibble: false,
+            quitting: false,
+        };
+        viewer.fit();
+        viewer
+    }
+
+    /// Allows editing. The cursor can then also rest just past the last
+    /// byte, to append there.
+    pub fn editable(mut self, editable: bool) -> Self {
+        self.editable = editable;
+        self.fit();
+        self
+    }
+
+    /// Sets the line layout and theme. Colour is always on, since the
+    /// cursor and matches are drawn with it, and lines are narrowed to
+    /// fewer bytes when the terminal is too small for them.
+    pub fn dumper(mut self, dumper: HexDumper) -> Self {
+        self.dumper = dumper.color(ColorChoice::Always);
+        self.fit();
+        self
+    }
+
+    /// Moves the cursor to `offset`, or to the last byte if it lies past
+    /// the end.
+    pub fn goto(&mut self, offset: u64) {
+        self.cursor = offset.min(self.last());
+        self.scroll();
+    }
+
+    /// The bytes being viewed, with any edits.
+    pub fn buffer(&self) -> &Buffer {
+        &self.buffer
+    }
+
+    /// The offset of the byte under the cursor.
+    pub fn cursor(&self) -> u64 {
+        self.cursor
+    }
+
+    /// Whether the user has asked to quit.
+    pub fn is_done(&self) -> bool {
+        self.done
+    }
+
+    /// Shows the viewer in the terminal until the user quits.
+    ///
+    /// The terminal is switched to an alternate screen in raw mode and
+    /// restored afterwards, even if drawing fails.
+    pub fn run(mut self) -> io::Result<()> {
+        let mut out = io::stdout();
+        let _guard = RawTerminal::enter(&mut out)?;
+        let (width, height) = terminal::size()?;
+        self.resize(width, height);
+        while !self.done {
+            self.draw(&mut out)?;
+            match event::read()? {
+                Event::Key(key) if key.kind != KeyEventKind::Release => self.key(key),
+                Event::Resize(width, height) => self.resize(width, height),
+                _ => {}
+            }
+        }
+        Ok(())
+    }
+
+    /// Adapts the view to a terminal of `width` columns and `height` rows.
+    pub fn resize(&mut self, width: u16, height: u16) {
+        self.width = width;
+        self.height = height;
+        self.fit();
+    }
+
+    /// Handles one key press.
+    pub fn key(&mut self, key: KeyEvent) {
+        if key.modifiers.contains(KeyModifiers::CONTROL) && key.code == KeyCode::Char('c') {
+            self.done = true;
+            return;
+        }
+        if self.prompt.is_some() {
+            self.prompt_key(key.code);
+            return;
+        }
+        self.message = None;
+        let nibble = std::mem::take(&mut self.nibble);
+        let quitting = std::mem::take(&mut self.quitting);
+        if self.editable && self.edit_key(key, nibble) {
+            return;
+        }
+        let bpl = self.bpl();
+        let page = self.dump_rows() as u64 * bpl;
+        let line_start = self.cursor - self.cursor % bpl;
+        match key.code {
+            KeyCode::Char('q') | KeyCode::Esc => self.quit(quitting),
+            KeyCode::Left | KeyCode::Char('h') => self.goto(self.cursor.saturating_sub(1)),
+            KeyCode::Right | KeyCode::Char('l') => self.goto(self.cursor + 1),
+            KeyCode::Up | KeyCode::Char('k') if self.cursor >= bpl => self.goto(self.cursor - bpl),
+            KeyCode::Down | KeyCode::Char('j') if self.cursor + bpl <= self.last() => {
+                self.goto(self.cursor + bpl)
+            }
+            KeyCode::PageUp => {
+                self.top = self.top.saturating_sub(page);
+                self.goto(self.cursor.saturating_sub(page));
+            }
+            KeyCode::PageDown => {
+                let last_line = self.last() / bpl * bpl;
+                self.top = (self.top + page).min(last_line);
+                self.goto(self.cursor.saturating_add(page));
+            }
+            KeyCode::Home => self.goto(line_start),
+            KeyCode::End => self.goto(line_start + bpl - 1),
+            KeyCode::Char('g') => self.goto(0),
+            KeyCode::Char('G') => self.goto(u64::MAX),
+            KeyCode::Char(':') => self.start_prompt(PromptKind::Goto),
+            KeyCode::Char('/') => self.start_prompt(PromptKind::Search),
+            KeyCode::Char('n') => self.find_next(false),
+            KeyCode::Char('N') => self.find_next(true),
+            _ => {}
+        }
+    }
+
+    /// The rows of the screen as drawn, colour escape codes included.
+    pub fn screen(&self) -> Vec<String> {
+        let width = usize::from(self.width);
+        let bpl = self.bpl();
+        let hits = self.visible_matches();
+        let shown = self
+            .buffer
+            .slice(self.top..self.top + self.dump_rows() as u64 * bpl);
+        let mut rows = Vec::with_capacity(usize::from(self.height));
+        for (i, line) in shown.chunks(bpl as usize).enumerate() {
+            let start = self.top + i as u64 * bpl;
+            let mut row = String::new();
+            self.layout
+                .write_line_with(&mut row, start, line, |j| {
+                    let at = start + j as u64;
+                    if at == self.cursor {
+                        Some(CURSOR)
+                    } else {
+                        hits.iter().any(|hit| hit.contains(&at)).then_some(MATCH)
+                    }
+                })
+                .expect(STRING_WRITE);
+            rows.push(row);
+        }
+        rows.resize(self.dump_rows(), String::new());
+
+        let here = &self.buffer.slice(self.cursor..self.cursor + 8);
+        let mut bar = format!(" {}", self.name);
+        if self.buffer.is_modified() {
+            bar.push_str(" [+]");
+        }
+        if self.editable {
+            let mode = if self.insert { "INS" } else { "OVR" };
+            let column = if self.text { "text" } else { "hex" };
+            write!(bar, "  {mode} {column}").expect(STRING_WRITE);
+        }
+        write!(bar, "  0x{:08x} / 0x{:08x}", self.cursor, self.len()).expect(STRING_WRITE);
+        if !self.buffer.is_empty() {
+            let percent = ((self.cursor + 1) * 100 / self.len()).min(100);
+            write!(bar, " ({percent}%)  {}", values::byte(here)).expect(STRING_WRITE);
+        }
+        let bar = clip(&bar, width);
+        let pad = width - bar.chars().count();
+        rows.push(format!("{REVERSE}{bar}{:pad$}{RESET}", ""));
+        rows.push(clip(&values::row(here, false), width).to_owned());
+        rows.push(clip(&values::row(here, true), width).to_owned());
+        let bottom = match (&self.prompt, &self.message) {
+            (Some(prompt), _) => {
+                let label = match prompt.kind {
+                    PromptKind::Goto => "goto: ",
+                    PromptKind::Search => "search: ",
+                };
+                format!("{label}{}_", prompt.text)
+            }
+            (None, Some(message)) => message.clone(),
+            (None, None) if self.editable => EDIT_HINTS.to_owned(),
+            (None, None) => HINTS.to_owned(),
+        };
+        rows.push(clip(&bottom, width).to_owned());
+        rows.truncate(usize::from(self.height));
+        rows
+    }
+
+    fn draw<W: Write>(&self, out: &mut W) -> io::Result<()> {
+        for (i, row) in self.screen().iter().enumerate() {
+            let y = u16::try_from(i).unwrap_or(u16::MAX);
+            queue!(out, MoveTo(0, y))?;
+            out.write_all(row.as_bytes())?;
+            queue!(out, Clear(ClearType::UntilNewLine))?;
+        }
+        out.flush()
+    }
+
+    fn len(&self) -> u64 {
+        self.buffer.len()
+    }
+
+    /// The furthest the cursor can go: the last byte, or just past it when
+    /// editing.
+    fn last(&self) -> u64 {
+        if self.editable {
+            self.len()
+        } else {
+            self.len().saturating_sub(1)
+        }
+    }
+
+    fn bpl(&self) -> u64 {
+        self.layout.bytes_per_line as u64
+    }
+
+    fn dump_rows(&self) -> usize {
+        usize::from(self.height).saturating_sub(STATUS_ROWS).max(1)
+    }
+
+    /// Picks the widest line, in whole groups where possible, that fits the
+    /// terminal.
+    fn fit(&mut self) {
+        let width = usize::from(self.width);
+        let group = self.dumper.group_size;
+        let widest = self.last();
+        let fits = |n: usize| {
+            let layout = self.dumper.clone().bytes_per_line(n);
+            layout.line_width(widest, n) <= width
+        };
+        let bpl = (1..=self.dumper.bytes_per_line)
+            .rev()
+            .filter(|&n| group == 0 || n < group || n.is_multiple_of(group))
+            .find(|&n| fits(n))
+            .unwrap_or(1);
+        self.layout = self.dumper.clone().bytes_per_line(bpl);
+        self.top -= self.top % bpl as u64;
+        self.scroll();
+    }
+
+    /// Scrolls so the cursor is on screen.
+    fn scroll(&mut self) {
+        let bpl = self.bpl();
+        let line = self.cursor - self.cursor % bpl;
+        let span = (self.dump_rows() as u64 - 1) * bpl;
+        if line < self.top {
+            self.top = line;
+        } else if line > self.top + span {
+            self.top = line - span;
+        }
+    }
+
+    fn start_prompt(&mut self, kind: PromptKind) {
+        self.prompt = Some(Prompt {
+            kind,
+            text: String::new(),
+        });
+    }
+
+    fn prompt_key(&mut self, code: KeyCode) {
+        let Some(prompt) = &mut self.prompt else {
+            return;
+        };
+        match code {
+            KeyCode::Char(c) => prompt.text.push(c),
+            KeyCode::Backspace => {
+                prompt.text.pop();
+            }
+            KeyCode::Esc => self.prompt = None,
+            KeyCode::Enter => {
+                let Prompt { kind, text } = self.prompt.take().expect("prompt is open");
+                match kind {
+                    PromptKind::Goto => match parse_offset(&text, self.cursor) {
+                        Some(offset) => self.goto(offset),
+                        None => self.message = Some(format!("not an offset: {text}")),
+                    },
+                    PromptKind::Search => {
+                        self.pattern = parse_pattern(&text);
+                        match self.pattern {
+                            Some(_) => self.find_next(false),
+                            None => self.message = Some("empty search".to_owned()),
+                        }
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+
+    /// Moves to the next match of the search pattern after the cursor, or
+    /// the previous one before it, wrapping around the ends of the data.
+    fn find_next(&mut self, backwards: bool) {
+        let Some(pattern) = &self.pattern else {
+            self.message = Some("no search pattern".to_owned());
+            return;
+        };
+        let (buffer, at, len) = (&self.buffer, self.cursor, self.len());
+        let found = if backwards {
+            find_in(buffer, pattern, 0..at, true)
+                .map(|i| (i, false))
+                .or_else(|| find_in(buffer, pattern, 0..len, true).map(|i| (i, true)))
+        } else {
+            find_in(buffer, pattern, (at + 1).min(len)..len, false)
+                .map(|i| (i, false))
+                .or_else(|| find_in(buffer, pattern, 0..len, false).map(|i| (i, true)))
+        };
+        match found {
+            Some((offset, wrapped)) => {
+                if wrapped {
+                    self.message = Some("search wrapped".to_owned());
+                }
+                self.goto(offset);
+            }
+            None => self.message = Some("pattern not found".to_owned()),
+        }
+    }
+
+    /// The matches of the search pattern overlapping the screen.
+    fn visible_matches(&self) -> Vec<Range<u64>> {
+        let Some(pattern) = &self.pattern else {
+            return Vec::new();
+        };
+        let overlap = pattern.longest() as u64 - 1;
+        let from = self.top.saturating_sub(overlap);
+        let end = self.top + self.dump_rows() as u64 * self.bpl();
+        pattern
+            .find_iter(&self.buffer.slice(from..end + overlap))
+            .map(|found| from + found.start as u64..from + found.end as u64)
+            .collect()
+    }
+
+    /// Handles a key that edits, returning `false` for keys that do not.
+    fn edit_key(&mut self, key: KeyEvent, nibble: bool) -> bool {
+        let control = key.modifiers.contains(KeyModifiers::CONTROL);
+        match key.code {
+            KeyCode::Char('s') if control => self.save(),
+            KeyCode::Char('z') if control => self.undo(),
+            KeyCode::Char('y' | 'r') if control => self.redo(),
+            _ if control => return false,
+            KeyCode::Tab => self.text = !self.text,
+            KeyCode::Insert => self.insert = !self.insert,
+            KeyCode::Delete => self.delete(self.cursor),
+            KeyCode::Backspace if self.cursor > 0 => self.delete(self.cursor - 1),
+            KeyCode::Esc if self.text => self.text = false,
+            KeyCode::Char(c) if self.text => self.type_bytes(c.encode_utf8(&mut [0; 4]).as_bytes()),
+            KeyCode::Char(c) if c.is_ascii_hexdigit() => {
+                let digit = c.to_digit(16).expect("hex digit") as u8;
+                self.type_nibble(digit, nibble);
+            }
+            KeyCode::Char('i') => self.insert = !self.insert,
+            KeyCode::Char('x') => self.delete(self.cursor),
+            KeyCode::Char('u') => self.undo(),
+            KeyCode::Char('U') => self.redo(),
+            KeyCode::Char('w') => self.save(),
+            _ => return false,
+        }
+        true
+    }
+
+    /// Types a hex digit: the high nibble of a new or overwritten byte,
+    /// then, right after, its low nibble.
+    fn type_nibble(&mut self, digit: u8, low: bool) {
+        let at = self.cursor;
+        let old = self.buffer.get(at).unwrap_or(0);
+        if low {
+            self.buffer.amend(at, &[old & 0xf0 | digit]);
+            self.edited(at + 1);
+        } else {
+            if self.insert {
+                self.buffer.insert(at, &[digit << 4]);
+            } else {
+                self.buffer.replace(at, &[digit << 4 | old & 0x0f]);
+            }
+            self.edited(at);
+            self.nibble = true;
+        }
+    }
+
+    fn type_bytes(&mut self, bytes: &[u8]) {
+        let at = self.cursor;
+        if self.insert {
+            self.buffer.insert(at, bytes);
+        } else {
+            self.buffer.replace(at, bytes);
+        }
+        self.edited(at + bytes.len() as u64);
+    }
+
+    fn delete(&mut self, at: u64) {
+        self.buffer.delete(at..at + 1);
+        self.edited(at);
+    }
+
+    fn undo(&mut self) {
+        match self.buffer.undo() {
+            Some(at) => self.edited(at),
+            None => self.message = Some("nothing to undo".to_owned()),
+        }
+    }
+
+    fn redo(&mut self) {
+        match self.buffer.redo() {
+            Some(at) => self.edited(at),
+            None => self.message = Some("nothing to redo".to_owned()),
+        }
+    }
+
+    /// Refits the lines to the new length and moves the cursor to `at`.
+    fn edited(&mut self, at: u64) {
+        self.fit();
+        self.goto(at);
+    }
+
+    fn save(&mut self) {
+        if !self.buffer.is_modified() {
+            self.message = Some("no changes to save".to_owned());
+            return;
+        }
+        self.message = Some(match self.buffer.save() {
+            Ok(()) => format!("saved {} bytes", self.len()),
+            Err(SaveError::ChangedOnDisk) => {
+                "not saved: the file changed on disk since it was opened".to_owned()
+            }
+            Err(err) => format!("not saved: {err}"),
+        });
+    }
+
+    /// Quits, unless there are unsaved changes the user has not just been
+    /// warned about.
+    fn quit(&mut self, warned: bool) {
+        if self.buffer.is_modified() && !warned {
+            self.message =
+                Some("unsaved changes: w saves, q again quits without saving".to_owned());
+            self.quitting = true;
+        } else {
+            self.done = true;
+        }
+    }
+}
+
+/// Finds the first match of `pattern` starting in `range`, or the last one
+/// if `backwards`, reading the buffer a block at a time.
+fn find_in(buffer: &Buffer, pattern: &Pattern, range: Range<u64>, backwards: bool) -> Option<u64> {
+    let overlap = pattern.longest() as u64 - 1;
+    let mut rest = range;
+    while !rest.is_empty() {
+        let block = if backwards {
+            rest.end.saturating_sub(SEARCH_BLOCK).max(rest.start)..rest.end
+        } else {
+            rest.start..rest.end.min(rest.start + SEARCH_BLOCK)
+        };
+        let bytes = buffer.slice(block.start..block.end + overlap);
+        let found = if backwards {
+            pattern.find_iter(&bytes).last()
+        } else {
+            pattern.find(&bytes)
+        };
+        if let Some(found) = found {
+            return Some(block.start + found.start as u64);
+        }
+        if backwards {
+            rest.end = block.start;
+        } else {
+            rest.start = block.end;
+        }
+    }
+    None
+}
+
+/// Parses a goto target: an offset in decimal or with a `0x`, `0o` or `0b`
+/// prefix, or a distance from the cursor with a leading `+` or `-`.
+fn parse_offset(text: &str, cursor: u64) -> Option<u64> {
+    let text = text.trim();
+    let (relative, digits) = match text.as_bytes().first() {
+        Some(b'+') => (Some(true), &text[1..]),
+        Some(b'-') => (Some(false), &text[1..]),
+        _ => (None, text),
+    };
+    let (digits, radix) = match digits.get(..2) {
+        Some("0x" | "0X") => (&digits[2..], 16),
+        Some("0o" | "0O") => (&digits[2..], 8),
+        Some("0b" | "0B") => (&digits[2..], 2),
+        _ => (digits, 10),
+    };
+    if digits.starts_with(['+', '-']) {
+        return None;
+    }
+    let n = u64::from_str_radix(digits, radix).ok()?;
+    Some(match relative {
+        Some(true) => cursor.saturating_add(n),
+        Some(false) => cursor.saturating_sub(n),
+        None => n,
+    })
+}
+
+/// Parses a search: hex bytes, with `?` for any nibble, if the text is an
+/// even number of hex digits and wildcards, spaces aside, and text
+/// otherwise. Double quotes force text.
+fn parse_pattern(text: &str) -> Option<Pattern> {
+    if let Some(quoted) = text.strip_prefix('"') {
+        let quoted = quoted.strip_suffix('"').unwrap_or(quoted);
+        return (!quoted.is_empty()).then(|| Pattern::text(quoted));
+    }
+    if text.trim().is_empty() {
+        return None;
+    }
+    Some(Pattern::hex(text).unwrap_or_else(|_| Pattern::text(text)))
+}
+
+/// Cuts `text` to at most `width` characters.
+fn clip(text: &str, width: usize) -> &str {
+    match text.char_indices().nth(width) {
+        Some((i, _)) => &text[..i],
+        None => text,
+    }
+}
+
+/// Puts the terminal in raw mode on an alternate screen, and restores it
+/// when dropped, including when unwinding from a panic.
+struct RawTerminal;
+
+impl RawTerminal {
+    fn enter<W: Write>(out: &mut W) -> io::Result<RawTerminal> {
+        terminal::enable_raw_mode()?;
+        let guard = RawTerminal;
+        execute!(out, EnterAlternateScreen, Hide, DisableLineWrap)?;
+        Ok(guard)
+    }
+}
+
+impl Drop for RawTerminal {
+    fn drop(&mut self) {
+        let _ = execute!(io::stdout(), EnableLineWrap, Show, LeaveAlternateScreen);
+        let _ = terminal::disable_raw_mode();
+    }
+}
@@ -0,0 +1,102 @@
+//! Readings of the bytes under the cursor, for the status rows.
+
+use std::fmt::{self, Write};
+
+use crate::STRING_WRITE;
+
+/// Describes the byte at the cursor: `u8 200 (-56)  0b11001000  'x'`.
+pub(crate) fn byte(bytes: &[u8]) -> String {
+    let Some(&b) = bytes.first() else {
+        return "u8 -".to_owned();
+    };
+    let mut out = String::new();
+    write!(out, "u8 {b}").expect(STRING_WRITE);
+    if b >= 0x80 {
+        write!(out, " ({})", b as i8).expect(STRING_WRITE);
+    }
+    write!(out, "  0b{b:08b}").expect(STRING_WRITE);
+    if b.is_ascii_graphic() || b == b' ' {
+        write!(out, "  '{}'", b as char).expect(STRING_WRITE);
+    }
+    out
+}
+
+/// Describes the integers, floats and Unix time starting at the cursor, in
+/// one byte order. Widths running past the end of the data show `-`.
+pub(crate) fn row(bytes: &[u8], big_endian: bool) -> String {
+    let int = |n: usize| {
+        let bytes = bytes.get(..n)?;
+        let fold = |acc: u64, &b: &u8| acc << 8 | u64::from(b);
+        Some(if big_endian {
+            bytes.iter().fold(0, fold)
+        } else {
+            bytes.iter().rev().fold(0, fold)
+        })
+    };
+    let mut out = String::from(if big_endian { "BE" } else { "LE" });
+    for bits in [16, 32, 64] {
+        write!(out, "  u{bits} ").expect(STRING_WRITE);
+        match int(bits / 8) {
+            Some(v) => {
+                write!(out, "{v}").expect(STRING_WRITE);
+                if v >> (bits - 1) & 1 == 1 {
+                    let signed = (v << (64 - bits)) as i64 >> (64 - bits);
+                    write!(out, " ({signed})").expect(STRING_WRITE);
+                }
+            }
+            None => out.push('-'),
+        }
+    }
+    out.push_str("  f32 ");
+    match int(4) {
+        Some(v) => write_float(&mut out, f32::from_bits(v as u32)).expect(STRING_WRITE),
+        None => out.push('-'),
+    }
+    out.push_str("  f64 ");
+    match int(8) {
+        Some(v) => write_float(&mut out, f64::from_bits(v)).expect(STRING_WRITE),
+        None => out.push('-'),
+    }
+    out.push_str("  time ");
+    match int(4) {
+        Some(v) => write_unix_time(&mut out, v).expect(STRING_WRITE),
+        None => out.push('-'),
+    }
+    out
+}
+
+/// Writes a float plainly when that is short, in exponent form otherwise.
+fn write_float<T>(out: &mut String, x: T) -> fmt::Result
+where
+    T: Copy + Into<f64> + fmt::Display + fmt::LowerExp,
+{
+    let magnitude = x.into().abs();
+    if magnitude == 0.0 || !magnitude.is_finite() || (1e-4..1e9).contains(&magnitude) {
+        write!(out, "{x}")
+    } else {
+        write!(out, "{x:e}")
+    }
+}
+
+/// Writes seconds since the Unix epoch as a UTC date and time.
+fn write_unix_time(out: &mut String, secs: u64) -> fmt::Result {
+    let days = secs / 86_400;
+    let time = secs % 86_400;
+    // Days to a civil date, after Howard Hinnant's `civil_from_days`.
+    let z = days + 719_468;
+    let era = z / 146_097;
+    let doe = z % 146_097;
+    let yoe = (doe - doe / 1460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + u64::from(month <= 2);
+    write!(
+        out,
+        "{year:04}-{month:02}-{day:02} {:02}:{:02}:{:02}",
+        time / 3600,
+        time / 60 % 60,
+        time % 60
+    )
+}
@@ -0,0 +1,425 @@
+//! Turning hex dumps back into bytes, like `xxd -r`.
+//!
+//! [`Undumper`] reads the layouts this crate writes as well as text from
+//! `xxd`, `hexdump -C` and `od -t x1z`. Each line is placed at the offset it
+//! names, so lines may come in any order and gaps are filled with zeros. A
+//! `*` line, as printed when repeated lines are squeezed, repeats the line
+//! before it up to the next offset, or as many times as a
+//! `* (N identical lines)` annotation says; a bare `*` with no offset after
+//! it is an error, since the run's length is unknown. The output is held in
+//! memory, so offsets past [`max_len`](Undumper::max_len) are refused rather
+//! than filled up to.
+//!
+//! ```
+//! use hexdump::undump::undump;
+//!
+//! let text = "\
+//! 00000000  00 00 00 00 00 00 00 00  00 00 00 00 00 00 00 00  |................|
+//! *
+//! 00000030  68 69                                             |hi|
+//! 00000032
+//! ";
+//! let bytes = undump(text)?;
+//! assert_eq!(bytes.len(), 0x32);
+//! assert_eq!(&bytes[0x30..], b"hi");
+//! # Ok::<(), hexdump::undump::UndumpError>(())
+//! ```
+
+use std::error::Error;
+use std::fmt;
+use std::ops::Range;
+
+use crate::OffsetBase;
+
+/// Error from parsing a hex dump. Lines and columns count from 1.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum UndumpError {
+    /// A line does not start with an offset.
+    InvalidOffset { line: usize, column: usize },
+    /// A character in the hex column is not a hex digit.
+    InvalidHex { line: usize, column: usize },
+    /// A hex digit is missing its other half.
+    UnpairedDigit { line: usize, column: usize },
+    /// An offset lies before the configured [`origin`](Undumper::origin).
+    OffsetBeforeOrigin { line: usize, column: usize },
+    /// An offset, or the end of its line's bytes, lies past the
+    /// [`max_len`](Undumper::max_len) or is too large to address in memory.
+    OffsetTooLarge { line: usize, column: usize },
+    /// A `*` line without a count is not followed by an offset, so the
+    /// length of the squeezed run is unknown.
+    UnendedRun { line: usize, column: usize },
+}
+
+impl UndumpError {
+    /// The line the error was found on.
+    pub fn line(&self) -> usize {
+        self.position().0
+    }
+
+    /// The column the error was found at.
+    pub fn column(&self) -> usize {
+        self.position().1
+    }
+
+    fn position(&self) -> (usize, usize) {
+        match *self {
+            UndumpError::InvalidOffset { line, column }
+            | UndumpError::InvalidHex { line, column }
+            | UndumpError::UnpairedDigit { line, column }
+            | UndumpError::OffsetBeforeOrigin { line, column }
+            | UndumpError::OffsetTooLarge { line, column }
+            | UndumpError::UnendedRun { line, column } => (line, column),
+        }
+    }
+}
+
+impl fmt::Display for UndumpError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let what = match self {
+            UndumpError::InvalidOffset { .. } => "invalid offset",
+            UndumpError::InvalidHex { .. } => "invalid hex digit",
+            UndumpError::UnpairedDigit { .. } => "unpaired hex digit",
+            UndumpError::OffsetBeforeOrigin { .. } => "offset before origin",
+            UndumpError::OffsetTooLarge { .. } => "offset too large",
+            UndumpError::UnendedRun { .. } => "squeezed run without an offset after it",
+        };
+        write!(f, "line {}, column {}: {what}", self.line(), self.column())
+    }
+}
+
+impl Error for UndumpError {}
+
+/// Parses `text` with the default [`Undumper`].
+pub fn undump(text: &str) -> Result<Vec<u8>, UndumpError> {
+    Undumper::new().parse(text)
+}
+
+/// The default [`max_len`](Undumper::max_len), 1 GiB.
+pub const DEFAULT_MAX_LEN: usize = 1 << 30;
+
+/// Configurable hex dump parser.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Undumper {
+    offset_base: OffsetBase,
+    plain: bool,
+    origin: u64,
+    max_len: usize,
+}
+
+impl Default for Undumper {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Undumper {
+    /// Creates a parser for dumps with hex offsets.
+    pub fn new() -> Self {
+        Undumper {
+            offset_base: OffsetBase::default(),
+            plain: false,
+            origin: 0,
+            max_len: DEFAULT_MAX_LEN,
+        }
+    }
+
+    /// Sets the base offsets are written in.
+    pub fn offset_base(mut self, base: OffsetBase) -> Self {
+        self.offset_base = base;
+        self
+    }
+
+    /// Parses plain hex, as written by `xxd -p`: no offsets or ASCII, every
+    /// hex digit is data and whitespace is ignored.
+    pub fn plain(mut self, plain: bool) -> Self {
+        self.plain = plain;
+        self
+    }
+
+    /// Sets the offset of the first output byte. Use it to rebuild a dump
+    /// that starts at a non-zero offset without zero filling up to it.
+    pub fn origin(mut self, origin: u64) -> Self {
+        self.origin = origin;
+        self
+    }
+
+    /// Sets the most bytes the output may hold, [`DEFAULT_MAX_LEN`] by
+    /// default. Gaps and squeezed runs are filled with bytes, so a single
+    /// line at a far offset would otherwise need that much memory; lines
+    /// ending past the limit give [`UndumpError::OffsetTooLarge`]. Dumps
+    /// that start at a high offset usually want an
+    /// [`origin`](Undumper::origin) instead.
+    pub fn max_len(mut self, max_len: usize) -> Self {
+        self.max_len = max_len;
+        self
+    }
+
+    /// Parses `text` into the bytes it describes.
+    pub fn parse(&self, text: &str) -> Result<Vec<u8>, UndumpError> {
+        let mut state = State::default();
+        for (i, line) in text.lines().enumerate() {
+            if self.plain {
+                state.plain_line(i + 1, line)?;
+            } else {
+                self.dump_line(&mut state, i + 1, line)?;
+            }
+        }
+        state.finish()
+    }
+
+    fn dump_line(&self, state: &mut State, lineno: usize, line: &str) -> Result<(), UndumpError> {
+        let text = line.trim_start();
+        if text.is_empty() {
+            return Ok(());
+        }
+        let start = line.len() - text.len();
+        let error = |column| (lineno, column_of(line, column));
+        if text == "*" || text.starts_with("* ") {
+            match identical_lines(text) {
+                Some(count) => state.repeat(count, self.max_len).ok_or_else(|| {
+                    let (line, column) = error(start);
+                    UndumpError::OffsetTooLarge { line, column }
+                })?,
+                None => state.squeezed = Some(error(start)),
+            }
+            return Ok(());
+        }
+        let token_end = text.find(char::is_whitespace).unwrap_or(text.len());
+        let token = &text[..token_end];
+        let digits = token.strip_suffix(':').unwrap_or(token);
+        let radix = match self.offset_base {
+            OffsetBase::Hex | OffsetBase::UpperHex => 16,
+            OffsetBase::Decimal => 10,
+            OffsetBase::Octal => 8,
+        };
+        // `from_str_radix` takes a leading `+`, which no dump writes.
+        let offset = Some(digits)
+            .filter(|digits| !digits.starts_with('+'))
+            .and_then(|digits| u64::from_str_radix(digits, radix).ok())
+            .ok_or_else(|| {
+                let (line, column) = error(start);
+                UndumpError::InvalidOffset { line, column }
+            })?;
+        let offset = offset.checked_sub(self.origin).ok_or_else(|| {
+            let (line, column) = error(start);
+            UndumpError::OffsetBeforeOrigin { line, column }
+        })?;
+
+        let mut bytes = Vec::new();
+        let body = start + token_end;
+        parse_hex_column(line, body, state.width, &mut bytes).map_err(|e| e.at(lineno, line))?;
+        state.width = state.width.max(bytes.len());
+        let range = usize::try_from(offset)
+            .ok()
+            .and_then(|offset| Some(offset..offset.checked_add(bytes.len())?))
+            .filter(|range| range.end <= self.max_len)
+            .ok_or_else(|| {
+                let (line, column) = error(start);
+                UndumpError::OffsetTooLarge { line, column }
+            })?;
+        state.place(range, &bytes);
+        Ok(())
+    }
+}
+
+/// Bytes rebuilt so far and the bookkeeping needed to place the next line.
+#[derive(Default)]
+struct State {
+    out: Vec<u8>,
+    /// End of the output, which can lie past `out.len()` after a line with
+    /// only an offset.
+    len: usize,
+    /// The previous line's bytes, repeated to fill a squeezed run.
+    prev: Vec<u8>,
+    /// Line and column of a `*` line without a count, until the next
+    /// offset closes its run.
+    squeezed: Option<(usize, usize)>,
+    /// The most bytes a line has held so far.
+    width: usize,
+    /// The high half of a byte split across lines in plain mode.
+    nibble: Option<(u8, usize, usize)>,
+}
+
+impl State {
+    /// Writes a line's `bytes` at `range`, which the caller has checked
+    /// against the output limit, after filling any squeezed run before it.
+    fn place(&mut self, range: Range<usize>, bytes: &[u8]) {
+        if self.squeezed.take().is_some() {
+            self.fill(range.start);
+        }
+        self.len = self.len.max(range.end);
+        self.write(range, bytes);
+        if !bytes.is_empty() {
+            self.prev.clear();
+            self.prev.extend_from_slice(bytes);
+        }
+    }
+
+    /// Repeats the previous line `count` times at the end of the output,
+    /// for a `*` line stating its count. Returns `None` if the output would
+    /// then end past `max_len`.
+    fn repeat(&mut self, count: u64, max_len: usize) -> Option<()> {
+        self.squeezed = None;
+        let end = (self.prev.len() as u64)
+            .checked_mul(count)?
+            .checked_add(self.len as u64)?;
+        let end = usize::try_from(end).ok().filter(|&end| end <= max_len)?;
+        self.fill(end);
+        self.len = self.len.max(end);
+        Some(())
+    }
+
+    /// Repeats the previous line from the end of the output up to `end`.
+    fn fill(&mut self, end: usize) {
+        if self.prev.is_empty() {
+            return;
+        }
+        let prev = std::mem::take(&mut self.prev);
+        let mut at = self.len;
+        while at < end {
+            let n = (end - at).min(prev.len());
+            self.write(at..at + n, &prev[..n]);
+            at += n;
+        }
+        self.prev = prev;
+    }
+
+    fn write(&mut self, range: Range<usize>, bytes: &[u8]) {
+        if self.out.len() < range.end {
+            self.out.resize(range.end, 0);
+        }
+        self.out[range].copy_from_slice(bytes);
+    }
+
+    fn plain_line(&mut self, lineno: usize, line: &str) -> Result<(), UndumpError> {
+        for (i, c) in line.char_indices() {
+            if c.is_whitespace() {
+                continue;
+            }
+            let digit = c.to_digit(16).ok_or(UndumpError::InvalidHex {
+                line: lineno,
+                column: column_of(line, i),
+            })? as u8;
+            match self.nibble.take() {
+                Some((high, _, _)) => self.out.push(high << 4 | digit),
+                None => self.nibble = Some((digit, lineno, column_of(line, i))),
+            }
+        }
+        self.len = self.out.len();
+        Ok(())
+    }
+
+    fn finish(mut self) -> Result<Vec<u8>, UndumpError> {
+        if let Some((_, line, column)) = self.nibble {
+            return Err(UndumpError::UnpairedDigit { line, column });
+        }
+        if let (Some((line, column)), false) = (self.squeezed, self.prev.is_empty()) {
+            return Err(UndumpError::UnendedRun { line, column });
+        }
+        self.out.resize(self.len, 0);
+        Ok(self.out)
+    }
+}
+
+/// A hex column error, positioned by byte index within its line.
+enum HexError {
+    Invalid(usize),
+    Unpaired(usize),
+}
+
+impl HexError {
+    fn at(self, line: usize, text: &str) -> UndumpError {
+        match self {
+            HexError::Invalid(i) => UndumpError::InvalidHex {
+                line,
+                column: column_of(text, i),
+            },
+            HexError::Unpaired(i) => UndumpError::UnpairedDigit {
+                line,
+                column: column_of(text, i),
+            },
+        }
+    }
+}
+
+/// Parses the hex column of `line`, starting at byte index `pos`, up to the
+/// ASCII gutter or the end of the line.
+///
+/// The gutter is recognised by its `|` or `>` delimiter or, when it has
+/// none as in `xxd` output, by [`is_gutter`]. `width` is the most bytes an
+/// earlier line held.
+fn parse_hex_column(
+    line: &str,
+    mut pos: usize,
+    width: usize,
+    bytes: &mut Vec<u8>,
+) -> Result<(), HexError> {
+    loop {
+        let rest = &line[pos..];
+        let token = rest.trim_start();
+        if token.is_empty() {
+            return Ok(());
+        }
+        let gap = rest.len() - token.len();
+        pos += gap;
+        if gap >= 2 && !bytes.is_empty() && is_gutter(token, gap, bytes.len(), width) {
+            return Ok(());
+        }
+        if token.starts_with(['|', '>']) {
+            return Ok(());
+        }
+        let end = token.find(char::is_whitespace).unwrap_or(token.len());
+        let word = &token[..end];
+        if let Some(bad) = word.find(|c: char| !c.is_ascii_hexdigit()) {
+            return Err(HexError::Invalid(pos + bad));
+        }
+        if !word.len().is_multiple_of(2) {
+            return Err(HexError::Unpaired(pos + word.len() - 1));
+        }
+        for pair in word.as_bytes().chunks(2) {
+            let pair = std::str::from_utf8(pair).expect("hex digits are ASCII");
+            bytes.push(u8::from_str_radix(pair, 16).expect("validated hex digits"));
+        }
+        pos += end;
+    }
+}
+
+/// Whether `rest`, found after `gap` spaces on a line that has produced
+/// `count` bytes so far, is an undelimited ASCII gutter. `width` is the
+/// most bytes an earlier line held, or zero.
+///
+/// A gutter holds one character per byte, fewer if its trailing spaces
+/// were trimmed. Its leading spaces run into the gap, of which `xxd` puts
+/// two between the columns, so a gutter of `"  ab"` shows as `"ab"`.
+fn is_gutter(rest: &str, gap: usize, count: usize, width: usize) -> bool {
+    let len = rest.chars().count();
+    if len > count {
+        return false;
+    }
+    let all_hex = rest
+        .split_whitespace()
+        .all(|w| w.len().is_multiple_of(2) && w.bytes().all(|b| b.is_ascii_hexdigit()));
+    // More hex digits or a gutter that happens to look like hex: it is the
+    // gutter if the spaces missing from it fit in the gap, or if the line
+    // already holds as many bytes as the widest line before it.
+    !all_hex || count - len <= gap - 2 || (width > 0 && count >= width)
+}
+
+/// The count in a `* (N identical lines)` line, as written with
+/// [`squeeze_annotation`](crate::HexDumper::squeeze_annotation).
+fn identical_lines(text: &str) -> Option<u64> {
+    let inner = text.strip_prefix("* (")?.strip_suffix(')')?;
+    let count = inner
+        .strip_suffix(" identical lines")
+        .or_else(|| inner.strip_suffix(" identical line"))?;
+    if !count.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+    count.parse().ok()
+}
+
+/// Converts a byte index in `line` to a 1-based column.
+fn column_of(line: &str, index: usize) -> usize {
+    line[..index].chars().count() + 1
+}
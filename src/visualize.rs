@@ -0,0 +1,332 @@
+//! Pictures of a buffer, one pixel per byte, written as PNG.
+//!
+//! A [`Visualizer`] colours each byte by its [`ByteClass`], by the entropy
+//! of the bytes around it or as a grey level of its value, and lays the
+//! pixels out either in rows or along a Hilbert curve. The curve keeps
+//! bytes that are close in the file close in the picture, as `binvis` and
+//! `cantordust` do, so headers, code, text, tables and compressed data show
+//! up as patches instead of smeared stripes:
+//!
+//! ```
+//! use hexdump::visualize::{Colors, Layout, Visualizer};
+//!
+//! let data: Vec<u8> = (0..=255).collect();
+//! let bitmap = Visualizer::new()
+//!     .colors(Colors::Greyscale)
+//!     .layout(Layout::Hilbert)
+//!     .render(&data);
+//! assert_eq!((bitmap.width(), bitmap.height()), (16, 16));
+//! // The curve starts in the top left corner and ends in the top right.
+//! assert_eq!(bitmap.pixel(0, 0), Some([0, 0, 0, 255]));
+//! assert_eq!(bitmap.pixel(15, 0), Some([255, 255, 255, 255]));
+//!
+//! let mut png = Vec::new();
+//! bitmap.write_png(&mut png)?;
+//! assert!(png.starts_with(b"\x89PNG"));
+//! # Ok::<(), std::io::Error>(())
+//! ```
+
+use std::io::{self, Write};
+
+use crate::color::{ByteClass, Theme};
+
+/// How bytes are coloured.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[non_exhaustive]
+pub enum Colors {
+    /// The foreground colour of the byte's class in the
+    /// [`theme`](Visualizer::theme), as a coloured dump shows it.
+    #[default]
+    ByteClass,
+    /// The Shannon entropy of the bytes around each byte, from black for
+    /// runs of one value through blue to pink for random or compressed
+    /// data, on the colour scale `binvis` uses.
+    Entropy,
+    /// Black for `0x00` to white for `0xff`.
+    Greyscale,
+}
+
+/// Where each byte's pixel goes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[non_exhaustive]
+pub enum Layout {
+    /// Left to right in rows of [`width`](Visualizer::width) pixels, top
+    /// to bottom.
+    #[default]
+    Linear,
+    /// Along a Hilbert curve filling the smallest square whose side is a
+    /// power of two. The curve starts in the top left corner and ends in
+    /// the top right.
+    Hilbert,
+}
+
+/// Configurable picture renderer.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Visualizer {
+    colors: Colors,
+    layout: Layout,
+    width: u32,
+    bytes_per_pixel: usize,
+    entropy_window: usize,
+    theme: Theme,
+}
+
+impl Default for Visualizer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Visualizer {
+    /// Creates a renderer colouring bytes by class with the default theme,
+    /// in rows of 256 pixels, a pixel per byte.
+    pub fn new() -> Self {
+        Visualizer {
+            colors: Colors::ByteClass,
+            layout: Layout::Linear,
+            width: 256,
+            bytes_per_pixel: 1,
+            entropy_window: 32,
+            theme: Theme::DEFAULT,
+        }
+    }
+
+    /// Sets how bytes are coloured.
+    pub fn colors(mut self, colors: Colors) -> Self {
+        self.colors = colors;
+        self
+    }
+
+    /// Sets where the pixels go.
+    pub fn layout(mut self, layout: Layout) -> Self {
+        self.layout = layout;
+        self
+    }
+
+    /// Sets the width in pixels of the [`Linear`](Layout::Linear) layout.
+    /// Zero counts as one.
+    pub fn width(mut self, width: u32) -> Self {
+        self.width = width.max(1);
+        self
+    }
+
+    /// Sets the number of bytes each pixel stands for, to keep pictures of
+    /// large inputs small. A pixel's colour is the average of its bytes'.
+    /// Zero counts as one.
+    pub fn bytes_per_pixel(mut self, n: usize) -> Self {
+        self.bytes_per_pixel = n.max(1);
+        self
+    }
+
+    /// Sets the number of bytes whose entropy colours each byte with
+    /// [`Colors::Entropy`], 32 by default. The window is centred on the
+    /// byte where the input allows. Zero counts as one.
+    pub fn entropy_window(mut self, n: usize) -> Self {
+        self.entropy_window = n.max(1);
+        self
+    }
+
+    /// Sets the theme for [`Colors::ByteClass`]. Classes without a
+    /// foreground colour are drawn light grey.
+    pub fn theme(mut self, theme: Theme) -> Self {
+        self.theme = theme;
+        self
+    }
+
+    /// Draws `data`. Pixels past the end of the input are transparent, and
+    /// empty input gives a single transparent pixel.
+    pub fn render(&self, data: &[u8]) -> Bitmap {
+        let colors = self.byte_colors(data);
+        let count = data.len().div_ceil(self.bytes_per_pixel);
+        let (width, height) = match self.layout {
+            Layout::Linear => {
+                let rows = count.div_ceil(self.width as usize).max(1);
+                (self.width, u32::try_from(rows).expect("picture too tall"))
+            }
+            Layout::Hilbert => {
+                let mut side = 1u32;
+                while (side as usize).pow(2) < count {
+                    side *= 2;
+                }
+                (side, side)
+            }
+        };
+        let mut bitmap = Bitmap {
+            width,
+            height,
+            pixels: vec![0; width as usize * height as usize * 4],
+        };
+        for (i, block) in colors.chunks(self.bytes_per_pixel).enumerate() {
+            let (x, y) = match self.layout {
+                Layout::Linear => (i as u32 % width, (i / width as usize) as u32),
+                Layout::Hilbert => hilbert(width, i as u64),
+            };
+            let mut sum = [0u32; 3];
+            for color in block {
+                for (total, c) in sum.iter_mut().zip(color) {
+                    *total += u32::from(*c);
+                }
+            }
+            let n = block.len() as u32;
+            let at = (y as usize * width as usize + x as usize) * 4;
+            for (to, total) in bitmap.pixels[at..at + 3].iter_mut().zip(sum) {
+                *to = ((total + n / 2) / n) as u8;
+            }
+            bitmap.pixels[at + 3] = 255;
+        }
+        bitmap
+    }
+
+    /// Draws `data` and writes it to `writer` as a PNG file.
+    pub fn write_png<W: Write>(&self, data: &[u8], writer: W) -> io::Result<()> {
+        self.render(data).write_png(writer)
+    }
+
+    /// The colour of each byte.
+    fn byte_colors(&self, data: &[u8]) -> Vec<[u8; 3]> {
+        match self.colors {
+            Colors::ByteClass => {
+                let mut table = [[0; 3]; 256];
+                for (b, color) in table.iter_mut().enumerate() {
+                    let style = self.theme.style(ByteClass::of(b as u8));
+                    let (r, g, b) = style.fg.map_or((229, 229, 229), |fg| fg.to_rgb());
+                    *color = [r, g, b];
+                }
+                data.iter().map(|&b| table[usize::from(b)]).collect()
+            }
+            Colors::Greyscale => data.iter().map(|&b| [b, b, b]).collect(),
+            Colors::Entropy => entropy_colors(data, self.entropy_window),
+        }
+    }
+}
+
+/// An RGBA picture.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Bitmap {
+    width: u32,
+    height: u32,
+    pixels: Vec<u8>,
+}
+
+impl Bitmap {
+    pub fn width(&self) -> u32 {
+        self.width
+    }
+
+    pub fn height(&self) -> u32 {
+        self.height
+    }
+
+    /// The red, green, blue and alpha values of the pixel at column `x` and
+    /// row `y`, from the top left.
+    pub fn pixel(&self, x: u32, y: u32) -> Option<[u8; 4]> {
+        if x >= self.width || y >= self.height {
+            return None;
+        }
+        let at = (y as usize * self.width as usize + x as usize) * 4;
+        self.pixels[at..at + 4].try_into().ok()
+    }
+
+    /// The pixels row by row, four bytes each.
+    pub fn as_rgba(&self) -> &[u8] {
+        &self.pixels
+    }
+
+    /// Writes the picture to `writer` as an 8-bit RGBA PNG file.
+    pub fn write_png<W: Write>(&self, writer: W) -> io::Result<()> {
+        let mut encoder = png::Encoder::new(writer, self.width, self.height);
+        encoder.set_color(png::ColorType::Rgba);
+        encoder.set_depth(png::BitDepth::Eight);
+        let mut writer = encoder.write_header()?;
+        writer.write_image_data(&self.pixels)?;
+        writer.finish()?;
+        Ok(())
+    }
+}
+
+/// The point at distance `d` along a Hilbert curve filling a square of
+/// `side` pixels, a power of two.
+fn hilbert(side: u32, d: u64) -> (u32, u32) {
+    let (mut x, mut y) = (0u32, 0u32);
+    let mut t = d;
+    let mut s = 1u32;
+    while s < side {
+        let rx = (1 & (t / 2)) as u32;
+        let ry = (1 & (t ^ u64::from(rx))) as u32;
+        if ry == 0 {
+            if rx == 1 {
+                x = s - 1 - x;
+                y = s - 1 - y;
+            }
+            std::mem::swap(&mut x, &mut y);
+        }
+        x += s * rx;
+        y += s * ry;
+        t /= 4;
+        s *= 2;
+    }
+    (x, y)
+}
+
+/// The colour of each byte of `data` for the entropy of the `window` bytes
+/// around it, from 0 to 1 of the most the window can hold.
+fn entropy_colors(data: &[u8], window: usize) -> Vec<[u8; 3]> {
+    let window = window.min(data.len());
+    if window == 0 {
+        return Vec::new();
+    }
+    // Entropy is log2(n) - sum(c * log2(c)) / n over the counts c of each
+    // value; the sum is kept up to date as the window slides.
+    let c_log_c: Vec<f64> = (0..=window)
+        .map(|c| {
+            if c == 0 {
+                0.0
+            } else {
+                c as f64 * (c as f64).log2()
+            }
+        })
+        .collect();
+    let n = window as f64;
+    let max = n.log2().min(8.0);
+    let mut counts = [0usize; 256];
+    let mut sum = 0.0;
+    let add = |counts: &mut [usize; 256], b: u8, sum: &mut f64, up: bool| {
+        let c = &mut counts[usize::from(b)];
+        *sum -= c_log_c[*c];
+        if up {
+            *c += 1;
+        } else {
+            *c -= 1;
+        }
+        *sum += c_log_c[*c];
+    };
+    for &b in &data[..window] {
+        add(&mut counts, b, &mut sum, true);
+    }
+    let mut start = 0;
+    let mut out = Vec::with_capacity(data.len());
+    for i in 0..data.len() {
+        let want = (i.saturating_sub(window / 2)).min(data.len() - window);
+        while start < want {
+            add(&mut counts, data[start], &mut sum, false);
+            add(&mut counts, data[start + window], &mut sum, true);
+            start += 1;
+        }
+        let entropy = if max > 0.0 {
+            ((n.log2() - sum / n) / max).clamp(0.0, 1.0)
+        } else {
+            0.0
+        };
+        out.push(entropy_color(entropy));
+    }
+    out
+}
+
+/// The colour of an entropy from 0 to 1: blue rising with it, and red
+/// rising above one half.
+fn entropy_color(e: f64) -> [u8; 3] {
+    let curve = |v: f64| (4.0 * v - 4.0 * v * v).powi(4).max(0.0);
+    let r = if e > 0.5 { curve(e - 0.5) } else { 0.0 };
+    let b = e * e;
+    [(255.0 * r).round() as u8, 0, (255.0 * b).round() as u8]
+}
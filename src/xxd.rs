@@ -0,0 +1,288 @@
+//! Byte-for-byte reproduction of `xxd` output.
+//!
+//! [`Xxd`] mirrors the `xxd` command line: each setter corresponds to one
+//! flag and the defaults match running `xxd` with no flags.
+//!
+//! ```
+//! use hexdump::xxd::{Xxd, XxdMode};
+//!
+//! let data = b"Hello, world!\n";
+//! assert_eq!(
+//!     Xxd::new().dump(data),
+//!     "00000000: 4865 6c6c 6f2c 2077 6f72 6c64 210a       Hello, world!.\n",
+//! );
+//! assert_eq!(
+//!     Xxd::new().mode(XxdMode::Plain).seek(7).length(5).dump(data),
+//!     "776f726c64\n",
+//! );
+//! ```
+
+use std::fmt::Write as _;
+use std::io::{self, Read, Write};
+
+use crate::stream::{read_full, CHUNK_SIZE};
+use crate::{HexDumper, Preset, STRING_WRITE};
+
+/// The output modes of `xxd`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum XxdMode {
+    /// The default hex dump.
+    #[default]
+    Hex,
+    /// `-p`: plain hex digits without offsets or ASCII.
+    Plain,
+    /// `-i`: a C include file.
+    Include,
+    /// `-b`: binary digits instead of hex.
+    Bits,
+}
+
+impl XxdMode {
+    fn default_columns(self) -> usize {
+        match self {
+            XxdMode::Hex => 16,
+            XxdMode::Plain => 30,
+            XxdMode::Include => 12,
+            XxdMode::Bits => 6,
+        }
+    }
+}
+
+/// `xxd` compatible dumper, configured like the `xxd` command.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Xxd {
+    mode: XxdMode,
+    columns: Option<usize>,
+    group_size: Option<usize>,
+    seek: u64,
+    length: Option<u64>,
+    uppercase: bool,
+    capitalize: bool,
+    name: Option<String>,
+}
+
+impl Xxd {
+    /// Creates a dumper equivalent to `xxd` with no flags.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Selects the output mode (`-p`, `-i` or `-b`).
+    pub fn mode(mut self, mode: XxdMode) -> Self {
+        self.mode = mode;
+        self
+    }
+
+    /// `-c`: bytes per line.
+    ///
+    /// Zero selects the mode's default, except in plain mode where it puts
+    /// all output on a single line.
+    pub fn columns(mut self, columns: usize) -> Self {
+        self.columns = Some(columns);
+        self
+    }
+
+    /// `-g`: bytes per group. Zero disables grouping.
+    pub fn group_size(mut self, group_size: usize) -> Self {
+        self.group_size = Some(group_size);
+        self
+    }
+
+    /// `-s`: skips `seek` bytes of input. Offsets still count from the start
+    /// of the input.
+    pub fn seek(mut self, seek: u64) -> Self {
+        self.seek = seek;
+        self
+    }
+
+    /// `-l`: stops after `length` bytes.
+    pub fn length(mut self, length: u64) -> Self {
+        self.length = Some(length);
+        self
+    }
+
+    /// `-u`: upper case hex digits.
+    pub fn uppercase(mut self, upper: bool) -> Self {
+        self.uppercase = upper;
+        self
+    }
+
+    /// `-C`: capitalizes the variable names in include mode, including the
+    /// `_len` suffix.
+    pub fn capitalize(mut self, capitalize: bool) -> Self {
+        self.capitalize = capitalize;
+        self
+    }
+
+    /// Sets the variable name used in include mode, as `-n` does.
+    ///
+    /// Without a name the array body is written alone, which is what `xxd -i`
+    /// prints for standard input. Use [`c_identifier`] to derive the name
+    /// `xxd` would pick for a file.
+    pub fn name(mut self, name: impl Into<String>) -> Self {
+        self.name = Some(name.into());
+        self
+    }
+
+    /// Returns the [`HexDumper`] producing this configuration's lines.
+    ///
+    /// Include mode and single-line plain mode are not line based; for
+    /// those the returned dumper shows the same bytes as plain hex, with
+    /// plain mode's default line length.
+    pub fn dumper(&self) -> HexDumper {
+        let preset = match self.mode {
+            XxdMode::Hex => Preset::Xxd,
+            XxdMode::Plain | XxdMode::Include => Preset::XxdPlain,
+            XxdMode::Bits => Preset::XxdBits,
+        };
+        let mut dumper = HexDumper::new()
+            .preset(preset)
+            .bytes_per_line(
+                self.line_columns()
+                    .unwrap_or(XxdMode::Plain.default_columns()),
+            )
+            .uppercase(self.uppercase);
+        if let (Some(group), XxdMode::Hex | XxdMode::Bits) = (self.group_size, self.mode) {
+            dumper = dumper.group_size(group);
+        }
+        dumper
+    }
+
+    /// Bytes per line, or `None` when everything goes on one line.
+    fn line_columns(&self) -> Option<usize> {
+        match self.columns {
+            Some(0) if self.mode == XxdMode::Plain => None,
+            Some(0) | None => Some(self.mode.default_columns()),
+            Some(n) => Some(n),
+        }
+    }
+
+    /// Renders `data` as `xxd` would.
+    pub fn dump(&self, data: &[u8]) -> String {
+        let mut out = Vec::new();
+        self.dump_reader(data, &mut out)
+            .expect("writing to a Vec cannot fail");
+        String::from_utf8(out).expect("xxd output is ASCII")
+    }
+
+    /// Streams the `xxd` output for everything read from `reader` to
+    /// `writer`. Returns the number of bytes dumped, not counting skipped
+    /// input.
+    pub fn dump_reader<R: Read, W: Write>(&self, mut reader: R, writer: W) -> io::Result<u64> {
+        io::copy(&mut (&mut reader).take(self.seek), &mut io::sink())?;
+        let reader = reader.take(self.length.unwrap_or(u64::MAX));
+        match (self.mode, self.line_columns()) {
+            (XxdMode::Include, _) => self.write_include(reader, writer),
+            (_, None) => self.write_single_line(reader, writer),
+            (_, Some(_)) => self.dumper().dump_reader_from(reader, writer, self.seek),
+        }
+    }
+
+    /// Plain mode with unlimited columns: one line of hex digits.
+    fn write_single_line<R: Read, W: Write>(
+        &self,
+        mut reader: R,
+        mut writer: W,
+    ) -> io::Result<u64> {
+        // Each chunk is written as a line of its own, with no line break
+        // after it.
+        let dumper = self.dumper().bytes_per_line(CHUNK_SIZE);
+        let mut buf = vec![0u8; CHUNK_SIZE];
+        let mut text = String::new();
+        let mut total = 0u64;
+        loop {
+            let n = read_full(&mut reader, &mut buf)?;
+            if n == 0 {
+                break;
+            }
+            text.clear();
+            dumper
+                .write_line(&mut text, total, &buf[..n])
+                .expect(STRING_WRITE);
+            writer.write_all(text.as_bytes())?;
+            total += n as u64;
+        }
+        // xxd ends the line even when there was no input.
+        writer.write_all(b"\n")?;
+        writer.flush()?;
+        Ok(total)
+    }
+
+    /// Include mode: the bytes as a C array, optionally wrapped in
+    /// declarations of the array and its length.
+    fn write_include<R: Read, W: Write>(&self, mut reader: R, mut writer: W) -> io::Result<u64> {
+        let name = self.name.as_ref().map(|name| {
+            if self.capitalize {
+                name.to_ascii_uppercase()
+            } else {
+                name.clone()
+            }
+        });
+        if let Some(name) = &name {
+            writeln!(writer, "unsigned char {name}[] = {{")?;
+        }
+        let columns = self
+            .line_columns()
+            .unwrap_or(XxdMode::Include.default_columns());
+        let mut buf = vec![0u8; (CHUNK_SIZE / columns).max(1) * columns];
+        let mut text = String::new();
+        let mut total = 0u64;
+        loop {
+            let n = read_full(&mut reader, &mut buf)?;
+            if n == 0 {
+                break;
+            }
+            text.clear();
+            for line in buf[..n].chunks(columns) {
+                text.push_str(if total == 0 { "  " } else { ",\n  " });
+                for (i, b) in line.iter().enumerate() {
+                    if i > 0 {
+                        text.push_str(", ");
+                    }
+                    if self.uppercase {
+                        write!(text, "0X{b:02X}")
+                    } else {
+                        write!(text, "0x{b:02x}")
+                    }
+                    .expect(STRING_WRITE);
+                }
+                total += line.len() as u64;
+            }
+            writer.write_all(text.as_bytes())?;
+            if n < buf.len() {
+                break;
+            }
+        }
+        if total > 0 {
+            writer.write_all(b"\n")?;
+        }
+        if let Some(name) = &name {
+            let len = if self.capitalize { "LEN" } else { "len" };
+            writeln!(writer, "}};\nunsigned int {name}_{len} = {total};")?;
+        }
+        writer.flush()?;
+        Ok(total)
+    }
+}
+
+/// Turns a file name into the C identifier `xxd -i` declares for it.
+///
+/// Every character other than an ASCII letter or digit becomes `_`, and a
+/// leading digit is prefixed with `__`.
+///
+/// ```
+/// assert_eq!(hexdump::xxd::c_identifier("fw/boot-v2.bin"), "fw_boot_v2_bin");
+/// assert_eq!(hexdump::xxd::c_identifier("9lives"), "__9lives");
+/// ```
+pub fn c_identifier(file_name: &str) -> String {
+    let mut ident = String::with_capacity(file_name.len() + 2);
+    if file_name.starts_with(|c: char| c.is_ascii_digit()) {
+        ident.push_str("__");
+    }
+    ident.extend(
+        file_name
+            .chars()
+            .map(|c| if c.is_ascii_alphanumeric() { c } else { '_' }),
+    );
+    ident
+}
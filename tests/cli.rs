@@ -0,0 +1,507 @@
+#![cfg(feature = "std")]
+
+mod common;
+
+use std::fs;
+use std::io::{self, Write};
+use std::process::{Command, Output, Stdio};
+
+use hexdump::annotate::{Annotation, Endian, Field};
+use hexdump::codegen::{Codegen, Language};
+use hexdump::html::HtmlPage;
+use hexdump::ihex::{self, IhexFile};
+use hexdump::image::Image;
+use hexdump::json::JsonLines;
+use hexdump::srec;
+use hexdump::xxd::{c_identifier, Xxd, XxdMode};
+use hexdump::{HexDumper, Preset, Squeeze, TrailingOffset};
+
+use common::TempFile;
+
+fn hexdump_rs(args: &[&str], stdin: &[u8]) -> Output {
+    let mut child = Command::new(env!("CARGO_BIN_EXE_hexdump-rs"))
+        .args(args)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .unwrap();
+    // A usage error exits before reading, closing the pipe under the write.
+    if let Err(err) = child.stdin.take().unwrap().write_all(stdin) {
+        assert_eq!(err.kind(), io::ErrorKind::BrokenPipe);
+    }
+    child.wait_with_output().unwrap()
+}
+
+/// Runs the binary and returns its output, which must be a success.
+fn stdout(args: &[&str], stdin: &[u8]) -> String {
+    let output = hexdump_rs(args, stdin);
+    assert!(
+        output.status.success(),
+        "{args:?} failed: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    String::from_utf8(output.stdout).unwrap()
+}
+
+fn sample() -> Vec<u8> {
+    let mut data = b"hexdump-rs command line\n".to_vec();
+    data.extend([0; 64]);
+    data.extend(0..=255);
+    data
+}
+
+#[test]
+fn layouts_match_the_library() {
+    let data = sample();
+    assert_eq!(stdout(&[], &data), HexDumper::new().dump(&data));
+    assert_eq!(
+        stdout(&["-C"], &data),
+        HexDumper::new()
+            .preset(Preset::HexdumpCanonical)
+            .dump(&data)
+    );
+    assert_eq!(
+        stdout(&["--preset", "od-octal"], &data),
+        HexDumper::new().preset(Preset::OdOctal).dump(&data)
+    );
+    // Options adjust the preset wherever they appear.
+    assert_eq!(
+        stdout(
+            &[
+                "-c8",
+                "-g",
+                "2",
+                "-au",
+                "--preset=xxd",
+                "--ascii-delimiters",
+                "[]"
+            ],
+            &data
+        ),
+        HexDumper::new()
+            .preset(Preset::Xxd)
+            .bytes_per_line(8)
+            .group_size(2)
+            .squeeze(Squeeze::Always)
+            .uppercase(true)
+            .ascii_delimiters("[", "]")
+            .dump(&data)
+    );
+    assert_eq!(
+        stdout(
+            &[
+                "--no-ascii",
+                "-d",
+                "--offset-width=4",
+                "--squeeze-count",
+                "--squeeze",
+                "2"
+            ],
+            &data
+        ),
+        HexDumper::new()
+            .show_ascii(false)
+            .offset_base(hexdump::OffsetBase::Decimal)
+            .offset_width(4)
+            .squeeze_annotation(true)
+            .squeeze(Squeeze::After(2))
+            .dump(&data)
+    );
+}
+
+#[test]
+fn seek_and_length() {
+    let data = sample();
+    let file = TempFile::with_bytes("seek", &data);
+    let dumper = HexDumper::new().preset(Preset::HexdumpCanonical);
+    let expected = dumper
+        .clone()
+        .base_address(0x10)
+        .dump(&data[0x10..0x10 + 100]);
+    assert_eq!(
+        stdout(&["-C", "-s", "0x10", "-l", "100", file.path()], b""),
+        expected
+    );
+    assert_eq!(stdout(&["-C", "-s", "0x10", "-l", "100"], &data), expected);
+    assert_eq!(
+        stdout(&["-s", "-3", "-o", "0x8000", file.path()], b""),
+        HexDumper::new()
+            .base_address(0x8000 + data.len() as u64 - 3)
+            .dump(&data[data.len() - 3..])
+    );
+
+    let output = hexdump_rs(&["-s", "1k", file.path()], b"");
+    assert_eq!(output.status.code(), Some(1));
+    let output = hexdump_rs(&["-s", "-1"], b"");
+    assert_eq!(output.status.code(), Some(1));
+    assert!(String::from_utf8_lossy(&output.stderr).contains("standard input"));
+}
+
+#[test]
+fn xxd_modes() {
+    let data = sample();
+    let file = TempFile::with_bytes("fw.bin", &data);
+    assert_eq!(
+        stdout(&["-i", "-c", "8", "-u", file.path()], b""),
+        Xxd::new()
+            .mode(XxdMode::Include)
+            .columns(8)
+            .uppercase(true)
+            .name(hexdump::xxd::c_identifier(file.path()))
+            .dump(&data)
+    );
+    assert_eq!(
+        stdout(&["-i", "-n", "blob", "--capitalize"], &data),
+        Xxd::new()
+            .mode(XxdMode::Include)
+            .name("blob")
+            .capitalize(true)
+            .dump(&data)
+    );
+    assert_eq!(
+        stdout(&["-p", "-c", "0"], &data),
+        Xxd::new().mode(XxdMode::Plain).columns(0).dump(&data)
+    );
+    assert_eq!(
+        stdout(&["-b"], &data),
+        HexDumper::new().preset(Preset::XxdBits).dump(&data)
+    );
+}
+
+#[test]
+fn reverse() {
+    let data = sample();
+    for args in [&["-C"][..], &["-p"], &["-d", "-o", "4096"]] {
+        let text = stdout(args, &data);
+        let reverse: Vec<&str> = ["-r"].iter().chain(args).copied().collect();
+        let output = hexdump_rs(&reverse, text.as_bytes());
+        assert!(output.status.success(), "{args:?}");
+        assert_eq!(output.stdout, data, "{args:?}");
+    }
+
+    let output = hexdump_rs(&["-r"], b"00000000: 4g\n");
+    assert_eq!(output.status.code(), Some(1));
+    assert_eq!(
+        String::from_utf8_lossy(&output.stderr),
+        "hexdump-rs: standard input: line 1, column 12: invalid hex digit\n"
+    );
+    for text in ["ffffffffffffffff: 00\n", "7fff00000000: 4142  AB\n"] {
+        let output = hexdump_rs(&["-r"], text.as_bytes());
+        assert_eq!(output.status.code(), Some(1), "{text}");
+        assert!(String::from_utf8_lossy(&output.stderr).contains("offset too large"));
+    }
+    let output = hexdump_rs(&["-r", "-o", "0x7fff00000000"], b"7fff00000000: 4142  AB\n");
+    assert_eq!(output.stdout, b"AB");
+    // Gutters need not be UTF-8.
+    let output = hexdump_rs(&["-r"], b"00000000: 41ff 42  A\xffB\n");
+    assert_eq!(output.stdout, b"A\xffB");
+}
+
+#[test]
+fn output_file_and_colour() {
+    let data = sample();
+    let out = TempFile::with_bytes("out.txt", b"stale");
+    stdout(&["-R", "always", "-", out.path()], &data);
+    let text = fs::read_to_string(&out.0).unwrap();
+    assert!(text.contains("\x1b["));
+    // Auto colour is off when not writing to a terminal.
+    assert!(!stdout(&[], &data).contains('\x1b'));
+
+    // A failed run leaves the output file alone; an empty one truncates it.
+    let out = TempFile::with_bytes("kept.bin", b"precious");
+    let output = hexdump_rs(&["-r", "-", out.path()], b"00000000: 4g\n");
+    assert_eq!(output.status.code(), Some(1));
+    assert_eq!(fs::read(&out.0).unwrap(), b"precious");
+    let missing = hexdump_rs(&["/nonexistent/input", out.path()], b"");
+    assert_eq!(missing.status.code(), Some(1));
+    assert_eq!(fs::read(&out.0).unwrap(), b"precious");
+    stdout(&["-r", "-", out.path()], b"");
+    assert_eq!(fs::read(&out.0).unwrap(), b"");
+}
+
+#[test]
+fn search() {
+    let data = sample();
+    let dumper = HexDumper::new();
+    // With a line of context around the match on the second line.
+    let text = stdout(&["--find-text", "line", "--context", "1"], &data);
+    let carets = format!("{:19}^^ ^^ ^^ ^^{:33}^^^^\n", "", "");
+    assert_eq!(
+        text,
+        format!(
+            "{}{carets}{}",
+            dumper.dump(&data[..32]),
+            dumper.clone().base_address(32).dump(&data[32..48])
+        )
+    );
+    let text = stdout(&["-s", "24", "--find", "?0 ?1 02", "--context", "0"], &data);
+    assert!(text.starts_with(&HexDumper::new().base_address(88).dump(&data[88..104])));
+    let carets: Vec<&str> = text.lines().nth(1).unwrap().split_whitespace().collect();
+    assert_eq!(carets, ["^^", "^^", "^^", "^^^"]);
+    // Without context, every line is dumped with the matches coloured.
+    let text = stdout(&["-R", "always", "--find", "ff"], &data);
+    assert_eq!(text.lines().count(), 22);
+    assert!(text.contains("\x1b[30;43mff"));
+}
+
+#[test]
+fn strings() {
+    let mut data = vec![0u8; 40];
+    data[3..11].copy_from_slice(b"firmware");
+    data[20..30].copy_from_slice(b"v\x002\x00.\x001\x000\x00");
+    assert_eq!(
+        stdout(&["--strings"], &data),
+        "00000003  ascii     firmware\n"
+    );
+    let text = stdout(
+        &["--strings", "--encodings", "ascii,utf16le", "-s", "16"],
+        &data,
+    );
+    assert_eq!(text, "00000014  utf-16le  v2.10\n");
+    let text = stdout(&["--strings", "--min-len", "9"], &data);
+    assert_eq!(text, "");
+    let text = stdout(&["--strings", "--show-lines", "-C"], &data);
+    let expected = format!(
+        "00000003  ascii     firmware\n  {}",
+        HexDumper::new()
+            .preset(Preset::HexdumpCanonical)
+            .trailing_offset(TrailingOffset::Never)
+            .dump(&data[..16])
+    );
+    assert_eq!(text, expected);
+}
+
+#[test]
+fn codegen() {
+    let data = sample();
+    let file = TempFile::with_bytes("codegen.bin", &data);
+    let text = stdout(
+        &["--codegen", "rust", "-c", "16", "--comments", file.path()],
+        b"",
+    );
+    let name = c_identifier(file.path()).to_ascii_uppercase();
+    let expected = Codegen::new(Language::Rust)
+        .name(&name)
+        .bytes_per_line(16)
+        .comments(true)
+        .generate(&data);
+    assert_eq!(text, expected);
+    let text = stdout(
+        &[
+            "--codegen",
+            "go",
+            "-n",
+            "Blob",
+            "--len-name",
+            "BlobSize",
+            "-l",
+            "4",
+        ],
+        &data,
+    );
+    assert_eq!(
+        text,
+        Codegen::new(Language::Go)
+            .name("Blob")
+            .len_name("BlobSize")
+            .generate(&data[..4])
+    );
+}
+
+#[test]
+fn firmware_files() {
+    let data = sample();
+    let hex = stdout(&["--to", "ihex", "-o", "0x1fff0"], &data);
+    let image = Image::from_bytes(0x1fff0, data.clone());
+    let file = IhexFile { image, start: None };
+    assert_eq!(hex, ihex::Encoder::new().encode(&file).unwrap());
+
+    // Dumped at its own addresses, and converted back.
+    let text = stdout(&["--from", "ihex"], hex.as_bytes());
+    assert_eq!(text, HexDumper::new().dump_image(&file.image));
+    assert!(text.starts_with("0001fff0: "));
+    let srec = stdout(
+        &["--from", "ihex", "--to", "srec", "-c", "32"],
+        hex.as_bytes(),
+    );
+    assert!(srec.starts_with("S0030000FC\nS22401FFF0"));
+    let output = hexdump_rs(&["--from", "srec", "--to", "raw"], srec.as_bytes());
+    assert_eq!(output.stdout, data);
+
+    let mut gappy = srec::parse(&srec).unwrap();
+    gappy.image.insert(0x20_0000, b"far").unwrap();
+    let text = srec::Encoder::new().encode(&gappy).unwrap();
+    let output = hexdump_rs(
+        &["--from", "srec", "--to", "raw", "--gap-fill", "0"],
+        text.as_bytes(),
+    );
+    assert_eq!(output.stdout.len(), 0x20_0003 - 0x1fff0);
+    assert!(output.stdout.ends_with(b"\0\0far"));
+
+    let output = hexdump_rs(&["--from", "ihex"], b":0100000041BF\n");
+    assert_eq!(output.status.code(), Some(1));
+    let message = String::from_utf8_lossy(&output.stderr);
+    assert!(message.contains("line 1: checksum is BF, expected BE"));
+}
+
+#[test]
+fn json() {
+    let data = sample();
+    let file = TempFile::with_bytes("json.bin", &data);
+    let text = stdout(
+        &[
+            "--json",
+            "-a",
+            "-s",
+            "8",
+            "--annotate",
+            "0x10:u16be:version: major",
+            "--annotate",
+            "0:4",
+            file.path(),
+        ],
+        b"",
+    );
+    let expected = JsonLines::new()
+        .dumper(HexDumper::new().base_address(8).squeeze(Squeeze::Always))
+        .annotate(Annotation::field(
+            0x10,
+            Field::U16(Endian::Big),
+            "version: major",
+        ))
+        .annotate(Annotation::new(0..4, "4"))
+        .dump(&data[8..]);
+    assert_eq!(text, expected);
+    assert!(text.contains("\"type\":\"squeeze\""));
+}
+
+#[test]
+fn html() {
+    let data = sample();
+    let file = TempFile::with_bytes("html.bin", &data);
+    let text = stdout(
+        &["--html", "-C", "--annotate", "0:7:name", file.path()],
+        b"",
+    );
+    let expected = HtmlPage::new()
+        .title(file.path())
+        .dumper(
+            HexDumper::new()
+                .preset(Preset::HexdumpCanonical)
+                .base_address(0),
+        )
+        .annotate(Annotation::new(0..7, "name"))
+        .dump(&data);
+    assert_eq!(text, expected);
+    let text = stdout(&["--html", "-s", "16"], &data);
+    assert!(text.contains("<title>standard input</title>"));
+    assert!(text.contains("<span class=\"off\">00000010</span>"));
+}
+
+#[cfg(feature = "visualize")]
+#[test]
+fn visualize() {
+    use hexdump::visualize::{Colors, Layout, Visualizer};
+
+    let data = sample();
+    let file = TempFile::with_bytes("visualize.bin", &data);
+    let output = hexdump_rs(&["--visualize", "class", "-c", "16", file.path()], b"");
+    assert!(output.status.success());
+    let mut expected = Vec::new();
+    Visualizer::new()
+        .width(16)
+        .write_png(&data, &mut expected)
+        .unwrap();
+    assert_eq!(output.stdout, expected);
+
+    let output = hexdump_rs(
+        &[
+            "--visualize",
+            "entropy",
+            "--hilbert",
+            "--pixel-bytes",
+            "2",
+            "-s",
+            "4",
+        ],
+        &data,
+    );
+    let mut expected = Vec::new();
+    Visualizer::new()
+        .colors(Colors::Entropy)
+        .layout(Layout::Hilbert)
+        .bytes_per_pixel(2)
+        .write_png(&data[4..], &mut expected)
+        .unwrap();
+    assert_eq!(output.stdout, expected);
+}
+
+#[test]
+fn columns_are_capped() {
+    for args in [
+        &["-c", "4097"][..],
+        &["-c", "1m"],
+        &["-c", "0x10000000000"],
+        &["-p", "-c", "1m"],
+    ] {
+        let output = hexdump_rs(args, b"28 bytes of input, no more.\n");
+        assert_eq!(output.status.code(), Some(2), "{args:?}");
+        assert!(output.stdout.is_empty());
+        assert!(String::from_utf8_lossy(&output.stderr).contains("at most 4096"));
+    }
+    let text = stdout(&["-c", "4096", "--no-ascii"], b"ab");
+    assert_eq!(text, "00000000: 61 62\n");
+}
+
+#[test]
+fn usage_errors() {
+    for args in [
+        &["--bogus"][..],
+        &["-x"],
+        &["-c"],
+        &["-c", "0"],
+        &["-c", "many"],
+        &["--color=sometimes"],
+        &["--no-ascii=yes"],
+        &["a", "b", "c"],
+        &["--find", "4d 5"],
+        &["--find", "ab", "--find-text", "ab"],
+        &["--context", "2"],
+        &["-i", "--find", "00"],
+        &["--strings", "--find", "00"],
+        &["--strings", "--encodings", "ebcdic"],
+        &["--min-len", "2"],
+        &["--strings", "--min-len", "0"],
+        &["--codegen", "cobol"],
+        &["--codegen", "c", "-i"],
+        &["--comments"],
+        &["--from", "elf"],
+        &["--to", "ihex", "--strings"],
+        &["--from", "ihex", "-s", "16"],
+        &["--gap-fill", "0"],
+        &["--to", "raw", "--gap-fill", "256"],
+        &["--json", "--strings"],
+        &["--json", "--to", "srec"],
+        &["--annotate", "0:u8"],
+        &["--json", "--annotate", "0:u24le"],
+        &["--json", "--annotate", "0"],
+        &["--json", "--html"],
+        &["--html", "-i"],
+        &["--visualize", "sepia"],
+        &["--visualize", "grey", "--strings"],
+        &["--visualize", "grey", "--json"],
+        &["--visualize", "grey", "--hilbert", "-c", "8"],
+        &["--hilbert"],
+        &["--pixel-bytes", "4"],
+    ] {
+        let output = hexdump_rs(args, b"");
+        assert_eq!(output.status.code(), Some(2), "{args:?}");
+        assert!(output.stdout.is_empty());
+        assert!(String::from_utf8_lossy(&output.stderr).starts_with("hexdump-rs: "));
+    }
+    let output = hexdump_rs(&["/nonexistent/input"], b"");
+    assert_eq!(output.status.code(), Some(1));
+    assert!(String::from_utf8_lossy(&output.stderr).contains("/nonexistent/input"));
+    assert!(stdout(&["--help"], b"").starts_with("Usage: hexdump-rs"));
+}
@@ -0,0 +1,103 @@
+#![cfg(feature = "std")]
+
+use hexdump::codegen::{Codegen, Language};
+
+const DATA: &[u8] = b"Hello, world!\n";
+
+#[test]
+fn languages() {
+    let cases = [
+        (
+            Language::C,
+            "static const uint8_t boot[] = {\n    0x48, 0x65, 0x6c, 0x6c, 0x6f,\n};\n\
+             static const size_t boot_len = 5;\n",
+        ),
+        (
+            Language::Rust,
+            "pub static boot: [u8; 5] = [\n    0x48, 0x65, 0x6c, 0x6c, 0x6f,\n];\n\
+             pub const boot_len: usize = 5;\n",
+        ),
+        (
+            Language::RustSlice,
+            "pub static boot: &[u8] = &[\n    0x48, 0x65, 0x6c, 0x6c, 0x6f,\n];\n\
+             pub const boot_len: usize = 5;\n",
+        ),
+        (
+            Language::Python,
+            "boot = (\n    b\"\\x48\\x65\\x6c\\x6c\\x6f\"\n)\nboot_len = 5\n",
+        ),
+        (
+            Language::Go,
+            "var boot = []byte{\n\t0x48, 0x65, 0x6c, 0x6c, 0x6f,\n}\n\nconst bootLen = 5\n",
+        ),
+        (
+            Language::Zig,
+            "pub const boot = [_]u8{\n    0x48, 0x65, 0x6c, 0x6c, 0x6f,\n};\n\
+             pub const boot_len: usize = 5;\n",
+        ),
+    ];
+    for (language, expected) in cases {
+        let code = Codegen::new(language).name("boot").generate(&DATA[..5]);
+        assert_eq!(code, expected, "{language:?}");
+    }
+}
+
+#[test]
+fn names_and_lines() {
+    let code = Codegen::new(Language::C)
+        .bytes_per_line(6)
+        .uppercase(true)
+        .generate(DATA);
+    assert_eq!(
+        code,
+        "static const uint8_t data[] = {\n\
+         \x20   0x48, 0x65, 0x6C, 0x6C, 0x6F, 0x2C,\n\
+         \x20   0x20, 0x77, 0x6F, 0x72, 0x6C, 0x64,\n\
+         \x20   0x21, 0x0A,\n\
+         };\n\
+         static const size_t data_len = 14;\n"
+    );
+    let code = Codegen::new(Language::Rust).generate(b"\x00");
+    assert!(code.starts_with("pub static DATA: [u8; 1] = ["));
+    assert!(code.ends_with("pub const DATA_LEN: usize = 1;\n"));
+    let code = Codegen::new(Language::Zig)
+        .name("fw")
+        .len_name("fw_size")
+        .generate(b"\x00");
+    assert!(code.ends_with("pub const fw_size: usize = 1;\n"));
+    let code = Codegen::new(Language::Go).len_constant(false).generate(b"");
+    assert_eq!(code, "var data = []byte{\n}\n");
+    let code = Codegen::new(Language::Python).generate(b"");
+    assert_eq!(code, "data = b\"\"\ndata_len = 0\n");
+}
+
+#[test]
+fn comments() {
+    let code = Codegen::new(Language::RustSlice)
+        .bytes_per_line(8)
+        .comments(true)
+        .len_constant(false)
+        .generate(DATA);
+    assert_eq!(
+        code,
+        "pub static DATA: &[u8] = &[\n\
+         \x20   0x48, 0x65, 0x6c, 0x6c, 0x6f, 0x2c, 0x20, 0x77, // 00000000: Hello, w\n\
+         \x20   0x6f, 0x72, 0x6c, 0x64, 0x21, 0x0a,             // 00000008: orld!.\n\
+         ];\n"
+    );
+    let code = Codegen::new(Language::Python)
+        .bytes_per_line(3)
+        .comments(true)
+        .generate(b"ab\\c");
+    assert_eq!(
+        code,
+        "data = (\n    b\"\\x61\\x62\\x5c\"  # 00000000: ab\\\n    b\"\\x63\"          # 00000003: c\n)\n\
+         data_len = 4\n"
+    );
+    // A backslash would continue a C line comment onto the next line.
+    let code = Codegen::new(Language::C)
+        .bytes_per_line(2)
+        .comments(true)
+        .generate(b"a\\");
+    assert!(code.contains("0x61, 0x5c, // 00000000: a.\n"));
+}
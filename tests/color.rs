@@ -0,0 +1,116 @@
+#![cfg(feature = "std")]
+
+use hexdump::color::{ByteClass, Color, ColorChoice, ColorDepth, Style, Theme};
+use hexdump::{HexDumper, Preset};
+
+#[test]
+fn byte_classes() {
+    let class = |b| ByteClass::of(b);
+    assert_eq!(class(0x00), ByteClass::Null);
+    assert_eq!(class(b'A'), ByteClass::Printable);
+    assert_eq!(class(b'~'), ByteClass::Printable);
+    assert_eq!(class(b' '), ByteClass::Whitespace);
+    assert_eq!(class(b'\t'), ByteClass::Whitespace);
+    assert_eq!(class(b'\r'), ByteClass::Whitespace);
+    assert_eq!(class(0x01), ByteClass::Control);
+    assert_eq!(class(0x7f), ByteClass::Control);
+    assert_eq!(class(0x80), ByteClass::HighBit);
+    assert_eq!(class(0xfe), ByteClass::HighBit);
+    assert_eq!(class(0xff), ByteClass::Ff);
+}
+
+#[test]
+fn no_escapes_unless_enabled() {
+    let data: Vec<u8> = (0..=255).collect();
+    let plain = HexDumper::new().dump(&data);
+    assert!(!plain.contains('\x1b'));
+    for choice in [ColorChoice::Never, ColorChoice::Auto] {
+        let dumper = HexDumper::new().color(choice);
+        assert_eq!(dumper.dump(&data), plain);
+    }
+    // A pipe or file is not a terminal, so `Auto` resolves to no colour.
+    let file = tempfile();
+    let resolved = HexDumper::new().color(ColorChoice::Auto).color_for(&file);
+    assert_eq!(resolved.dump(&data), plain);
+}
+
+fn tempfile() -> std::fs::File {
+    let path = std::env::temp_dir().join(format!("hexdump-color-{}", std::process::id()));
+    let file = std::fs::File::create(&path).unwrap();
+    std::fs::remove_file(&path).unwrap();
+    file
+}
+
+#[test]
+fn colours_bytes_by_class() {
+    let theme = Theme {
+        null: Style::new().fg(Color::BRIGHT_BLACK),
+        printable: Style::new().fg(Color::CYAN),
+        whitespace: Style::new(),
+        control: Style::new().fg(Color::Ansi256(201)),
+        high_bit: Style::new().fg(Color::Rgb(1, 2, 3)).bold(),
+        ff: Style::new().bg(Color::RED),
+        offset: Style::new().dim(),
+    };
+    let dumper = HexDumper::new()
+        .show_offset(true)
+        .offset_width(2)
+        .color(ColorChoice::Always)
+        .theme(theme);
+    assert_eq!(
+        dumper.dump(b"\x00AB \x01\x80\xff"),
+        "\x1b[2m00\x1b[0m: \
+         \x1b[90m00\x1b[0m \x1b[36m41 42\x1b[0m 20 \x1b[38;5;201m01\x1b[0m \
+         \x1b[1;38;2;1;2;3m80\x1b[0m \x1b[41mff\x1b[0m\
+         \x20                            |\
+         \x1b[90m.\x1b[0m\x1b[36mAB\x1b[0m \x1b[38;5;201m.\x1b[0m\
+         \x1b[1;38;2;1;2;3m.\x1b[0m\x1b[41m.\x1b[0m|\n"
+    );
+}
+
+#[test]
+fn colour_does_not_change_layout() {
+    let data: Vec<u8> = (0..=255).chain(0..40).collect();
+    for preset in [Preset::Xxd, Preset::HexdumpCanonical, Preset::OdHex] {
+        let plain = HexDumper::new().preset(preset);
+        let colored = plain.clone().color(ColorChoice::Always);
+        assert_eq!(strip_ansi(&colored.dump(&data)), plain.dump(&data));
+    }
+}
+
+fn strip_ansi(text: &str) -> String {
+    let mut out = String::new();
+    let mut chars = text.chars();
+    while let Some(c) = chars.next() {
+        if c == '\x1b' {
+            chars.by_ref().find(|&c| c == 'm');
+        } else {
+            out.push(c);
+        }
+    }
+    out
+}
+
+#[test]
+fn depth_downgrades_colours() {
+    let orange = Color::Rgb(255, 135, 0);
+    assert_eq!(orange.to_depth(ColorDepth::TrueColor), orange);
+    assert_eq!(orange.to_depth(ColorDepth::Ansi256), Color::Ansi256(208));
+    assert_eq!(orange.to_depth(ColorDepth::Ansi16), Color::YELLOW);
+    assert_eq!(
+        Color::Ansi256(196).to_depth(ColorDepth::Ansi16),
+        Color::Ansi(9)
+    );
+    assert_eq!(Color::Ansi256(4).to_depth(ColorDepth::Ansi16), Color::BLUE);
+
+    let dumper = HexDumper::new()
+        .show_offset(false)
+        .show_ascii(false)
+        .color(ColorChoice::Always)
+        .color_depth(ColorDepth::Ansi16)
+        .theme(Theme {
+            printable: Style::new().fg(Color::Rgb(0, 0, 0)),
+            ..Theme::default()
+        });
+    assert_eq!(dumper.dump(b"A"), "\x1b[30m41\x1b[0m\n");
+}
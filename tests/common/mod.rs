@@ -0,0 +1,114 @@
+//! Helpers shared by the integration tests.
+
+#![allow(dead_code)]
+
+use std::fs;
+use std::io::{self, Read, Write};
+use std::path::PathBuf;
+
+/// A reader handing out its data a few bytes at a time, like a pipe or
+/// socket.
+pub struct Trickle<'a> {
+    data: &'a [u8],
+    max: usize,
+    uneven: bool,
+    reads: usize,
+}
+
+impl<'a> Trickle<'a> {
+    /// A reader returning at most `max` bytes per read.
+    pub fn new(data: &'a [u8], max: usize) -> Self {
+        Trickle {
+            data,
+            max,
+            uneven: false,
+            reads: 0,
+        }
+    }
+
+    /// A reader returning from one to `max` bytes, a different number each
+    /// time, and interrupted on every fifth read.
+    pub fn uneven(data: &'a [u8], max: usize) -> Self {
+        Trickle {
+            uneven: true,
+            ..Trickle::new(data, max)
+        }
+    }
+}
+
+impl Read for Trickle<'_> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        self.reads += 1;
+        let mut n = self.max;
+        if self.uneven {
+            if self.reads.is_multiple_of(5) {
+                return Err(io::ErrorKind::Interrupted.into());
+            }
+            n = self.reads % self.max + 1;
+        }
+        let n = n.min(buf.len()).min(self.data.len());
+        buf[..n].copy_from_slice(&self.data[..n]);
+        self.data = &self.data[n..];
+        Ok(n)
+    }
+}
+
+/// A file in the temporary directory, removed when dropped.
+pub struct TempFile(pub PathBuf);
+
+impl TempFile {
+    /// A path for a file called `name`, unique to the test process, which
+    /// is not created.
+    pub fn new(name: &str) -> Self {
+        let path = std::env::temp_dir().join(format!("hexdump-{}-{name}", std::process::id()));
+        TempFile(path)
+    }
+
+    pub fn with_bytes(name: &str, bytes: &[u8]) -> Self {
+        let file = TempFile::new(name);
+        fs::write(&file.0, bytes).unwrap();
+        file
+    }
+
+    pub fn path(&self) -> &str {
+        self.0.to_str().unwrap()
+    }
+}
+
+impl Drop for TempFile {
+    fn drop(&mut self) {
+        let _ = fs::remove_file(&self.0);
+    }
+}
+
+/// Formats a firmware file record: `start`, then `fields` and their
+/// checksum in upper case hex. `complement` turns the sum of the fields
+/// into the checksum.
+pub fn checksummed(start: &str, fields: &[u8], complement: fn(u8) -> u8) -> String {
+    let sum = fields.iter().fold(0u8, |sum, &b| sum.wrapping_add(b));
+    let hex: String = fields
+        .iter()
+        .chain([&complement(sum)])
+        .map(|b| format!("{b:02X}"))
+        .collect();
+    format!("{start}{hex}\n")
+}
+
+/// A writer remembering the longest write it was given.
+#[derive(Default)]
+pub struct LongestWrite {
+    pub longest: usize,
+    pub total: usize,
+}
+
+impl Write for LongestWrite {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.longest = self.longest.max(buf.len());
+        self.total += buf.len();
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
@@ -0,0 +1,137 @@
+#![cfg(feature = "std")]
+
+use std::io;
+
+use hexdump::color::{ColorChoice, Style};
+use hexdump::diff::{DiffDumper, DiffSummary};
+use hexdump::HexDumper;
+
+fn sample() -> Vec<u8> {
+    (0..64).collect()
+}
+
+fn markers(diff: &str) -> String {
+    diff.lines()
+        .map(|l| {
+            if l == "--" {
+                '-'
+            } else {
+                l.as_bytes()[46] as char
+            }
+        })
+        .collect()
+}
+
+#[test]
+fn identical_inputs() {
+    let data = sample();
+    let mut out = Vec::new();
+    let summary = DiffDumper::new()
+        .diff_readers(&data[..], &data[..], &mut out)
+        .unwrap();
+    assert!(summary.is_identical());
+    assert_eq!(summary.left_len, 64);
+    assert_eq!(markers(&String::from_utf8(out).unwrap()), "        ");
+    assert_eq!(DiffDumper::new().context(3).diff(&data, &data), "");
+}
+
+#[test]
+fn offset_alignment() {
+    let left = sample();
+    let mut right = sample();
+    right[9] = 0xff;
+    right.truncate(60);
+    right.extend_from_slice(&[1; 10]);
+    let diff = DiffDumper::new().diff(&left, &right);
+    assert_eq!(markers(&diff), " !     !>");
+    assert_eq!(
+        diff.lines().next_back(),
+        Some(&*format!(
+            "{:46}> 00000040: 01 01 01 01 01 01        |......|",
+            ""
+        ))
+    );
+
+    let mut out = Vec::new();
+    let summary = DiffDumper::new()
+        .diff_readers(&left[..], &right[..], &mut out)
+        .unwrap();
+    assert_eq!(
+        summary,
+        DiffSummary {
+            left_len: 64,
+            right_len: 70,
+            differing_bytes: 1 + 4 + 6,
+        }
+    );
+}
+
+#[test]
+fn resync_realigns_after_insertions_and_deletions() {
+    let left = sample();
+    let mut right = sample();
+    right.splice(20..20, [0xaa, 0xbb]);
+    right.remove(41);
+    let plain = DiffDumper::new().diff(&left, &right);
+    assert_eq!(markers(&plain), "  !!!!!!>");
+
+    let diff = DiffDumper::new().resync(16).diff(&left, &right);
+    assert_eq!(markers(&diff), "   >   <   ");
+    let rows: Vec<&str> = diff.lines().collect();
+    assert!(rows[3].ends_with("> 00000014: aa bb                    |..|"));
+    assert!(rows[7].starts_with("00000027: 27 "));
+    assert!(rows[7].ends_with('<'));
+    assert!(rows[8].starts_with("00000028: 28"));
+    assert!(rows[8].contains("   00000029: 28"));
+}
+
+#[test]
+fn context_limits_output() {
+    let left: Vec<u8> = vec![0; 8 * 20];
+    let mut right = left.clone();
+    right[8 * 3] = 1;
+    right[8 * 4] = 1;
+    right[8 * 15] = 1;
+    let diff = DiffDumper::new().context(1).diff(&left, &right);
+    assert_eq!(markers(&diff), " !! - ! ");
+    assert!(diff.lines().next().unwrap().starts_with("00000010:"));
+    assert_eq!(
+        markers(&DiffDumper::new().context(0).diff(&left, &right)),
+        "!!-!"
+    );
+}
+
+#[test]
+fn highlights_differing_bytes_when_coloured() {
+    let dumper = HexDumper::new()
+        .bytes_per_line(4)
+        .show_ascii(false)
+        .show_offset(false)
+        .color(ColorChoice::Always)
+        .theme(hexdump::color::Theme {
+            printable: Style::new(),
+            ..Default::default()
+        });
+    let diff = DiffDumper::new()
+        .dumper(dumper)
+        .highlight(Style::new().bold())
+        .diff(b"abcd", b"abXd");
+    assert_eq!(
+        diff,
+        "61 62 63 64 ! 61 62 \x1b[1m58\x1b[0m 64\n".replacen("63", "\x1b[1m63\x1b[0m", 1)
+    );
+}
+
+#[test]
+fn read_errors_propagate() {
+    struct Broken;
+    impl io::Read for Broken {
+        fn read(&mut self, _: &mut [u8]) -> io::Result<usize> {
+            Err(io::Error::other("gone"))
+        }
+    }
+    let err = DiffDumper::new()
+        .diff_readers(&b"abc"[..], Broken, io::sink())
+        .unwrap_err();
+    assert_eq!(err.to_string(), "gone");
+}
@@ -0,0 +1,86 @@
+#![cfg(feature = "std")]
+
+use hexdump::{HexDump, HexDumper, Preset, Squeeze};
+
+#[derive(Debug)]
+#[allow(dead_code)]
+struct Frame<'a> {
+    id: u16,
+    body: HexDump<'a>,
+}
+
+#[test]
+fn display_matches_dump_without_final_newline() {
+    let data: Vec<u8> = (0..40).collect();
+    let dumper = HexDumper::new().preset(Preset::HexdumpCanonical);
+    let text = dumper.dump(&data);
+    assert_eq!(
+        format!("{}", HexDump::with(&dumper, &data)),
+        text.trim_end_matches('\n')
+    );
+    assert_eq!(
+        format!("{}", HexDump(&data)),
+        HexDumper::new().dump(&data).trim_end_matches('\n')
+    );
+    assert_eq!(format!("{}", HexDump(&[])), "");
+}
+
+#[test]
+fn width_and_precision() {
+    let data = [0x41; 10];
+    assert_eq!(
+        format!("{:4.6}", HexDump(&data)),
+        "00000000: 41 41 41 41  |AAAA|\n\
+         00000004: 41 41        |AA|\n\
+         ... (4 more bytes)"
+    );
+    assert_eq!(format!("{:.0}", HexDump(&data)), "... (10 more bytes)");
+    assert_eq!(format!("{:.0?}", HexDump(&data)), "[...]");
+    let squeezed = HexDumper::new().bytes_per_line(8).squeeze(Squeeze::Always);
+    assert_eq!(
+        format!("{:2}", HexDump::with(&squeezed, &data)),
+        "00000000: 41 41  |AA|\n*"
+    );
+}
+
+#[test]
+fn hex_digits() {
+    let data = [0xde, 0xad, 0xbe, 0xef, 0x01];
+    assert_eq!(format!("{:x}", HexDump(&data)), "deadbeef01");
+    assert_eq!(format!("{:X}", HexDump(&data)), "DEADBEEF01");
+    assert_eq!(format!("{:#.2X}", HexDump(&data)), "0xDEAD");
+    assert_eq!(format!("{:2x}", HexDump(&data)), "dead\nbeef\n01");
+    let upper = HexDumper::new().uppercase(true);
+    assert_eq!(
+        format!("{:?}", HexDump::with(&upper, &data[..2])),
+        "[DE AD]"
+    );
+    assert_eq!(format!("{:x}", HexDump::with(&upper, &data[..2])), "dead");
+}
+
+#[test]
+fn pretty_debug_indents_the_dump() {
+    let frame = Frame {
+        id: 7,
+        body: HexDump(b"ping pong ping pong ping pong"),
+    };
+    assert_eq!(
+        format!("{frame:?}"),
+        "Frame { id: 7, body: [70 69 6e 67 20 70 6f 6e 67 20 70 69 6e 67 20 70 \
+         6f 6e 67 20 70 69 6e 67 20 70 6f 6e 67] }"
+    );
+    assert_eq!(
+        format!("{frame:#?}"),
+        "Frame {\n    \
+         id: 7,\n    \
+         body: 00000000: 70 69 6e 67 20 70 6f 6e 67 20 70 69 6e 67 20 70  |ping pong ping p|\n    \
+         00000010: 6f 6e 67 20 70 69 6e 67 20 70 6f 6e 67           |ong ping pong|,\n\
+         }"
+    );
+    // Nested deeper, every line of the dump moves in with it.
+    let nested = format!("{:#?}", [Some(frame)]);
+    assert!(nested.contains(
+        "\n            body: 00000000: 70 69 6e 67 20 70 6f 6e 67 20 70 69 6e 67 20 70  |ping pong ping p|\n            \
+         00000010: 6f 6e"
+    ));
+}
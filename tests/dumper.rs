@@ -0,0 +1,185 @@
+#![cfg(feature = "std")]
+
+use hexdump::color::ColorChoice;
+use hexdump::{ByteFormat, HexDumper, OffsetBase, Preset};
+
+#[test]
+fn default_layout() {
+    let data: Vec<u8> = (0x41..0x41 + 20).collect();
+    assert_eq!(
+        HexDumper::new().dump(&data),
+        "00000000: 41 42 43 44 45 46 47 48 49 4a 4b 4c 4d 4e 4f 50  |ABCDEFGHIJKLMNOP|\n\
+         00000010: 51 52 53 54                                      |QRST|\n"
+    );
+}
+
+#[test]
+fn empty_input() {
+    assert_eq!(HexDumper::new().dump(&[]), "");
+}
+
+#[test]
+fn grouping_and_separators() {
+    let dumper = HexDumper::new()
+        .bytes_per_line(6)
+        .group_size(4)
+        .group_separator("_")
+        .offset_separator(" | ")
+        .ascii_separator(" ")
+        .ascii_delimiters("<", ">")
+        .placeholder('~');
+    assert_eq!(
+        dumper.dump(b"\x00abcdefg"),
+        "00000000 | 00616263_6465 <~abcde>\n\
+         00000006 | 6667          <fg>\n"
+    );
+}
+
+#[test]
+fn group_size_zero_is_one_group() {
+    let dumper = HexDumper::new().bytes_per_line(4).group_size(0);
+    assert_eq!(
+        dumper.dump(b"abcde"),
+        "00000000: 61626364  |abcd|\n00000004: 65        |e|\n"
+    );
+}
+
+#[test]
+fn offset_formats() {
+    let data = [0u8; 9];
+    let base = HexDumper::new().bytes_per_line(8).show_ascii(false);
+    assert_eq!(
+        base.clone()
+            .offset_base(OffsetBase::Octal)
+            .offset_width(4)
+            .dump(&data),
+        "0000: 00 00 00 00 00 00 00 00\n0010: 00\n"
+    );
+    assert_eq!(
+        base.clone()
+            .offset_base(OffsetBase::Decimal)
+            .offset_width(2)
+            .dump(&data),
+        "00: 00 00 00 00 00 00 00 00\n08: 00\n"
+    );
+    assert_eq!(
+        base.show_offset(false).dump(&data),
+        "00 00 00 00 00 00 00 00\n00\n"
+    );
+}
+
+#[test]
+fn uppercase_digits() {
+    let dumper = HexDumper::new()
+        .bytes_per_line(10)
+        .uppercase(true)
+        .show_ascii(false)
+        .offset_width(2);
+    let data = [0xab; 11];
+    assert_eq!(
+        dumper.clone().dump(&data),
+        "00: AB AB AB AB AB AB AB AB AB AB\n0a: AB\n"
+    );
+    assert_eq!(
+        dumper
+            .uppercase(false)
+            .offset_base(OffsetBase::UpperHex)
+            .dump(&data),
+        "00: ab ab ab ab ab ab ab ab ab ab\n0A: ab\n"
+    );
+}
+
+#[test]
+fn binary_bytes() {
+    let dumper = HexDumper::new()
+        .bytes_per_line(3)
+        .group_size(2)
+        .byte_format(ByteFormat::Binary);
+    assert_eq!(
+        dumper.dump(b"Hi!\n"),
+        "00000000: 0100100001101001 00100001  |Hi!|\n\
+         00000003: 00001010                   |.|\n"
+    );
+}
+
+#[test]
+#[should_panic(expected = "bytes_per_line must be non-zero")]
+fn zero_bytes_per_line_panics() {
+    let _ = HexDumper::new().bytes_per_line(0);
+}
+
+#[test]
+fn lines_longer_than_bytes_per_line_are_errors() {
+    for dumper in [
+        HexDumper::new().bytes_per_line(4),
+        HexDumper::new()
+            .bytes_per_line(4)
+            .color(ColorChoice::Always),
+    ] {
+        let mut out = String::new();
+        assert!(dumper.write_line(&mut out, 0, b"abcde").is_err());
+        assert_eq!(out, "");
+        assert!(dumper.write_line(&mut out, 0, b"abcd").is_ok());
+    }
+}
+
+#[test]
+fn base_address_numbers_lines_and_trailing_offset() {
+    let dumper = HexDumper::new()
+        .preset(Preset::HexdumpCanonical)
+        .base_address(0x8000_0000);
+    let data = [0u8; 40];
+    let text = dumper.dump(&data);
+    assert_eq!(
+        text,
+        "80000000  00 00 00 00 00 00 00 00  00 00 00 00 00 00 00 00  |................|\n\
+         *\n\
+         80000020  00 00 00 00 00 00 00 00                           |........|\n\
+         80000028\n"
+    );
+    let mut streamed = Vec::new();
+    dumper.dump_reader(&data[..], &mut streamed).unwrap();
+    assert_eq!(String::from_utf8(streamed).unwrap(), text);
+}
+
+#[test]
+fn addresses_past_32_bits_use_full_width() {
+    let dumper = HexDumper::new()
+        .bytes_per_line(4)
+        .show_ascii(false)
+        .base_address(0xffff_fffc);
+    assert_eq!(
+        dumper.dump(b"abcdef"),
+        "fffffffc: 61 62 63 64\n0000000100000000: 65 66\n"
+    );
+    let decimal = dumper
+        .offset_base(OffsetBase::Decimal)
+        .base_address(u64::MAX - 1);
+    assert_eq!(decimal.dump(b"ab"), "18446744073709551614: 61 62\n");
+    // Plain offsets keep the configured width, as the classic tools do.
+    let offsets = HexDumper::new().bytes_per_line(1).show_ascii(false);
+    let mut text = String::new();
+    offsets.write_line(&mut text, 1 << 32, b"a").unwrap();
+    assert_eq!(text, "100000000: 61");
+}
+
+#[test]
+fn relative_offsets_are_signed() {
+    let dumper = HexDumper::new()
+        .bytes_per_line(8)
+        .offset_base(OffsetBase::Decimal)
+        .offset_width(3)
+        .relative_to(12);
+    assert_eq!(
+        dumper.dump(&[0x2e; 20]),
+        "-012: 2e 2e 2e 2e 2e 2e 2e 2e  |........|\n\
+         -004: 2e 2e 2e 2e 2e 2e 2e 2e  |........|\n\
+         +004: 2e 2e 2e 2e              |....|\n"
+    );
+    let before_zero = HexDumper::new()
+        .bytes_per_line(2)
+        .show_ascii(false)
+        .base_address(0x10)
+        .relative_to(u64::MAX);
+    assert_eq!(before_zero.dump(b"ab"), "-ffffffffffffffef: 61 62\n");
+}
@@ -0,0 +1,256 @@
+#![cfg(feature = "mmap")]
+
+mod common;
+
+use std::fs;
+
+use hexdump::edit::{Buffer, SaveError};
+
+use common::TempFile;
+
+fn contents(buffer: &Buffer) -> Vec<u8> {
+    let mut out = Vec::new();
+    buffer.write_to(&mut out).unwrap();
+    out
+}
+
+#[test]
+fn edits_and_reads() {
+    let mut buffer = Buffer::new(b"0123456789".to_vec());
+    buffer.replace(2, b"ab");
+    buffer.insert(5, b"XYZ");
+    buffer.delete(0..1);
+    assert_eq!(contents(&buffer), b"1ab4XYZ56789");
+    assert_eq!(buffer.len(), 12);
+    // Overwriting past the end extends the contents.
+    buffer.replace(10, b"!!!");
+    assert_eq!(contents(&buffer), b"1ab4XYZ567!!!");
+    buffer.insert(13, b"?");
+    assert_eq!(buffer.slice(3..100), b"4XYZ567!!!?");
+    assert_eq!(buffer.get(4), Some(b'X'));
+    assert_eq!(buffer.get(14), None);
+    let mut four = [0; 4];
+    assert_eq!(buffer.read_at(12, &mut four), 2);
+    assert_eq!(&four[..2], b"!?");
+    // Deleting everything and typing into the empty buffer.
+    buffer.delete(0..u64::MAX);
+    assert!(buffer.is_empty());
+    buffer.insert(0, b"new");
+    assert_eq!(contents(&buffer), b"new");
+}
+
+#[test]
+fn undo_and_redo() {
+    let mut buffer = Buffer::new(b"hello world".to_vec());
+    assert!(!buffer.is_modified());
+    assert_eq!(buffer.undo(), None);
+    buffer.replace(0, b"J");
+    buffer.insert(5, b",");
+    buffer.delete(7..12);
+    assert_eq!(contents(&buffer), b"Jello, ");
+    assert!(buffer.is_modified());
+
+    assert_eq!(buffer.undo(), Some(7));
+    assert_eq!(buffer.undo(), Some(5));
+    assert_eq!(contents(&buffer), b"Jello world");
+    assert_eq!(buffer.redo(), Some(5));
+    assert_eq!(contents(&buffer), b"Jello, world");
+    assert_eq!(buffer.undo(), Some(5));
+    assert_eq!(buffer.undo(), Some(0));
+    assert_eq!(buffer.undo(), None);
+    assert_eq!(contents(&buffer), b"hello world");
+    assert!(!buffer.is_modified());
+
+    // A new edit drops what could have been redone.
+    buffer.redo();
+    buffer.insert(0, b">");
+    assert_eq!(buffer.redo(), None);
+    assert_eq!(contents(&buffer), b">Jello world");
+}
+
+#[test]
+fn amending_the_last_edit() {
+    let mut buffer = Buffer::new(b"abcdef".to_vec());
+    buffer.insert(2, b"XY");
+    buffer.amend(3, b"Z");
+    buffer.amend(2, b"W");
+    assert_eq!(contents(&buffer), b"abWZcdef");
+    assert_eq!(buffer.undo(), Some(2));
+    assert_eq!(contents(&buffer), b"abcdef");
+    assert_eq!(buffer.redo(), Some(2));
+    assert_eq!(contents(&buffer), b"abWZcdef");
+
+    // Bytes outside what the last edit wrote are an edit of their own.
+    buffer.amend(3, b"!?");
+    assert_eq!(contents(&buffer), b"abW!?def");
+    assert_eq!(buffer.undo(), Some(3));
+    assert_eq!(contents(&buffer), b"abWZcdef");
+    // So are bytes amended after an undo.
+    buffer.amend(0, b"A");
+    assert_eq!(buffer.redo(), None);
+    assert_eq!(buffer.undo(), Some(0));
+    assert_eq!(contents(&buffer), b"abWZcdef");
+    assert!(buffer.is_modified());
+    assert_eq!(buffer.undo(), Some(2));
+    assert!(!buffer.is_modified());
+}
+
+#[test]
+fn many_small_edits() {
+    let original: Vec<u8> = (0..=255).collect();
+    let mut expected = original.clone();
+    let mut buffer = Buffer::new(original);
+    for i in 0..200u64 {
+        let at = i * 37 % buffer.len();
+        match i % 3 {
+            0 => {
+                buffer.insert(at, &[i as u8]);
+                expected.insert(at as usize, i as u8);
+            }
+            1 => {
+                buffer.replace(at, &[i as u8, !i as u8]);
+                let end = (at as usize + 2).min(expected.len());
+                expected.splice(at as usize..end, [i as u8, !i as u8]);
+            }
+            _ => {
+                buffer.delete(at..at + 3);
+                let end = (at as usize + 3).min(expected.len());
+                expected.drain(at as usize..end);
+            }
+        }
+        assert_eq!(contents(&buffer), expected, "after edit {i}");
+    }
+    while buffer.undo().is_some() {}
+    assert_eq!(contents(&buffer), (0..=255).collect::<Vec<u8>>());
+    while buffer.redo().is_some() {}
+    assert_eq!(contents(&buffer), expected);
+}
+
+#[test]
+fn saves_atomically() {
+    let file = TempFile::with_bytes("edit-save", b"firmware v1");
+    let mut buffer = Buffer::open(&file.0).unwrap();
+    assert_eq!(buffer.path(), Some(file.0.as_path()));
+    buffer.replace(10, b"2");
+    buffer.insert(11, b" patched");
+    buffer.save().unwrap();
+    assert!(!buffer.is_modified());
+    assert_eq!(fs::read(&file.0).unwrap(), b"firmware v2 patched");
+    // The mapping of the replaced file still backs the buffer.
+    buffer.delete(0..9);
+    buffer.save().unwrap();
+    assert_eq!(fs::read(&file.0).unwrap(), b"v2 patched");
+    // No temporary files are left behind.
+    let dir = file.0.parent().unwrap();
+    let name = file.0.file_name().unwrap().to_string_lossy().into_owned();
+    let leftovers = fs::read_dir(dir)
+        .unwrap()
+        .filter(|entry| {
+            let entry = entry.as_ref().unwrap().file_name();
+            let entry = entry.to_string_lossy();
+            entry.starts_with(&format!(".{name}")) && entry.ends_with(".tmp")
+        })
+        .count();
+    assert_eq!(leftovers, 0);
+}
+
+#[cfg(unix)]
+#[test]
+fn keeps_permissions() {
+    use std::os::unix::fs::PermissionsExt;
+
+    let file = TempFile::with_bytes("edit-mode", b"#!/bin/sh\n");
+    fs::set_permissions(&file.0, fs::Permissions::from_mode(0o750)).unwrap();
+    let mut buffer = Buffer::open(&file.0).unwrap();
+    buffer.insert(10, b"exit 0\n");
+    buffer.save().unwrap();
+    let mode = fs::metadata(&file.0).unwrap().permissions().mode();
+    assert_eq!(mode & 0o777, 0o750);
+}
+
+#[test]
+fn refuses_to_save_over_changes() {
+    let file = TempFile::with_bytes("edit-changed", b"original");
+    let mut buffer = Buffer::open(&file.0).unwrap();
+    buffer.replace(0, b"O");
+    assert!(!buffer.changed_on_disk().unwrap());
+    // Another editor saves its own version the same way.
+    let theirs = TempFile::with_bytes("edit-theirs", b"someone else's");
+    fs::rename(&theirs.0, &file.0).unwrap();
+    assert!(buffer.changed_on_disk().unwrap());
+    assert!(matches!(buffer.save(), Err(SaveError::ChangedOnDisk)));
+    assert_eq!(fs::read(&file.0).unwrap(), b"someone else's");
+    assert!(buffer.is_modified());
+    fs::remove_file(&file.0).unwrap();
+    assert!(matches!(buffer.save(), Err(SaveError::ChangedOnDisk)));
+    // Saving elsewhere is always allowed.
+    let other = TempFile::with_bytes("edit-other", b"");
+    buffer.save_as(&other.0).unwrap();
+    assert_eq!(fs::read(&other.0).unwrap(), b"Original");
+    assert_eq!(buffer.path(), Some(other.0.as_path()));
+
+    let mut memory = Buffer::new(b"bytes".to_vec());
+    memory.insert(0, b"more ");
+    assert!(matches!(memory.save(), Err(SaveError::NoFile)));
+}
+
+#[cfg(unix)]
+#[test]
+fn notices_replacements_that_keep_length_and_time() {
+    let file = TempFile::with_bytes("edit-replaced", b"original");
+    let modified = fs::metadata(&file.0).unwrap().modified().unwrap();
+    let mut buffer = Buffer::open(&file.0).unwrap();
+    buffer.replace(0, b"O");
+    // Same length and modification time, but a different file.
+    let theirs = TempFile::with_bytes("edit-lookalike", b"ORIGINAL");
+    fs::File::options()
+        .write(true)
+        .open(&theirs.0)
+        .unwrap()
+        .set_modified(modified)
+        .unwrap();
+    fs::rename(&theirs.0, &file.0).unwrap();
+    assert!(buffer.changed_on_disk().unwrap());
+    assert!(matches!(buffer.save(), Err(SaveError::ChangedOnDisk)));
+    assert_eq!(fs::read(&file.0).unwrap(), b"ORIGINAL");
+}
+
+#[test]
+fn skips_temporary_files_left_behind() {
+    let file = TempFile::with_bytes("edit-stale", b"old");
+    let name = file.0.file_name().unwrap().to_str().unwrap();
+    // What a save that crashed in this process would have left.
+    let stale: Vec<TempFile> = (0..2)
+        .map(|attempt| {
+            let path = file
+                .0
+                .with_file_name(format!(".{name}.{}.{attempt}.tmp", std::process::id()));
+            fs::write(&path, b"stale").unwrap();
+            TempFile(path)
+        })
+        .collect();
+    let mut buffer = Buffer::open(&file.0).unwrap();
+    buffer.replace(0, b"new");
+    buffer.save().unwrap();
+    assert_eq!(fs::read(&file.0).unwrap(), b"new");
+    for stale in &stale {
+        assert_eq!(fs::read(&stale.0).unwrap(), b"stale");
+    }
+}
+
+#[cfg(unix)]
+#[test]
+fn saves_through_symbolic_links() {
+    let target = TempFile::with_bytes("edit-target", b"old");
+    let link = TempFile::new("edit-link");
+    std::os::unix::fs::symlink(&target.0, &link.0).unwrap();
+    let mut buffer = Buffer::open(&link.0).unwrap();
+    buffer.replace(0, b"new");
+    buffer.save().unwrap();
+    assert!(fs::symlink_metadata(&link.0).unwrap().is_symlink());
+    assert_eq!(fs::read(&target.0).unwrap(), b"new");
+    // The link's stamp is the target's, so saving again is allowed.
+    buffer.insert(3, b"!");
+    buffer.save().unwrap();
+    assert_eq!(fs::read(&target.0).unwrap(), b"new!");
+}
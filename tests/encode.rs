@@ -0,0 +1,132 @@
+#![cfg(feature = "std")]
+
+use hexdump::encode::{self, Backend};
+
+/// xorshift64*, so the random cases are the same on every run.
+struct Rng(u64);
+
+impl Rng {
+    fn next(&mut self) -> u64 {
+        self.0 ^= self.0 >> 12;
+        self.0 ^= self.0 << 25;
+        self.0 ^= self.0 >> 27;
+        self.0.wrapping_mul(0x2545_f491_4f6c_dd1d)
+    }
+
+    fn below(&mut self, n: usize) -> usize {
+        (self.next() % n as u64) as usize
+    }
+}
+
+fn available() -> impl Iterator<Item = Backend> {
+    Backend::ALL.into_iter().filter(|b| b.is_available())
+}
+
+#[test]
+fn every_byte() {
+    let all: Vec<u8> = (0..=255).collect();
+    let expected: String = all.iter().map(|b| format!("{b:02x}")).collect();
+    for backend in available() {
+        let mut hex = vec![0; 512];
+        backend.hex(&all, &mut hex, false);
+        assert_eq!(hex, expected.as_bytes(), "{backend:?}");
+        backend.hex(&all, &mut hex, true);
+        assert_eq!(hex, expected.to_uppercase().as_bytes(), "{backend:?}");
+
+        let mut text = vec![0; 256];
+        backend.ascii(&all, &mut text, b'.');
+        for (b, t) in all.iter().zip(&text) {
+            let want = if hexdump::is_printable(*b) { *b } else { b'.' };
+            assert_eq!(*t, want, "{backend:?} byte {b:#04x}");
+        }
+    }
+}
+
+#[test]
+fn backends_agree_with_scalar() {
+    let mut rng = Rng(0x9e37_79b9_7f4a_7c15);
+    let mut data = vec![0u8; 4096];
+    for _ in 0..2000 {
+        // Random lengths and unaligned starts exercise the vector loops and
+        // their scalar tails.
+        let start = rng.below(64);
+        let len = rng.below(data.len() - start);
+        for b in &mut data[start..start + len] {
+            *b = rng.next() as u8;
+        }
+        let src = &data[start..start + len];
+        let upper = rng.next() & 1 == 1;
+        let placeholder = b' ' + rng.below(95) as u8;
+
+        let mut want_hex = vec![0; len * 2];
+        let mut want_text = vec![0; len];
+        Backend::Scalar.hex(src, &mut want_hex, upper);
+        Backend::Scalar.ascii(src, &mut want_text, placeholder);
+        for backend in available() {
+            let mut hex = vec![0; len * 2];
+            let mut text = vec![0; len];
+            backend.hex(src, &mut hex, upper);
+            backend.ascii(src, &mut text, placeholder);
+            assert_eq!(hex, want_hex, "{backend:?} hex of {src:02x?}");
+            assert_eq!(text, want_text, "{backend:?} ascii of {src:02x?}");
+        }
+    }
+}
+
+#[test]
+fn detected_backend_is_available() {
+    assert!(Backend::detect().is_available());
+    let mut hex = [0; 6];
+    encode::hex(b"\x01\xab\xff", &mut hex, true);
+    assert_eq!(&hex, b"01ABFF");
+}
+
+#[test]
+#[should_panic(expected = "two digits per byte")]
+fn hex_checks_length() {
+    encode::hex(b"abc", &mut [0; 5], false);
+}
+
+#[test]
+fn fast_line_path_matches_painted_path() {
+    use hexdump::color::{ColorChoice, Style, Theme};
+    use hexdump::{ByteFormat, HexDumper};
+
+    // Colour with an empty theme writes no escape codes but takes the
+    // byte-at-a-time path, so it is a reference for the batched one.
+    let plain = Style::new();
+    let theme = Theme {
+        null: plain,
+        printable: plain,
+        whitespace: plain,
+        control: plain,
+        high_bit: plain,
+        ff: plain,
+        offset: plain,
+    };
+    let seps = ["", " ", "  ", "·", "--------"];
+    let mut rng = Rng(42);
+    let data: Vec<u8> = (0..700).map(|_| rng.next() as u8).collect();
+    for _ in 0..300 {
+        let dumper = HexDumper::new()
+            .bytes_per_line(1 + rng.below(150))
+            .group_size(rng.below(9))
+            .group_separator(seps[rng.below(seps.len())])
+            .byte_separator(seps[rng.below(seps.len())])
+            .uppercase(rng.below(2) == 1)
+            .byte_format(if rng.below(5) == 0 {
+                ByteFormat::Binary
+            } else {
+                ByteFormat::Hex
+            })
+            .placeholder(['.', '·', ' '][rng.below(3)])
+            .show_ascii(rng.below(4) != 0);
+        let len = rng.below(data.len());
+        let painted = dumper.clone().color(ColorChoice::Always).theme(theme);
+        assert_eq!(
+            dumper.dump(&data[..len]),
+            painted.dump(&data[..len]),
+            "{dumper:?}"
+        );
+    }
+}
@@ -0,0 +1,114 @@
+#![cfg(feature = "mmap")]
+
+mod common;
+
+use std::fs::File;
+use std::io::{ErrorKind, Seek, SeekFrom, Write};
+
+use hexdump::{HexDumper, Preset, Squeeze};
+
+use common::{LongestWrite, TempFile};
+
+fn dump_file(
+    dumper: &HexDumper,
+    file: &TempFile,
+    range: impl std::ops::RangeBounds<u64>,
+) -> String {
+    let mut out = Vec::new();
+    dumper.dump_file(&file.0, range, &mut out).unwrap();
+    String::from_utf8(out).unwrap()
+}
+
+#[test]
+fn matches_in_memory_dumps() {
+    let data: Vec<u8> = (0..5000u32).map(|i| (i * 31 % 251) as u8).collect();
+    let file = TempFile::with_bytes("ranges", &data);
+    let dumper = HexDumper::new().preset(Preset::HexdumpCanonical);
+    assert_eq!(dump_file(&dumper, &file, ..), dumper.dump(&data));
+    assert_eq!(
+        dump_file(&dumper, &file, 1000..=1100),
+        dumper.clone().base_address(1000).dump(&data[1000..1101])
+    );
+    assert_eq!(
+        dump_file(&dumper, &file, 4990..),
+        dumper.clone().base_address(4990).dump(&data[4990..])
+    );
+    // The end of the range is clamped to the file.
+    assert_eq!(
+        dump_file(&dumper, &file, 4999..9999),
+        "00001387  66                                                |f|\n00001388\n"
+    );
+    assert_eq!(dump_file(&dumper, &file, 5000..), "");
+}
+
+#[test]
+fn rejects_ranges_past_the_end() {
+    let file = TempFile::with_bytes("past-end", b"short");
+    let err = HexDumper::new()
+        .dump_file(&file.0, 6.., Vec::new())
+        .unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::InvalidInput);
+    let missing = TempFile::new("missing");
+    let err = HexDumper::new()
+        .dump_file(&missing.0, .., Vec::new())
+        .unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::NotFound);
+}
+
+#[test]
+fn sparse_files() {
+    let file = TempFile::new("sparse");
+    let size = 1u64 << 30;
+    {
+        let mut f = File::create(&file.0).unwrap();
+        f.set_len(size).unwrap();
+        f.write_all(b"header").unwrap();
+        for at in [0x12_3457, 0x3000_0000, size - 3] {
+            f.seek(SeekFrom::Start(at)).unwrap();
+            f.write_all(b"xyz").unwrap();
+        }
+    }
+    let dumper = HexDumper::new().preset(Preset::HexdumpCanonical);
+    let text = dump_file(&dumper, &file, ..);
+    assert_eq!(
+        text,
+        "\
+00000000  68 65 61 64 65 72 00 00  00 00 00 00 00 00 00 00  |header..........|
+00000010  00 00 00 00 00 00 00 00  00 00 00 00 00 00 00 00  |................|
+*
+00123450  00 00 00 00 00 00 00 78  79 7a 00 00 00 00 00 00  |.......xyz......|
+00123460  00 00 00 00 00 00 00 00  00 00 00 00 00 00 00 00  |................|
+*
+30000000  78 79 7a 00 00 00 00 00  00 00 00 00 00 00 00 00  |xyz.............|
+30000010  00 00 00 00 00 00 00 00  00 00 00 00 00 00 00 00  |................|
+*
+3ffffff0  00 00 00 00 00 00 00 00  00 00 00 00 00 78 79 7a  |.............xyz|
+40000000
+"
+    );
+
+    // Ranges starting inside a hole, at an offset that is not a multiple of
+    // the line length, and short runs that are not squeezed.
+    let start = 0x2fff_ffe5;
+    let squeezed = dumper.clone().squeeze(Squeeze::After(3));
+    let expected = squeezed
+        .clone()
+        .base_address(start)
+        .dump(&[&[0; 0x1b][..], b"xyz", &[0; 0x30]].concat());
+    assert_eq!(dump_file(&squeezed, &file, start..start + 0x4e), expected);
+}
+
+#[test]
+fn unsqueezed_holes_are_streamed() {
+    let file = TempFile::new("sparse-unsqueezed");
+    let size = 8u64 << 20;
+    File::create(&file.0).unwrap().set_len(size).unwrap();
+    let dumper = HexDumper::new().squeeze(Squeeze::Off);
+    let mut out = LongestWrite::default();
+    assert_eq!(dumper.dump_file(&file.0, .., &mut out).unwrap(), size);
+    assert_eq!(
+        out.total,
+        dumper.dump(&[0; 16]).len() * (size as usize / 16)
+    );
+    assert!(out.longest < 1 << 20, "{}", out.longest);
+}
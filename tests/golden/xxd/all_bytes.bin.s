@@ -0,0 +1,16 @@
+0000000a: 0a0b 0c0d 0e0f 1011 1213 1415 1617 1819  ................
+0000001a: 1a1b 1c1d 1e1f 2021 2223 2425 2627 2829  ...... !"#$%&'()
+0000002a: 2a2b 2c2d 2e2f 3031 3233 3435 3637 3839  *+,-./0123456789
+0000003a: 3a3b 3c3d 3e3f 4041 4243 4445 4647 4849  :;<=>?@ABCDEFGHI
+0000004a: 4a4b 4c4d 4e4f 5051 5253 5455 5657 5859  JKLMNOPQRSTUVWXY
+0000005a: 5a5b 5c5d 5e5f 6061 6263 6465 6667 6869  Z[\]^_`abcdefghi
+0000006a: 6a6b 6c6d 6e6f 7071 7273 7475 7677 7879  jklmnopqrstuvwxy
+0000007a: 7a7b 7c7d 7e7f 8081 8283 8485 8687 8889  z{|}~...........
+0000008a: 8a8b 8c8d 8e8f 9091 9293 9495 9697 9899  ................
+0000009a: 9a9b 9c9d 9e9f a0a1 a2a3 a4a5 a6a7 a8a9  ................
+000000aa: aaab acad aeaf b0b1 b2b3 b4b5 b6b7 b8b9  ................
+000000ba: babb bcbd bebf c0c1 c2c3 c4c5 c6c7 c8c9  ................
+000000ca: cacb cccd cecf d0d1 d2d3 d4d5 d6d7 d8d9  ................
+000000da: dadb dcdd dedf e0e1 e2e3 e4e5 e6e7 e8e9  ................
+000000ea: eaeb eced eeef f0f1 f2f3 f4f5 f6f7 f8f9  ................
+000000fa: fafb fcfd feff                           ......
@@ -0,0 +1,3 @@
+unsigned char empty_bin[] = {
+};
+unsigned int empty_bin_len = 0;
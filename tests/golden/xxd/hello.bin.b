@@ -0,0 +1,3 @@
+00000000: 01001000 01100101 01101100 01101100 01101111 00101100  Hello,
+00000006: 00100000 01110111 01101111 01110010 01101100 01100100   world
+0000000c: 00100001 00001010 00000000 00000001 11111111           !....
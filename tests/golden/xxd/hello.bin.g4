@@ -0,0 +1,2 @@
+00000000: 48656c6c 6f2c2077 6f726c64 210a0001  Hello, world!...
+00000010: ff                                   .
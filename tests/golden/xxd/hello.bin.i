@@ -0,0 +1,5 @@
+unsigned char hello_bin[] = {
+  0x48, 0x65, 0x6c, 0x6c, 0x6f, 0x2c, 0x20, 0x77, 0x6f, 0x72, 0x6c, 0x64,
+  0x21, 0x0a, 0x00, 0x01, 0xff
+};
+unsigned int hello_bin_len = 17;
@@ -0,0 +1 @@
+48656c6c6f2c20776f726c64210a0001ff
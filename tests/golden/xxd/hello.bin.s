@@ -0,0 +1 @@
+0000000a: 6c64 210a 0001 ff                        ld!....
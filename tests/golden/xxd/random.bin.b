@@ -0,0 +1,167 @@
+00000000: 00111001 00001100 10001100 01111101 01110010 01000111  9..}rG
+00000006: 00110100 00101100 11011000 00010000 00001111 00101111  4,.../
+0000000c: 01101111 01110111 00001101 01100101 11010110 01110000  ow.e.p
+00000012: 11100101 10001110 00000011 01010001 11011000 10101110  ...Q..
+00000018: 10001110 01001111 01101110 10101100 00110100 00101111  .On.4/
+0000001e: 11000010 00110001 10110111 10110000 10000111 00010110  .1....
+00000024: 11101011 00111111 11000001 00101000 10010110 10111001  .?.(..
+0000002a: 01100010 00100011 00010111 01110100 10010100 00101000  b#.t.(
+00000030: 01110111 00110011 11000010 10001110 11101000 10111010  w3....
+00000036: 01010011 10111101 10110101 01101011 10001000 00100100  S..k.$
+0000003c: 01010111 01111101 01010011 11101100 11000010 10001010  W}S...
+00000042: 01110000 10100110 00011100 01110101 00010000 10100001  p..u..
+00000048: 11001101 10001001 00100001 01101100 10100001 01101100  ..!l.l
+0000004e: 11111111 11001010 11101010 01001001 10000111 01000111  ...I.G
+00000054: 01111110 10000110 11011011 11001100 10111001 01110000  ~....p
+0000005a: 01000110 11111100 00101110 00011000 00111000 01001110  F...8N
+00000060: 01010001 11011000 00100000 11000101 11000011 11101111  Q. ...
+00000066: 10000000 00000101 00111010 10001000 10101110 00111001  ..:..9
+0000006c: 10010110 11011110 01010000 11101000 00000001 10000110  ..P...
+00000072: 01011011 00110110 10011000 01100101 01001110 10111111  [6.eN.
+00000078: 01010010 00000000 10100101 11111010 00001001 00111001  R....9
+0000007e: 10111001 10011101 01111010 00011101 01111011 00101000  ..z.{(
+00000084: 00101011 11111000 00100011 01000000 01000001 11110011  +.#@A.
+0000008a: 01010100 10000111 11011000 01101100 01100110 10011111  T..lf.
+00000090: 11001100 10111111 11100000 11100111 00111101 01111110  ....=~
+00000096: 01110011 00100000 10101101 00001010 01110101 01110000  s ..up
+0000009c: 00000011 00100100 00011110 01110101 00100010 00010000  .$.u".
+000000a2: 10101001 00100100 01111001 10001110 11111000 01101101  .$y..m
+000000a8: 01000011 11110010 01111100 11110010 11010000 01100001  C.|..a
+000000ae: 00110000 00110001 11011100 10110101 11011000 11010010  01....
+000000b4: 11101111 00011011 00110010 00011111 11001110 10101101  ..2...
+000000ba: 00110111 01111111 01100010 01100001 11100101 01000111  7.ba.G
+000000c0: 11011000 01011101 10001110 11101100 01111111 00100110  .]...&
+000000c6: 11100010 00110010 00011001 00000111 00101111 01111001  .2../y
+000000cc: 01010101 11010000 11111000 11110110 01101101 11001101  U...m.
+000000d2: 00011110 01010100 11000010 00000001 11000111 10000111  .T....
+000000d8: 11101000 10010010 11011000 11111001 01001111 01100001  ....Oa
+000000de: 10010111 01101111 00011101 00011111 10100000 00011101  .o....
+000000e4: 00011001 11110100 01010000 00011101 00101001 01011111  ..P.)_
+000000ea: 00100011 00100010 01111000 11001110 00111101 01111110  #"x.=~
+000000f0: 00010100 00101001 11010110 10100001 10000101 01101000  .)...h
+000000f6: 10100000 01111010 10000111 11001010 01000011 10011001  .z..C.
+000000fc: 11101010 10100001 00100101 00000100 11101010 00110011  ..%..3
+00000102: 00100101 01101101 10000111 01000011 10110010 00100011  %m.C.#
+00000108: 01111101 10111101 10010001 01010000 11100000 10011010  }..P..
+0000010e: 00000100 10011001 00110101 01000100 10000111 00111011  ..5D.;
+00000114: 00110110 01001111 10001011 10010000 01101011 10101111  6O..k.
+0000011a: 01101000 10000111 11111010 10000000 00011010 00101111  h..../
+00000120: 11011000 10001101 00010110 00000001 10101010 01000010  .....B
+00000126: 10000110 01010010 11100010 11011010 00000100 00111001  .R...9
+0000012c: 00100110 01001100 00010010 10111101 01001011 11011100  &L..K.
+00000132: 01000001 00010101 10011101 10111010 00010100 10110111  A.....
+00000138: 01101011 01111111 00110100 10110101 11010000 01001111  k.4..O
+0000013e: 01111001 01010011 01011010 11010011 00001100 01011011  ySZ..[
+00000144: 10101010 11010010 01111111 10001000 01010001 00110111  ....Q7
+0000014a: 11000011 00010011 11110000 01110001 01100110 11101011  ...qf.
+00000150: 10110011 10011100 01110100 01110010 00001100 01100010  ..tr.b
+00000156: 11001100 10101000 10001110 00100011 10001110 10110011  ...#..
+0000015c: 11001100 10101001 00001110 00111011 10000101 01011011  ...;.[
+00000162: 10000111 00010011 00110111 11011110 10110000 10100000  ..7...
+00000168: 11011111 00111011 11000101 01100001 10000010 00010110  .;.a..
+0000016e: 11011111 00000000 01100100 10111010 11011100 00100011  ..d..#
+00000174: 10101001 10100000 00111111 10011001 10011110 11010001  ..?...
+0000017a: 10100111 11001110 10010111 01000001 01100010 11010111  ...Ab.
+00000180: 11000010 01011001 10011010 11001111 00000000 10011011  .Y....
+00000186: 10010010 01101011 11011100 10100100 11101110 11100010  .k....
+0000018c: 11100010 01101101 11110010 01010110 00101011 10010001  .m.V+.
+00000192: 10101011 00101111 01111000 10011110 01110011 01100101  ./x.se
+00000198: 01001011 00001100 00010111 01111101 11110011 00100101  K..}.%
+0000019e: 11101001 11010100 01100011 11000100 11111101 11001100  ..c...
+000001a4: 01111100 01001011 00000010 00110110 11011001 01110000  |K.6.p
+000001aa: 01011010 11101101 00011001 01111111 00111110 11101001  Z...>.
+000001b0: 01000100 11101101 10100010 11100010 11011010 11100100  D.....
+000001b6: 01010001 11110011 11100110 10000100 01111110 10001101  Q...~.
+000001bc: 11111000 01111010 10001100 11100001 00100111 10010010  .z..'.
+000001c2: 01111000 10001011 10101011 10100011 00101001 01000110  x...)F
+000001c8: 01001101 01110110 11000100 01001110 01101101 00100000  Mv.Nm 
+000001ce: 11010100 11010000 10101001 11101110 11010100 00011111  ......
+000001d4: 01101001 11010111 11000111 00001010 11000010 11110100  i.....
+000001da: 00000011 10110100 10011000 11000111 11010110 01110000  .....p
+000001e0: 11111001 01110000 10001011 11011111 11111000 00001110  .p....
+000001e6: 11000111 10101100 11001111 01010100 11101111 01000001  ...T.A
+000001ec: 00001101 11001001 00001101 00101010 11011011 01000101  ...*.E
+000001f2: 11101100 01011101 00011001 10000101 11000010 10100111  .]....
+000001f8: 01101100 11101000 10100111 10101100 11000010 10001110  l.....
+000001fe: 11010111 10000001 00101001 11110000 00001001 00011010  ..)...
+00000204: 10110011 01110010 00100011 00010100 00001111 01111110  .r#..~
+0000020a: 01100110 00001010 01001110 01111010 01000000 11110010  f.Nz@.
+00000210: 00111010 01101111 11101110 10000011 10111100 01010101  :o...U
+00000216: 00111010 01010011 10011111 00110111 00001101 10011111  :S.7..
+0000021c: 11000000 11001011 01100101 00100110 01111100 00110100  ..e&|4
+00000222: 10011010 00111101 00010101 10110001 11011011 10111101  .=....
+00000228: 00100011 10101110 00000110 11010111 11111010 00110110  #....6
+0000022e: 11011101 10111001 11101011 01001110 11011110 01011010  ...N.Z
+00000234: 10001010 11110111 11101110 11011111 10001001 10100101  ......
+0000023a: 01111101 00101100 10001110 11100110 01111100 11101101  },..|.
+00000240: 11000010 10101100 00001110 11111101 10100110 01011101  .....]
+00000246: 11111001 01101100 10110101 10000100 10101110 10001111  .l....
+0000024c: 10001101 00000101 01100001 00101011 01111011 11010000  ..a+{.
+00000252: 11111010 01111011 11110011 11111011 11100101 00001000  .{....
+00000258: 00101111 10010110 01110001 11001111 01111100 10011100  /.q.|.
+0000025e: 10111100 11110010 10110000 11011001 10101001 10110100  ......
+00000264: 11101000 10001010 10011100 10000000 01110110 00111101  ....v=
+0000026a: 01100010 10100001 00111101 01011110 01100010 01101110  b.=^bn
+00000270: 11110111 10001101 10010000 00110011 01100011 10010111  ...3c.
+00000276: 01110100 10111000 01011011 10011010 00000111 01000000  t.[..@
+0000027c: 10001100 00010111 00011011 10010101 01000000 11111011  ....@.
+00000282: 00110100 00000110 10010001 11110000 11110101 11100001  4.....
+00000288: 10101110 01011110 00011010 10000001 11110100 00111010  .^...:
+0000028e: 00100001 11001101 11111011 00100101 00011011 01001101  !..%.M
+00000294: 01001100 10011011 00101011 01111111 00111100 11010101  L.+.<.
+0000029a: 01110011 11000010 11100110 11100010 10011000 11011011  s.....
+000002a0: 10011100 00011110 00110010 01101010 01101100 10000111  ..2jl.
+000002a6: 00101001 01010000 01111010 01011000 00100110 01010000  )PzX&P
+000002ac: 00000001 11010001 11100110 11110000 10010101 00010000  ......
+000002b2: 01110110 10010011 10010000 11101000 00100100 01110111  v...$w
+000002b8: 10000111 01100101 11011001 00111010 01110011 01001100  .e.:sL
+000002be: 10001000 01001000 00100100 00011110 01010100 10011101  .H$.T.
+000002c4: 10010011 11100000 00111111 11101111 10011011 11001110  ..?...
+000002ca: 10001011 11111100 11100000 00101001 00010100 11011101  ...)..
+000002d0: 10100101 10000000 00001101 00101110 01110101 00001010  ....u.
+000002d6: 10001001 00010100 01011001 11110000 11100010 10001110  ..Y...
+000002dc: 01011100 11011111 11111011 00101110 11110000 10110010  \.....
+000002e2: 11010001 10101010 10100100 00110101 01010010 10101000  ...5R.
+000002e8: 11010010 11111101 10010011 11001101 00010010 11101000  ......
+000002ee: 00101101 10100001 10000001 10100101 00111011 11001110  -...;.
+000002f4: 00000000 11101100 11010011 00011011 01100000 10111001  ....`.
+000002fa: 11111111 11100010 00011010 01101000 10001000 01000011  ...h.C
+00000300: 10010011 11100000 11111000 00111110 00001110 01111010  ...>.z
+00000306: 01010001 10011111 00000111 11010000 00101111 01110011  Q.../s
+0000030c: 00111010 11101100 00111100 01001110 11111111 10010101  :.<N..
+00000312: 10001011 11010100 11110111 11110001 01111100 11101001  ....|.
+00000318: 01001010 11000100 01100001 01000101 00100011 10001101  J.aE#.
+0000031e: 11010100 10101110 10001000 00000001 10010000 10011000  ......
+00000324: 11111010 01001100 11100100 11110111 10110000 10101010  .L....
+0000032a: 11000001 11101001 10100100 01100000 01111010 11000100  ...`z.
+00000330: 01110111 11010010 00010110 10100010 11110010 11000011  w.....
+00000336: 11000101 01001101 11111101 00010010 01000000 10101001  .M..@.
+0000033c: 00110011 11100001 00110011 11101001 00000111 01001001  3.3..I
+00000342: 11010001 01001111 00100110 11110000 10000111 10101101  .O&...
+00000348: 11001011 00101001 10101000 11000010 10100010 11111001  .)....
+0000034e: 00010010 00100011 01111000 10010011 01110100 00101110  .#x.t.
+00000354: 11011110 00110010 00110011 11100011 01010101 10011001  .23.U.
+0000035a: 00001110 00010111 10100110 00011100 10010110 10110111  ......
+00000360: 10111111 11011100 01001010 01111101 11010010 01011100  ..J}.\
+00000366: 01010111 01011001 00101000 11000011 01111011 11111110  WY(.{.
+0000036c: 01001001 01110110 11101100 10000010 11101011 10000010  Iv....
+00000372: 00000100 11101110 10010011 01010000 00100101 11100010  ...P%.
+00000378: 10110000 10011001 11011001 10000000 11101001 10011010  ......
+0000037e: 01100101 11000100 11110111 00110110 01111001 11000011  e..6y.
+00000384: 10110111 10010111 10010111 00001011 11001010 10001100  ......
+0000038a: 00000100 00011001 11111110 10010010 01110101 10110100  ....u.
+00000390: 01110000 01100001 10000000 01000110 00110001 00010100  pa.F1.
+00000396: 10011110 11100001 00010001 10111010 01000011 00101110  ....C.
+0000039c: 10010111 10100111 11010100 01011001 01100110 01000011  ...YfC
+000003a2: 10111011 10001011 01010100 10000011 11110110 10010111  ..T...
+000003a8: 10101101 00111010 11101111 00100110 01001000 01110011  .:.&Hs
+000003ae: 11001011 10111011 00101110 11001010 00000111 10000111  ......
+000003b4: 00111111 11101000 10111100 10000110 11000011 10111110  ?.....
+000003ba: 00110111 01110111 11110001 00001100 10100111 01110001  7w...q
+000003c0: 00100000 11101101 10011010 11010001 00111011 01000111   ...;G
+000003c6: 00010111 00010011 10011011 11111100 00111011 00110001  ....;1
+000003cc: 01111000 01000101 11000110 11101000 10111101 11010110  xE....
+000003d2: 01001111 11010100 00110010 11111010 11010000 10001111  O.2...
+000003d8: 00010000 10111101 01101111 11100011 11100011 01111000  ..o..x
+000003de: 10111001 00110010 10111100 10110111 00011111 11001011  .2....
+000003e4: 10001101 01100001 00111110 11101000                    .a>.
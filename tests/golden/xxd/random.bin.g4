@@ -0,0 +1,63 @@
+00000000: 390c8c7d 7247342c d8100f2f 6f770d65  9..}rG4,.../ow.e
+00000010: d670e58e 0351d8ae 8e4f6eac 342fc231  .p...Q...On.4/.1
+00000020: b7b08716 eb3fc128 96b96223 17749428  .....?.(..b#.t.(
+00000030: 7733c28e e8ba53bd b56b8824 577d53ec  w3....S..k.$W}S.
+00000040: c28a70a6 1c7510a1 cd89216c a16cffca  ..p..u....!l.l..
+00000050: ea498747 7e86dbcc b97046fc 2e18384e  .I.G~....pF...8N
+00000060: 51d820c5 c3ef8005 3a88ae39 96de50e8  Q. .....:..9..P.
+00000070: 01865b36 98654ebf 5200a5fa 0939b99d  ..[6.eN.R....9..
+00000080: 7a1d7b28 2bf82340 41f35487 d86c669f  z.{(+.#@A.T..lf.
+00000090: ccbfe0e7 3d7e7320 ad0a7570 03241e75  ....=~s ..up.$.u
+000000a0: 2210a924 798ef86d 43f27cf2 d0613031  "..$y..mC.|..a01
+000000b0: dcb5d8d2 ef1b321f cead377f 6261e547  ......2...7.ba.G
+000000c0: d85d8eec 7f26e232 19072f79 55d0f8f6  .]...&.2../yU...
+000000d0: 6dcd1e54 c201c787 e892d8f9 4f61976f  m..T........Oa.o
+000000e0: 1d1fa01d 19f4501d 295f2322 78ce3d7e  ......P.)_#"x.=~
+000000f0: 1429d6a1 8568a07a 87ca4399 eaa12504  .)...h.z..C...%.
+00000100: ea33256d 8743b223 7dbd9150 e09a0499  .3%m.C.#}..P....
+00000110: 3544873b 364f8b90 6baf6887 fa801a2f  5D.;6O..k.h..../
+00000120: d88d1601 aa428652 e2da0439 264c12bd  .....B.R...9&L..
+00000130: 4bdc4115 9dba14b7 6b7f34b5 d04f7953  K.A.....k.4..OyS
+00000140: 5ad30c5b aad27f88 5137c313 f07166eb  Z..[....Q7...qf.
+00000150: b39c7472 0c62cca8 8e238eb3 cca90e3b  ..tr.b...#.....;
+00000160: 855b8713 37deb0a0 df3bc561 8216df00  .[..7....;.a....
+00000170: 64badc23 a9a03f99 9ed1a7ce 974162d7  d..#..?......Ab.
+00000180: c2599acf 009b926b dca4eee2 e26df256  .Y.....k.....m.V
+00000190: 2b91ab2f 789e7365 4b0c177d f325e9d4  +../x.seK..}.%..
+000001a0: 63c4fdcc 7c4b0236 d9705aed 197f3ee9  c...|K.6.pZ...>.
+000001b0: 44eda2e2 dae451f3 e6847e8d f87a8ce1  D.....Q...~..z..
+000001c0: 2792788b aba32946 4d76c44e 6d20d4d0  '.x...)FMv.Nm ..
+000001d0: a9eed41f 69d7c70a c2f403b4 98c7d670  ....i..........p
+000001e0: f9708bdf f80ec7ac cf54ef41 0dc90d2a  .p.......T.A...*
+000001f0: db45ec5d 1985c2a7 6ce8a7ac c28ed781  .E.]....l.......
+00000200: 29f0091a b3722314 0f7e660a 4e7a40f2  )....r#..~f.Nz@.
+00000210: 3a6fee83 bc553a53 9f370d9f c0cb6526  :o...U:S.7....e&
+00000220: 7c349a3d 15b1dbbd 23ae06d7 fa36ddb9  |4.=....#....6..
+00000230: eb4ede5a 8af7eedf 89a57d2c 8ee67ced  .N.Z......},..|.
+00000240: c2ac0efd a65df96c b584ae8f 8d05612b  .....].l......a+
+00000250: 7bd0fa7b f3fbe508 2f9671cf 7c9cbcf2  {..{..../.q.|...
+00000260: b0d9a9b4 e88a9c80 763d62a1 3d5e626e  ........v=b.=^bn
+00000270: f78d9033 639774b8 5b9a0740 8c171b95  ...3c.t.[..@....
+00000280: 40fb3406 91f0f5e1 ae5e1a81 f43a21cd  @.4......^...:!.
+00000290: fb251b4d 4c9b2b7f 3cd573c2 e6e298db  .%.ML.+.<.s.....
+000002a0: 9c1e326a 6c872950 7a582650 01d1e6f0  ..2jl.)PzX&P....
+000002b0: 95107693 90e82477 8765d93a 734c8848  ..v...$w.e.:sL.H
+000002c0: 241e549d 93e03fef 9bce8bfc e02914dd  $.T...?......)..
+000002d0: a5800d2e 750a8914 59f0e28e 5cdffb2e  ....u...Y...\...
+000002e0: f0b2d1aa a43552a8 d2fd93cd 12e82da1  .....5R.......-.
+000002f0: 81a53bce 00ecd31b 60b9ffe2 1a688843  ..;.....`....h.C
+00000300: 93e0f83e 0e7a519f 07d02f73 3aec3c4e  ...>.zQ.../s:.<N
+00000310: ff958bd4 f7f17ce9 4ac46145 238dd4ae  ......|.J.aE#...
+00000320: 88019098 fa4ce4f7 b0aac1e9 a4607ac4  .....L.......`z.
+00000330: 77d216a2 f2c3c54d fd1240a9 33e133e9  w......M..@.3.3.
+00000340: 0749d14f 26f087ad cb29a8c2 a2f91223  .I.O&....).....#
+00000350: 7893742e de3233e3 55990e17 a61c96b7  x.t..23.U.......
+00000360: bfdc4a7d d25c5759 28c37bfe 4976ec82  ..J}.\WY(.{.Iv..
+00000370: eb8204ee 935025e2 b099d980 e99a65c4  .....P%.......e.
+00000380: f73679c3 b797970b ca8c0419 fe9275b4  .6y...........u.
+00000390: 70618046 31149ee1 11ba432e 97a7d459  pa.F1.....C....Y
+000003a0: 6643bb8b 5483f697 ad3aef26 4873cbbb  fC..T....:.&Hs..
+000003b0: 2eca0787 3fe8bc86 c3be3777 f10ca771  ....?.....7w...q
+000003c0: 20ed9ad1 3b471713 9bfc3b31 7845c6e8   ...;G....;1xE..
+000003d0: bdd64fd4 32fad08f 10bd6fe3 e378b932  ..O.2.....o..x.2
+000003e0: bcb71fcb 8d613ee8                    .....a>.
@@ -0,0 +1,87 @@
+unsigned char random_bin[] = {
+  0x39, 0x0c, 0x8c, 0x7d, 0x72, 0x47, 0x34, 0x2c, 0xd8, 0x10, 0x0f, 0x2f,
+  0x6f, 0x77, 0x0d, 0x65, 0xd6, 0x70, 0xe5, 0x8e, 0x03, 0x51, 0xd8, 0xae,
+  0x8e, 0x4f, 0x6e, 0xac, 0x34, 0x2f, 0xc2, 0x31, 0xb7, 0xb0, 0x87, 0x16,
+  0xeb, 0x3f, 0xc1, 0x28, 0x96, 0xb9, 0x62, 0x23, 0x17, 0x74, 0x94, 0x28,
+  0x77, 0x33, 0xc2, 0x8e, 0xe8, 0xba, 0x53, 0xbd, 0xb5, 0x6b, 0x88, 0x24,
+  0x57, 0x7d, 0x53, 0xec, 0xc2, 0x8a, 0x70, 0xa6, 0x1c, 0x75, 0x10, 0xa1,
+  0xcd, 0x89, 0x21, 0x6c, 0xa1, 0x6c, 0xff, 0xca, 0xea, 0x49, 0x87, 0x47,
+  0x7e, 0x86, 0xdb, 0xcc, 0xb9, 0x70, 0x46, 0xfc, 0x2e, 0x18, 0x38, 0x4e,
+  0x51, 0xd8, 0x20, 0xc5, 0xc3, 0xef, 0x80, 0x05, 0x3a, 0x88, 0xae, 0x39,
+  0x96, 0xde, 0x50, 0xe8, 0x01, 0x86, 0x5b, 0x36, 0x98, 0x65, 0x4e, 0xbf,
+  0x52, 0x00, 0xa5, 0xfa, 0x09, 0x39, 0xb9, 0x9d, 0x7a, 0x1d, 0x7b, 0x28,
+  0x2b, 0xf8, 0x23, 0x40, 0x41, 0xf3, 0x54, 0x87, 0xd8, 0x6c, 0x66, 0x9f,
+  0xcc, 0xbf, 0xe0, 0xe7, 0x3d, 0x7e, 0x73, 0x20, 0xad, 0x0a, 0x75, 0x70,
+  0x03, 0x24, 0x1e, 0x75, 0x22, 0x10, 0xa9, 0x24, 0x79, 0x8e, 0xf8, 0x6d,
+  0x43, 0xf2, 0x7c, 0xf2, 0xd0, 0x61, 0x30, 0x31, 0xdc, 0xb5, 0xd8, 0xd2,
+  0xef, 0x1b, 0x32, 0x1f, 0xce, 0xad, 0x37, 0x7f, 0x62, 0x61, 0xe5, 0x47,
+  0xd8, 0x5d, 0x8e, 0xec, 0x7f, 0x26, 0xe2, 0x32, 0x19, 0x07, 0x2f, 0x79,
+  0x55, 0xd0, 0xf8, 0xf6, 0x6d, 0xcd, 0x1e, 0x54, 0xc2, 0x01, 0xc7, 0x87,
+  0xe8, 0x92, 0xd8, 0xf9, 0x4f, 0x61, 0x97, 0x6f, 0x1d, 0x1f, 0xa0, 0x1d,
+  0x19, 0xf4, 0x50, 0x1d, 0x29, 0x5f, 0x23, 0x22, 0x78, 0xce, 0x3d, 0x7e,
+  0x14, 0x29, 0xd6, 0xa1, 0x85, 0x68, 0xa0, 0x7a, 0x87, 0xca, 0x43, 0x99,
+  0xea, 0xa1, 0x25, 0x04, 0xea, 0x33, 0x25, 0x6d, 0x87, 0x43, 0xb2, 0x23,
+  0x7d, 0xbd, 0x91, 0x50, 0xe0, 0x9a, 0x04, 0x99, 0x35, 0x44, 0x87, 0x3b,
+  0x36, 0x4f, 0x8b, 0x90, 0x6b, 0xaf, 0x68, 0x87, 0xfa, 0x80, 0x1a, 0x2f,
+  0xd8, 0x8d, 0x16, 0x01, 0xaa, 0x42, 0x86, 0x52, 0xe2, 0xda, 0x04, 0x39,
+  0x26, 0x4c, 0x12, 0xbd, 0x4b, 0xdc, 0x41, 0x15, 0x9d, 0xba, 0x14, 0xb7,
+  0x6b, 0x7f, 0x34, 0xb5, 0xd0, 0x4f, 0x79, 0x53, 0x5a, 0xd3, 0x0c, 0x5b,
+  0xaa, 0xd2, 0x7f, 0x88, 0x51, 0x37, 0xc3, 0x13, 0xf0, 0x71, 0x66, 0xeb,
+  0xb3, 0x9c, 0x74, 0x72, 0x0c, 0x62, 0xcc, 0xa8, 0x8e, 0x23, 0x8e, 0xb3,
+  0xcc, 0xa9, 0x0e, 0x3b, 0x85, 0x5b, 0x87, 0x13, 0x37, 0xde, 0xb0, 0xa0,
+  0xdf, 0x3b, 0xc5, 0x61, 0x82, 0x16, 0xdf, 0x00, 0x64, 0xba, 0xdc, 0x23,
+  0xa9, 0xa0, 0x3f, 0x99, 0x9e, 0xd1, 0xa7, 0xce, 0x97, 0x41, 0x62, 0xd7,
+  0xc2, 0x59, 0x9a, 0xcf, 0x00, 0x9b, 0x92, 0x6b, 0xdc, 0xa4, 0xee, 0xe2,
+  0xe2, 0x6d, 0xf2, 0x56, 0x2b, 0x91, 0xab, 0x2f, 0x78, 0x9e, 0x73, 0x65,
+  0x4b, 0x0c, 0x17, 0x7d, 0xf3, 0x25, 0xe9, 0xd4, 0x63, 0xc4, 0xfd, 0xcc,
+  0x7c, 0x4b, 0x02, 0x36, 0xd9, 0x70, 0x5a, 0xed, 0x19, 0x7f, 0x3e, 0xe9,
+  0x44, 0xed, 0xa2, 0xe2, 0xda, 0xe4, 0x51, 0xf3, 0xe6, 0x84, 0x7e, 0x8d,
+  0xf8, 0x7a, 0x8c, 0xe1, 0x27, 0x92, 0x78, 0x8b, 0xab, 0xa3, 0x29, 0x46,
+  0x4d, 0x76, 0xc4, 0x4e, 0x6d, 0x20, 0xd4, 0xd0, 0xa9, 0xee, 0xd4, 0x1f,
+  0x69, 0xd7, 0xc7, 0x0a, 0xc2, 0xf4, 0x03, 0xb4, 0x98, 0xc7, 0xd6, 0x70,
+  0xf9, 0x70, 0x8b, 0xdf, 0xf8, 0x0e, 0xc7, 0xac, 0xcf, 0x54, 0xef, 0x41,
+  0x0d, 0xc9, 0x0d, 0x2a, 0xdb, 0x45, 0xec, 0x5d, 0x19, 0x85, 0xc2, 0xa7,
+  0x6c, 0xe8, 0xa7, 0xac, 0xc2, 0x8e, 0xd7, 0x81, 0x29, 0xf0, 0x09, 0x1a,
+  0xb3, 0x72, 0x23, 0x14, 0x0f, 0x7e, 0x66, 0x0a, 0x4e, 0x7a, 0x40, 0xf2,
+  0x3a, 0x6f, 0xee, 0x83, 0xbc, 0x55, 0x3a, 0x53, 0x9f, 0x37, 0x0d, 0x9f,
+  0xc0, 0xcb, 0x65, 0x26, 0x7c, 0x34, 0x9a, 0x3d, 0x15, 0xb1, 0xdb, 0xbd,
+  0x23, 0xae, 0x06, 0xd7, 0xfa, 0x36, 0xdd, 0xb9, 0xeb, 0x4e, 0xde, 0x5a,
+  0x8a, 0xf7, 0xee, 0xdf, 0x89, 0xa5, 0x7d, 0x2c, 0x8e, 0xe6, 0x7c, 0xed,
+  0xc2, 0xac, 0x0e, 0xfd, 0xa6, 0x5d, 0xf9, 0x6c, 0xb5, 0x84, 0xae, 0x8f,
+  0x8d, 0x05, 0x61, 0x2b, 0x7b, 0xd0, 0xfa, 0x7b, 0xf3, 0xfb, 0xe5, 0x08,
+  0x2f, 0x96, 0x71, 0xcf, 0x7c, 0x9c, 0xbc, 0xf2, 0xb0, 0xd9, 0xa9, 0xb4,
+  0xe8, 0x8a, 0x9c, 0x80, 0x76, 0x3d, 0x62, 0xa1, 0x3d, 0x5e, 0x62, 0x6e,
+  0xf7, 0x8d, 0x90, 0x33, 0x63, 0x97, 0x74, 0xb8, 0x5b, 0x9a, 0x07, 0x40,
+  0x8c, 0x17, 0x1b, 0x95, 0x40, 0xfb, 0x34, 0x06, 0x91, 0xf0, 0xf5, 0xe1,
+  0xae, 0x5e, 0x1a, 0x81, 0xf4, 0x3a, 0x21, 0xcd, 0xfb, 0x25, 0x1b, 0x4d,
+  0x4c, 0x9b, 0x2b, 0x7f, 0x3c, 0xd5, 0x73, 0xc2, 0xe6, 0xe2, 0x98, 0xdb,
+  0x9c, 0x1e, 0x32, 0x6a, 0x6c, 0x87, 0x29, 0x50, 0x7a, 0x58, 0x26, 0x50,
+  0x01, 0xd1, 0xe6, 0xf0, 0x95, 0x10, 0x76, 0x93, 0x90, 0xe8, 0x24, 0x77,
+  0x87, 0x65, 0xd9, 0x3a, 0x73, 0x4c, 0x88, 0x48, 0x24, 0x1e, 0x54, 0x9d,
+  0x93, 0xe0, 0x3f, 0xef, 0x9b, 0xce, 0x8b, 0xfc, 0xe0, 0x29, 0x14, 0xdd,
+  0xa5, 0x80, 0x0d, 0x2e, 0x75, 0x0a, 0x89, 0x14, 0x59, 0xf0, 0xe2, 0x8e,
+  0x5c, 0xdf, 0xfb, 0x2e, 0xf0, 0xb2, 0xd1, 0xaa, 0xa4, 0x35, 0x52, 0xa8,
+  0xd2, 0xfd, 0x93, 0xcd, 0x12, 0xe8, 0x2d, 0xa1, 0x81, 0xa5, 0x3b, 0xce,
+  0x00, 0xec, 0xd3, 0x1b, 0x60, 0xb9, 0xff, 0xe2, 0x1a, 0x68, 0x88, 0x43,
+  0x93, 0xe0, 0xf8, 0x3e, 0x0e, 0x7a, 0x51, 0x9f, 0x07, 0xd0, 0x2f, 0x73,
+  0x3a, 0xec, 0x3c, 0x4e, 0xff, 0x95, 0x8b, 0xd4, 0xf7, 0xf1, 0x7c, 0xe9,
+  0x4a, 0xc4, 0x61, 0x45, 0x23, 0x8d, 0xd4, 0xae, 0x88, 0x01, 0x90, 0x98,
+  0xfa, 0x4c, 0xe4, 0xf7, 0xb0, 0xaa, 0xc1, 0xe9, 0xa4, 0x60, 0x7a, 0xc4,
+  0x77, 0xd2, 0x16, 0xa2, 0xf2, 0xc3, 0xc5, 0x4d, 0xfd, 0x12, 0x40, 0xa9,
+  0x33, 0xe1, 0x33, 0xe9, 0x07, 0x49, 0xd1, 0x4f, 0x26, 0xf0, 0x87, 0xad,
+  0xcb, 0x29, 0xa8, 0xc2, 0xa2, 0xf9, 0x12, 0x23, 0x78, 0x93, 0x74, 0x2e,
+  0xde, 0x32, 0x33, 0xe3, 0x55, 0x99, 0x0e, 0x17, 0xa6, 0x1c, 0x96, 0xb7,
+  0xbf, 0xdc, 0x4a, 0x7d, 0xd2, 0x5c, 0x57, 0x59, 0x28, 0xc3, 0x7b, 0xfe,
+  0x49, 0x76, 0xec, 0x82, 0xeb, 0x82, 0x04, 0xee, 0x93, 0x50, 0x25, 0xe2,
+  0xb0, 0x99, 0xd9, 0x80, 0xe9, 0x9a, 0x65, 0xc4, 0xf7, 0x36, 0x79, 0xc3,
+  0xb7, 0x97, 0x97, 0x0b, 0xca, 0x8c, 0x04, 0x19, 0xfe, 0x92, 0x75, 0xb4,
+  0x70, 0x61, 0x80, 0x46, 0x31, 0x14, 0x9e, 0xe1, 0x11, 0xba, 0x43, 0x2e,
+  0x97, 0xa7, 0xd4, 0x59, 0x66, 0x43, 0xbb, 0x8b, 0x54, 0x83, 0xf6, 0x97,
+  0xad, 0x3a, 0xef, 0x26, 0x48, 0x73, 0xcb, 0xbb, 0x2e, 0xca, 0x07, 0x87,
+  0x3f, 0xe8, 0xbc, 0x86, 0xc3, 0xbe, 0x37, 0x77, 0xf1, 0x0c, 0xa7, 0x71,
+  0x20, 0xed, 0x9a, 0xd1, 0x3b, 0x47, 0x17, 0x13, 0x9b, 0xfc, 0x3b, 0x31,
+  0x78, 0x45, 0xc6, 0xe8, 0xbd, 0xd6, 0x4f, 0xd4, 0x32, 0xfa, 0xd0, 0x8f,
+  0x10, 0xbd, 0x6f, 0xe3, 0xe3, 0x78, 0xb9, 0x32, 0xbc, 0xb7, 0x1f, 0xcb,
+  0x8d, 0x61, 0x3e, 0xe8
+};
+unsigned int random_bin_len = 1000;
@@ -0,0 +1,34 @@
+390c8c7d7247342cd8100f2f6f770d65d670e58e0351d8ae8e4f6eac342f
+c231b7b08716eb3fc12896b96223177494287733c28ee8ba53bdb56b8824
+577d53ecc28a70a61c7510a1cd89216ca16cffcaea4987477e86dbccb970
+46fc2e18384e51d820c5c3ef80053a88ae3996de50e801865b3698654ebf
+5200a5fa0939b99d7a1d7b282bf8234041f35487d86c669fccbfe0e73d7e
+7320ad0a757003241e752210a924798ef86d43f27cf2d0613031dcb5d8d2
+ef1b321fcead377f6261e547d85d8eec7f26e23219072f7955d0f8f66dcd
+1e54c201c787e892d8f94f61976f1d1fa01d19f4501d295f232278ce3d7e
+1429d6a18568a07a87ca4399eaa12504ea33256d8743b2237dbd9150e09a
+04993544873b364f8b906baf6887fa801a2fd88d1601aa428652e2da0439
+264c12bd4bdc41159dba14b76b7f34b5d04f79535ad30c5baad27f885137
+c313f07166ebb39c74720c62cca88e238eb3cca90e3b855b871337deb0a0
+df3bc5618216df0064badc23a9a03f999ed1a7ce974162d7c2599acf009b
+926bdca4eee2e26df2562b91ab2f789e73654b0c177df325e9d463c4fdcc
+7c4b0236d9705aed197f3ee944eda2e2dae451f3e6847e8df87a8ce12792
+788baba329464d76c44e6d20d4d0a9eed41f69d7c70ac2f403b498c7d670
+f9708bdff80ec7accf54ef410dc90d2adb45ec5d1985c2a76ce8a7acc28e
+d78129f0091ab37223140f7e660a4e7a40f23a6fee83bc553a539f370d9f
+c0cb65267c349a3d15b1dbbd23ae06d7fa36ddb9eb4ede5a8af7eedf89a5
+7d2c8ee67cedc2ac0efda65df96cb584ae8f8d05612b7bd0fa7bf3fbe508
+2f9671cf7c9cbcf2b0d9a9b4e88a9c80763d62a13d5e626ef78d90336397
+74b85b9a07408c171b9540fb340691f0f5e1ae5e1a81f43a21cdfb251b4d
+4c9b2b7f3cd573c2e6e298db9c1e326a6c8729507a58265001d1e6f09510
+769390e824778765d93a734c8848241e549d93e03fef9bce8bfce02914dd
+a5800d2e750a891459f0e28e5cdffb2ef0b2d1aaa43552a8d2fd93cd12e8
+2da181a53bce00ecd31b60b9ffe21a68884393e0f83e0e7a519f07d02f73
+3aec3c4eff958bd4f7f17ce94ac46145238dd4ae88019098fa4ce4f7b0aa
+c1e9a4607ac477d216a2f2c3c54dfd1240a933e133e90749d14f26f087ad
+cb29a8c2a2f912237893742ede3233e355990e17a61c96b7bfdc4a7dd25c
+575928c37bfe4976ec82eb8204ee935025e2b099d980e99a65c4f73679c3
+b797970bca8c0419fe9275b47061804631149ee111ba432e97a7d4596643
+bb8b5483f697ad3aef264873cbbb2eca07873fe8bc86c3be3777f10ca771
+20ed9ad13b4717139bfc3b317845c6e8bdd64fd432fad08f10bd6fe3e378
+b932bcb71fcb8d613ee8
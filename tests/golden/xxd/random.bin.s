@@ -0,0 +1,62 @@
+0000000a: 0f2f 6f77 0d65 d670 e58e 0351 d8ae 8e4f  ./ow.e.p...Q...O
+0000001a: 6eac 342f c231 b7b0 8716 eb3f c128 96b9  n.4/.1.....?.(..
+0000002a: 6223 1774 9428 7733 c28e e8ba 53bd b56b  b#.t.(w3....S..k
+0000003a: 8824 577d 53ec c28a 70a6 1c75 10a1 cd89  .$W}S...p..u....
+0000004a: 216c a16c ffca ea49 8747 7e86 dbcc b970  !l.l...I.G~....p
+0000005a: 46fc 2e18 384e 51d8 20c5 c3ef 8005 3a88  F...8NQ. .....:.
+0000006a: ae39 96de 50e8 0186 5b36 9865 4ebf 5200  .9..P...[6.eN.R.
+0000007a: a5fa 0939 b99d 7a1d 7b28 2bf8 2340 41f3  ...9..z.{(+.#@A.
+0000008a: 5487 d86c 669f ccbf e0e7 3d7e 7320 ad0a  T..lf.....=~s ..
+0000009a: 7570 0324 1e75 2210 a924 798e f86d 43f2  up.$.u"..$y..mC.
+000000aa: 7cf2 d061 3031 dcb5 d8d2 ef1b 321f cead  |..a01......2...
+000000ba: 377f 6261 e547 d85d 8eec 7f26 e232 1907  7.ba.G.]...&.2..
+000000ca: 2f79 55d0 f8f6 6dcd 1e54 c201 c787 e892  /yU...m..T......
+000000da: d8f9 4f61 976f 1d1f a01d 19f4 501d 295f  ..Oa.o......P.)_
+000000ea: 2322 78ce 3d7e 1429 d6a1 8568 a07a 87ca  #"x.=~.)...h.z..
+000000fa: 4399 eaa1 2504 ea33 256d 8743 b223 7dbd  C...%..3%m.C.#}.
+0000010a: 9150 e09a 0499 3544 873b 364f 8b90 6baf  .P....5D.;6O..k.
+0000011a: 6887 fa80 1a2f d88d 1601 aa42 8652 e2da  h..../.....B.R..
+0000012a: 0439 264c 12bd 4bdc 4115 9dba 14b7 6b7f  .9&L..K.A.....k.
+0000013a: 34b5 d04f 7953 5ad3 0c5b aad2 7f88 5137  4..OySZ..[....Q7
+0000014a: c313 f071 66eb b39c 7472 0c62 cca8 8e23  ...qf...tr.b...#
+0000015a: 8eb3 cca9 0e3b 855b 8713 37de b0a0 df3b  .....;.[..7....;
+0000016a: c561 8216 df00 64ba dc23 a9a0 3f99 9ed1  .a....d..#..?...
+0000017a: a7ce 9741 62d7 c259 9acf 009b 926b dca4  ...Ab..Y.....k..
+0000018a: eee2 e26d f256 2b91 ab2f 789e 7365 4b0c  ...m.V+../x.seK.
+0000019a: 177d f325 e9d4 63c4 fdcc 7c4b 0236 d970  .}.%..c...|K.6.p
+000001aa: 5aed 197f 3ee9 44ed a2e2 dae4 51f3 e684  Z...>.D.....Q...
+000001ba: 7e8d f87a 8ce1 2792 788b aba3 2946 4d76  ~..z..'.x...)FMv
+000001ca: c44e 6d20 d4d0 a9ee d41f 69d7 c70a c2f4  .Nm ......i.....
+000001da: 03b4 98c7 d670 f970 8bdf f80e c7ac cf54  .....p.p.......T
+000001ea: ef41 0dc9 0d2a db45 ec5d 1985 c2a7 6ce8  .A...*.E.]....l.
+000001fa: a7ac c28e d781 29f0 091a b372 2314 0f7e  ......)....r#..~
+0000020a: 660a 4e7a 40f2 3a6f ee83 bc55 3a53 9f37  f.Nz@.:o...U:S.7
+0000021a: 0d9f c0cb 6526 7c34 9a3d 15b1 dbbd 23ae  ....e&|4.=....#.
+0000022a: 06d7 fa36 ddb9 eb4e de5a 8af7 eedf 89a5  ...6...N.Z......
+0000023a: 7d2c 8ee6 7ced c2ac 0efd a65d f96c b584  },..|......].l..
+0000024a: ae8f 8d05 612b 7bd0 fa7b f3fb e508 2f96  ....a+{..{..../.
+0000025a: 71cf 7c9c bcf2 b0d9 a9b4 e88a 9c80 763d  q.|...........v=
+0000026a: 62a1 3d5e 626e f78d 9033 6397 74b8 5b9a  b.=^bn...3c.t.[.
+0000027a: 0740 8c17 1b95 40fb 3406 91f0 f5e1 ae5e  .@....@.4......^
+0000028a: 1a81 f43a 21cd fb25 1b4d 4c9b 2b7f 3cd5  ...:!..%.ML.+.<.
+0000029a: 73c2 e6e2 98db 9c1e 326a 6c87 2950 7a58  s.......2jl.)PzX
+000002aa: 2650 01d1 e6f0 9510 7693 90e8 2477 8765  &P......v...$w.e
+000002ba: d93a 734c 8848 241e 549d 93e0 3fef 9bce  .:sL.H$.T...?...
+000002ca: 8bfc e029 14dd a580 0d2e 750a 8914 59f0  ...)......u...Y.
+000002da: e28e 5cdf fb2e f0b2 d1aa a435 52a8 d2fd  ..\........5R...
+000002ea: 93cd 12e8 2da1 81a5 3bce 00ec d31b 60b9  ....-...;.....`.
+000002fa: ffe2 1a68 8843 93e0 f83e 0e7a 519f 07d0  ...h.C...>.zQ...
+0000030a: 2f73 3aec 3c4e ff95 8bd4 f7f1 7ce9 4ac4  /s:.<N......|.J.
+0000031a: 6145 238d d4ae 8801 9098 fa4c e4f7 b0aa  aE#........L....
+0000032a: c1e9 a460 7ac4 77d2 16a2 f2c3 c54d fd12  ...`z.w......M..
+0000033a: 40a9 33e1 33e9 0749 d14f 26f0 87ad cb29  @.3.3..I.O&....)
+0000034a: a8c2 a2f9 1223 7893 742e de32 33e3 5599  .....#x.t..23.U.
+0000035a: 0e17 a61c 96b7 bfdc 4a7d d25c 5759 28c3  ........J}.\WY(.
+0000036a: 7bfe 4976 ec82 eb82 04ee 9350 25e2 b099  {.Iv.......P%...
+0000037a: d980 e99a 65c4 f736 79c3 b797 970b ca8c  ....e..6y.......
+0000038a: 0419 fe92 75b4 7061 8046 3114 9ee1 11ba  ....u.pa.F1.....
+0000039a: 432e 97a7 d459 6643 bb8b 5483 f697 ad3a  C....YfC..T....:
+000003aa: ef26 4873 cbbb 2eca 0787 3fe8 bc86 c3be  .&Hs......?.....
+000003ba: 3777 f10c a771 20ed 9ad1 3b47 1713 9bfc  7w...q ...;G....
+000003ca: 3b31 7845 c6e8 bdd6 4fd4 32fa d08f 10bd  ;1xE....O.2.....
+000003da: 6fe3 e378 b932 bcb7 1fcb 8d61 3ee8       o..x.2.....a>.
@@ -0,0 +1,28 @@
+00000000: 01010100 01101000 01100101 00100000 01110001 01110101  The qu
+00000006: 01101001 01100011 01101011 00100000 01100010 01110010  ick br
+0000000c: 01101111 01110111 01101110 00100000 01100110 01101111  own fo
+00000012: 01111000 00100000 01101010 01110101 01101101 01110000  x jump
+00000018: 01110011 00100000 01101111 01110110 01100101 01110010  s over
+0000001e: 00100000 01110100 01101000 01100101 00100000 01101100   the l
+00000024: 01100001 01111010 01111001 00100000 01100100 01101111  azy do
+0000002a: 01100111 00101110 00001010 01010100 01101000 01100101  g..The
+00000030: 00100000 01110001 01110101 01101001 01100011 01101011   quick
+00000036: 00100000 01100010 01110010 01101111 01110111 01101110   brown
+0000003c: 00100000 01100110 01101111 01111000 00100000 01101010   fox j
+00000042: 01110101 01101101 01110000 01110011 00100000 01101111  umps o
+00000048: 01110110 01100101 01110010 00100000 01110100 01101000  ver th
+0000004e: 01100101 00100000 01101100 01100001 01111010 01111001  e lazy
+00000054: 00100000 01100100 01101111 01100111 00101110 00001010   dog..
+0000005a: 01010100 01101000 01100101 00100000 01110001 01110101  The qu
+00000060: 01101001 01100011 01101011 00100000 01100010 01110010  ick br
+00000066: 01101111 01110111 01101110 00100000 01100110 01101111  own fo
+0000006c: 01111000 00100000 01101010 01110101 01101101 01110000  x jump
+00000072: 01110011 00100000 01101111 01110110 01100101 01110010  s over
+00000078: 00100000 01110100 01101000 01100101 00100000 01101100   the l
+0000007e: 01100001 01111010 01111001 00100000 01100100 01101111  azy do
+00000084: 01100111 00101110 00001010 00001001 01010100 01100001  g...Ta
+0000008a: 01100010 01110011 00101100 00001101 00001010 01000011  bs,..C
+00000090: 01010010 01001100 01000110 00100000 01100001 01101110  RLF an
+00000096: 01100100 00100000 00011011 01011011 00110000 01101101  d .[0m
+0000009c: 00100000 01100101 01110011 01100011 01100001 01110000   escap
+000000a2: 01100101 01110011 00001010                             es.
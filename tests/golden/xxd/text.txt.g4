@@ -0,0 +1,11 @@
+00000000: 54686520 71756963 6b206272 6f776e20  The quick brown 
+00000010: 666f7820 6a756d70 73206f76 65722074  fox jumps over t
+00000020: 6865206c 617a7920 646f672e 0a546865  he lazy dog..The
+00000030: 20717569 636b2062 726f776e 20666f78   quick brown fox
+00000040: 206a756d 7073206f 76657220 74686520   jumps over the 
+00000050: 6c617a79 20646f67 2e0a5468 65207175  lazy dog..The qu
+00000060: 69636b20 62726f77 6e20666f 78206a75  ick brown fox ju
+00000070: 6d707320 6f766572 20746865 206c617a  mps over the laz
+00000080: 7920646f 672e0a09 54616273 2c0d0a43  y dog...Tabs,..C
+00000090: 524c4620 616e6420 1b5b306d 20657363  RLF and .[0m esc
+000000a0: 61706573 0a                          apes.
@@ -0,0 +1,17 @@
+unsigned char text_txt[] = {
+  0x54, 0x68, 0x65, 0x20, 0x71, 0x75, 0x69, 0x63, 0x6b, 0x20, 0x62, 0x72,
+  0x6f, 0x77, 0x6e, 0x20, 0x66, 0x6f, 0x78, 0x20, 0x6a, 0x75, 0x6d, 0x70,
+  0x73, 0x20, 0x6f, 0x76, 0x65, 0x72, 0x20, 0x74, 0x68, 0x65, 0x20, 0x6c,
+  0x61, 0x7a, 0x79, 0x20, 0x64, 0x6f, 0x67, 0x2e, 0x0a, 0x54, 0x68, 0x65,
+  0x20, 0x71, 0x75, 0x69, 0x63, 0x6b, 0x20, 0x62, 0x72, 0x6f, 0x77, 0x6e,
+  0x20, 0x66, 0x6f, 0x78, 0x20, 0x6a, 0x75, 0x6d, 0x70, 0x73, 0x20, 0x6f,
+  0x76, 0x65, 0x72, 0x20, 0x74, 0x68, 0x65, 0x20, 0x6c, 0x61, 0x7a, 0x79,
+  0x20, 0x64, 0x6f, 0x67, 0x2e, 0x0a, 0x54, 0x68, 0x65, 0x20, 0x71, 0x75,
+  0x69, 0x63, 0x6b, 0x20, 0x62, 0x72, 0x6f, 0x77, 0x6e, 0x20, 0x66, 0x6f,
+  0x78, 0x20, 0x6a, 0x75, 0x6d, 0x70, 0x73, 0x20, 0x6f, 0x76, 0x65, 0x72,
+  0x20, 0x74, 0x68, 0x65, 0x20, 0x6c, 0x61, 0x7a, 0x79, 0x20, 0x64, 0x6f,
+  0x67, 0x2e, 0x0a, 0x09, 0x54, 0x61, 0x62, 0x73, 0x2c, 0x0d, 0x0a, 0x43,
+  0x52, 0x4c, 0x46, 0x20, 0x61, 0x6e, 0x64, 0x20, 0x1b, 0x5b, 0x30, 0x6d,
+  0x20, 0x65, 0x73, 0x63, 0x61, 0x70, 0x65, 0x73, 0x0a
+};
+unsigned int text_txt_len = 165;
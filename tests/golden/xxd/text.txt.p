@@ -0,0 +1,6 @@
+54686520717569636b2062726f776e20666f78206a756d7073206f766572
+20746865206c617a7920646f672e0a54686520717569636b2062726f776e
+20666f78206a756d7073206f76657220746865206c617a7920646f672e0a
+54686520717569636b2062726f776e20666f78206a756d7073206f766572
+20746865206c617a7920646f672e0a09546162732c0d0a43524c4620616e
+64201b5b306d20657363617065730a
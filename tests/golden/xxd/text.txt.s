@@ -0,0 +1,10 @@
+0000000a: 6272 6f77 6e20 666f 7820 6a75 6d70 7320  brown fox jumps 
+0000001a: 6f76 6572 2074 6865 206c 617a 7920 646f  over the lazy do
+0000002a: 672e 0a54 6865 2071 7569 636b 2062 726f  g..The quick bro
+0000003a: 776e 2066 6f78 206a 756d 7073 206f 7665  wn fox jumps ove
+0000004a: 7220 7468 6520 6c61 7a79 2064 6f67 2e0a  r the lazy dog..
+0000005a: 5468 6520 7175 6963 6b20 6272 6f77 6e20  The quick brown 
+0000006a: 666f 7820 6a75 6d70 7320 6f76 6572 2074  fox jumps over t
+0000007a: 6865 206c 617a 7920 646f 672e 0a09 5461  he lazy dog...Ta
+0000008a: 6273 2c0d 0a43 524c 4620 616e 6420 1b5b  bs,..CRLF and .[
+0000009a: 306d 2065 7363 6170 6573 0a              0m escapes.
@@ -0,0 +1,17 @@
+00000000: 00000000 00000000 00000000 00000000 00000000 00000000  ......
+00000006: 00000000 00000000 00000000 00000000 00000000 00000000  ......
+0000000c: 00000000 00000000 00000000 00000000 00000000 00000000  ......
+00000012: 00000000 00000000 00000000 00000000 00000000 00000000  ......
+00000018: 00000000 00000000 00000000 00000000 00000000 00000000  ......
+0000001e: 00000000 00000000 00000000 00000000 00000000 00000000  ......
+00000024: 00000000 00000000 00000000 00000000 00000000 00000000  ......
+0000002a: 00000000 00000000 00000000 00000000 00000000 00000000  ......
+00000030: 00000000 00000000 00000000 00000000 00000000 00000000  ......
+00000036: 00000000 00000000 00000000 00000000 00000000 00000000  ......
+0000003c: 00000000 00000000 00000000 00000000 00000000 00000000  ......
+00000042: 00000000 00000000 00000000 00000000 00000000 00000000  ......
+00000048: 00000000 00000000 00000000 00000000 00000000 00000000  ......
+0000004e: 00000000 00000000 00000000 00000000 00000000 00000000  ......
+00000054: 00000000 00000000 00000000 00000000 00000000 00000000  ......
+0000005a: 00000000 00000000 00000000 00000000 00000000 00000000  ......
+00000060: 00000000 00000000 00000000 00000000                    ....
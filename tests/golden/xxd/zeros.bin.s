@@ -0,0 +1,6 @@
+0000000a: 0000 0000 0000 0000 0000 0000 0000 0000  ................
+0000001a: 0000 0000 0000 0000 0000 0000 0000 0000  ................
+0000002a: 0000 0000 0000 0000 0000 0000 0000 0000  ................
+0000003a: 0000 0000 0000 0000 0000 0000 0000 0000  ................
+0000004a: 0000 0000 0000 0000 0000 0000 0000 0000  ................
+0000005a: 0000 0000 0000 0000 0000                 ..........
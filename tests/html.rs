@@ -0,0 +1,155 @@
+#![cfg(feature = "std")]
+
+mod common;
+
+use hexdump::annotate::{Annotation, Endian, Field};
+use hexdump::color::{Color, Style, Theme};
+use hexdump::html::HtmlPage;
+use hexdump::{ByteFormat, HexDumper, Preset, Squeeze};
+
+use common::LongestWrite;
+
+/// The text of the `<pre>` holding the dump.
+fn dump_text(page: &str) -> &str {
+    let start = page.find("<pre id=\"dump\"").unwrap();
+    let start = start + page[start..].find('>').unwrap() + 1;
+    &page[start..start + page[start..].find("</pre>").unwrap()]
+}
+
+/// Strips the tags from `html` and unescapes the entities.
+fn text(html: &str) -> String {
+    let mut out = String::new();
+    let mut in_tag = false;
+    for c in html.chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' => in_tag = false,
+            c if !in_tag => out.push(c),
+            _ => {}
+        }
+    }
+    out.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&amp;", "&")
+}
+
+#[test]
+fn lines_match_the_dump() {
+    let data: Vec<u8> = (0..=255).chain([0; 64]).chain(*b"<a href=\"&\">").collect();
+    for dumper in [
+        HexDumper::new(),
+        HexDumper::new()
+            .bytes_per_line(10)
+            .group_size(4)
+            .uppercase(true)
+            .base_address(0x8000_0000),
+        HexDumper::new().show_offset(false).placeholder('_'),
+        HexDumper::new().show_ascii(false),
+        HexDumper::new().preset(Preset::HexdumpCanonical),
+        HexDumper::new()
+            .squeeze(Squeeze::Always)
+            .squeeze_annotation(true)
+            .byte_format(ByteFormat::Binary)
+            .group_size(4)
+            .ascii_delimiters("<", ">"),
+    ] {
+        let page = HtmlPage::new().dumper(dumper.clone()).dump(&data);
+        assert_eq!(text(dump_text(&page)), format!("\n{}", dumper.dump(&data)));
+    }
+}
+
+#[test]
+fn self_contained() {
+    let page = HtmlPage::new()
+        .title("a <b> & c")
+        .dump(b"\x00 a\x01\x80\xff");
+    assert!(page.starts_with("<!DOCTYPE html>\n"));
+    assert!(page.ends_with("</html>\n"));
+    assert!(page.contains("<title>a &lt;b&gt; &amp; c</title>"));
+    assert!(!page.contains("http"));
+    assert!(!page.contains(" src="));
+    assert!(!page.contains("<link"));
+    assert_eq!(page.matches("<script>").count(), 1);
+    // Every byte is coloured by class.
+    for (class, byte) in [
+        "c0\">00", "c2\">20", "c1\">61", "c3\">01", "c4\">80", "c5\">ff",
+    ]
+    .iter()
+    .zip(0..)
+    {
+        assert!(page.contains(class), "byte {byte}");
+    }
+    assert!(page.contains("#dump .c5{color:#cd0000;font-weight:bold;}"));
+
+    let theme = Theme {
+        printable: Style::new().fg(Color::Rgb(1, 2, 3)).bg(Color::Ansi256(231)),
+        offset: Style::new().dim(),
+        ..Theme::DEFAULT
+    };
+    let page = HtmlPage::new()
+        .dumper(HexDumper::new().theme(theme))
+        .dump(b"a");
+    assert!(page.contains("#dump .c1{color:#010203;background:#ffffff;}"));
+    assert!(page.contains("#dump .off{opacity:.6;}"));
+}
+
+#[test]
+fn annotations() {
+    let mut data = vec![0u8; 64];
+    data[0x10..0x14].copy_from_slice(&0x1234_5678u32.to_be_bytes());
+    let page = HtmlPage::new()
+        .dumper(HexDumper::new().base_address(0x400))
+        .annotate(Annotation::field(0x410, Field::U32(Endian::Big), "size"))
+        .annotate(Annotation::new(0x3f0..0x402, "header"))
+        .annotate(Annotation::field(
+            0x43e,
+            Field::I32(Endian::Little),
+            "cut off",
+        ))
+        .annotate(Annotation::new(0x800..0x810, "elsewhere"))
+        .dump(&data);
+    let list = &page[page.find("<ol id=\"annotations\">").unwrap()..];
+    let items: Vec<&str> = list.lines().skip(1).take(4).collect();
+    assert_eq!(
+        items,
+        [
+            "<li data-start=\"16\" data-end=\"20\"><a href=\"#\">size</a> \
+             <span class=\"range\">0x410..0x414</span> \
+             <span class=\"value\">u32be = 305419896 (0x12345678)</span></li>",
+            "<li data-start=\"0\" data-end=\"2\"><a href=\"#\">header</a> \
+             <span class=\"range\">0x3f0..0x402</span></li>",
+            "<li data-start=\"62\" data-end=\"64\"><a href=\"#\">cut off</a> \
+             <span class=\"range\">0x43e..0x442</span> \
+             <span class=\"value\">i32le = ?</span></li>",
+            "<li class=\"out\"><a href=\"#\">elsewhere</a> \
+             <span class=\"range\">0x800..0x810</span></li>",
+        ]
+    );
+    assert!(!HtmlPage::new().dump(&data).contains("<aside>"));
+}
+
+#[test]
+fn squeezed_lines_keep_byte_indices() {
+    let mut data = vec![0u8; 64];
+    data.extend(b"tail");
+    let page = HtmlPage::new()
+        .dumper(HexDumper::new().squeeze(Squeeze::Always))
+        .dump(&data);
+    let dump = dump_text(&page);
+    assert_eq!(dump.matches("<span class=\"hex\"").count(), 2);
+    assert!(dump.contains("<span class=\"hex\" data-at=\"0\">"));
+    assert!(dump.contains("<span class=\"hex\" data-at=\"64\">"));
+    assert!(dump.contains("<span class=\"asc\" data-at=\"64\">"));
+    assert!(page.contains("data-len=\"68\" data-line=\"16\" data-radix=\"16\""));
+}
+
+#[test]
+fn written_a_few_lines_at_a_time() {
+    let data: Vec<u8> = (0..=255).cycle().take(1 << 20).collect();
+    let page = HtmlPage::new();
+    let mut out = LongestWrite::default();
+    page.write_to(&data, &mut out).unwrap();
+    assert_eq!(out.total, page.dump(&data).len());
+    assert!(out.longest < 1 << 20, "{}", out.longest);
+}
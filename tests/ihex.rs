@@ -0,0 +1,131 @@
+#![cfg(feature = "std")]
+
+mod common;
+
+use hexdump::ihex::{self, Encoder, IhexError, IhexFile, StartAddress};
+use hexdump::image::{AddressTooLarge, Image};
+
+use common::checksummed;
+
+/// Builds a record from its fields, with a correct checksum.
+fn record(offset: u16, kind: u8, data: &[u8]) -> String {
+    let mut fields = vec![data.len() as u8, (offset >> 8) as u8, offset as u8, kind];
+    fields.extend_from_slice(data);
+    checksummed(":", &fields, u8::wrapping_neg)
+}
+
+#[test]
+fn record_types() {
+    let text = [
+        record(0x0010, 0x00, b"low"),
+        record(0, 0x02, &[0x10, 0x00]),
+        // Wraps around the end of segment 0x1000.
+        record(0xfffe, 0x00, b"wrap"),
+        record(0, 0x03, &[0x12, 0x34, 0x00, 0x10]),
+        record(0, 0x04, &[0x00, 0x02]),
+        record(0xffff, 0x00, b"on"),
+        record(0, 0x01, &[]),
+        "garbage after the end\n".to_owned(),
+    ]
+    .concat();
+    let file = ihex::parse(&text).unwrap();
+    let image = &file.image;
+    assert_eq!(image.get(0x10), Some(b'l'));
+    assert_eq!(image.get(0x1fffe), Some(b'w'));
+    assert_eq!(image.get(0x1ffff), Some(b'r'));
+    assert_eq!(image.get(0x10000), Some(b'a'));
+    assert_eq!(image.get(0x10001), Some(b'p'));
+    // Linear offsets carry past 64 KiB.
+    assert_eq!(image.get(0x2ffff), Some(b'o'));
+    assert_eq!(image.get(0x30000), Some(b'n'));
+    assert_eq!(
+        file.start,
+        Some(StartAddress::Segment {
+            cs: 0x1234,
+            ip: 0x0010
+        })
+    );
+    assert_eq!(file.start.unwrap().linear(), 0x12350);
+    // Lower case, blank lines and no end record are fine.
+    let file = ihex::parse(":0100000041be\n\n").unwrap();
+    assert_eq!(file.image.get(0), Some(b'A'));
+}
+
+#[test]
+fn errors() {
+    let cases = [
+        ("0100000041BE", IhexError::MissingColon { line: 1 }),
+        (":01000000Z1BE", IhexError::InvalidHex { line: 1 }),
+        (":0100000041B", IhexError::InvalidHex { line: 1 }),
+        (":0200000041BE", IhexError::BadLength { line: 1 }),
+        (":00000001", IhexError::BadLength { line: 1 }),
+        (
+            ":0100000041BF",
+            IhexError::Checksum {
+                line: 1,
+                expected: 0xbe,
+                found: 0xbf,
+            },
+        ),
+        (":00000006FA", IhexError::UnknownType { line: 1, kind: 6 }),
+        (":0100000401FA", IhexError::BadLength { line: 1 }),
+    ];
+    for (text, expected) in cases {
+        assert_eq!(ihex::parse(text).unwrap_err(), expected, "{text}");
+    }
+    let text = [record(0, 0, b"abcd"), record(2, 0, b"x")].concat();
+    let err = ihex::parse(&text).unwrap_err();
+    assert_eq!(
+        err,
+        IhexError::Overlap {
+            line: 2,
+            address: 2
+        }
+    );
+    assert_eq!(
+        err.to_string(),
+        "line 2: data for address 0x2 is given twice"
+    );
+    assert_eq!(
+        ihex::parse(":0100000041BF").unwrap_err().to_string(),
+        "line 1: checksum is BF, expected BE"
+    );
+}
+
+#[test]
+fn round_trips() {
+    let mut image = Image::new();
+    image.insert(0x0000_fff0, &[0xaa; 40]).unwrap();
+    image.insert(0x2000_0000, b"ram").unwrap();
+    let file = IhexFile {
+        image,
+        start: Some(StartAddress::Linear(0x0000_fff1)),
+    };
+    let text = Encoder::new().record_len(32).encode(&file).unwrap();
+    let expected = [
+        record(0xfff0, 0x00, &[0xaa; 16]),
+        record(0, 0x04, &[0x00, 0x01]),
+        record(0x0000, 0x00, &[0xaa; 24]),
+        record(0, 0x04, &[0x20, 0x00]),
+        record(0x0000, 0x00, b"ram"),
+        record(0, 0x05, &[0x00, 0x00, 0xff, 0xf1]),
+        record(0, 0x01, &[]),
+    ]
+    .concat();
+    assert_eq!(text, expected);
+    assert_eq!(ihex::parse(&text).unwrap(), file);
+    let lower = Encoder::new().uppercase(false).encode(&file).unwrap();
+    assert_eq!(lower, lower.to_lowercase());
+    assert_eq!(ihex::parse(&lower).unwrap(), file);
+
+    let far = IhexFile {
+        image: Image::from_bytes(0xffff_ffff, *b"ab"),
+        start: None,
+    };
+    assert_eq!(
+        Encoder::new().encode(&far),
+        Err(AddressTooLarge {
+            address: 0x1_0000_0000
+        })
+    );
+}
@@ -0,0 +1,71 @@
+#![cfg(feature = "std")]
+
+use hexdump::image::{Image, Overlap, Segment};
+use hexdump::{HexDumper, Squeeze, TrailingOffset};
+
+#[test]
+fn inserts_and_merges() {
+    let mut image = Image::new();
+    image.insert(0x100, b"cd").unwrap();
+    image.insert(0x200, b"xyz").unwrap();
+    image.insert(0xfe, b"ab").unwrap();
+    image.insert(0x102, b"ef").unwrap();
+    image.insert(0x1f0, b"").unwrap();
+    assert_eq!(
+        image.segments(),
+        [
+            Segment {
+                address: 0xfe,
+                data: b"abcdef".to_vec()
+            },
+            Segment {
+                address: 0x200,
+                data: b"xyz".to_vec()
+            },
+        ]
+    );
+    // Filling the gap joins both neighbours.
+    image.insert(0x104, &[0; 0xfc]).unwrap();
+    assert_eq!(image.segments().len(), 1);
+    assert_eq!(image.len(), 0x105);
+    assert_eq!((image.start(), image.end()), (Some(0xfe), Some(0x203)));
+    assert_eq!(image.get(0x201), Some(b'y'));
+    assert_eq!(image.get(0x203), None);
+
+    assert_eq!(image.insert(0x202, b"!!"), Err(Overlap { address: 0x202 }));
+    assert_eq!(
+        image.insert(0xf0, &[1; 0x10]),
+        Err(Overlap { address: 0xfe })
+    );
+    assert_eq!(image.len(), 0x105);
+}
+
+#[test]
+fn flattens_with_fill() {
+    let mut image = Image::from_bytes(0x10, *b"ab");
+    image.insert(0x14, b"c").unwrap();
+    assert_eq!(image.to_bytes(0xff), b"ab\xff\xffc");
+    assert_eq!(Image::new().to_bytes(0), b"");
+    assert!(Image::from_bytes(5, Vec::new()).is_empty());
+}
+
+#[test]
+fn dumps_in_address_space() {
+    let mut image = Image::from_bytes(0x8000, vec![0u8; 0x40]);
+    image.insert(0x9003, b"tail").unwrap();
+    let dumper = HexDumper::new()
+        .squeeze(Squeeze::Always)
+        .trailing_offset(TrailingOffset::Always);
+    let first = dumper
+        .clone()
+        .base_address(0x8000)
+        .trailing_offset(TrailingOffset::Never)
+        .dump(&[0; 0x40]);
+    let second = dumper.clone().base_address(0x9003).dump(b"tail");
+    assert_eq!(
+        dumper.dump_image(&image),
+        format!("{first}-- gap: 4035 bytes --\n{second}")
+    );
+    assert!(first.contains("*\n"));
+    assert_eq!(dumper.dump_image(&Image::new()), "");
+}
@@ -0,0 +1,171 @@
+#![cfg(feature = "std")]
+
+mod common;
+
+use hexdump::annotate::{Annotation, Endian, Field, Value};
+use hexdump::json::{JsonLines, VERSION};
+use hexdump::{HexDumper, Squeeze};
+use serde_json::{json, Value as Json};
+
+use common::Trickle;
+
+fn parse(text: &str) -> Vec<Json> {
+    assert!(text.ends_with('\n'));
+    text.lines()
+        .map(|line| serde_json::from_str(line).unwrap())
+        .collect()
+}
+
+#[test]
+fn records_and_schema() {
+    let json = JsonLines::new()
+        .dumper(HexDumper::new().bytes_per_line(8).uppercase(true))
+        .dump(b"\"quoted\\\"\x00\xff");
+    let records = parse(&json);
+    assert_eq!(
+        records[0],
+        json!({"type": "header", "format": "hexdump", "version": VERSION, "bytes_per_line": 8})
+    );
+    assert_eq!(
+        records[1],
+        json!({"type": "line", "offset": 0, "hex": "2271756F7465645C", "ascii": "\"quoted\\", "annotations": []})
+    );
+    assert_eq!(records[2]["hex"], "2200FF");
+    assert_eq!(records[2]["ascii"], "\"..");
+    assert_eq!(
+        records[3],
+        json!({"type": "end", "offset": 11, "length": 11})
+    );
+
+    let empty = parse(
+        &JsonLines::new()
+            .dumper(HexDumper::new().base_address(0x100))
+            .dump(b""),
+    );
+    assert_eq!(empty.len(), 2);
+    assert_eq!(
+        empty[1],
+        json!({"type": "end", "offset": 0x100, "length": 0})
+    );
+}
+
+#[test]
+fn annotations_and_fields() {
+    let mut data = vec![0u8; 40];
+    data[14..18].copy_from_slice(&0xdead_beefu32.to_le_bytes());
+    data[30..38].copy_from_slice(&(-1.5f64).to_be_bytes());
+    let json = JsonLines::new()
+        .dumper(HexDumper::new().base_address(0x1000))
+        .annotate(Annotation::field(0x100e, Field::U32(Endian::Little), "crc"))
+        .annotate(Annotation::field(0x101e, Field::F64(Endian::Big), "scale"))
+        .annotate(Annotation::new(0x1000..0x1004, "magic \"M\""))
+        .annotate(Annotation::field(
+            0x1026,
+            Field::U32(Endian::Big),
+            "cut off",
+        ));
+    let text = json.dump(&data);
+    let records = parse(&text);
+    let labels = |i: usize| -> Vec<&str> {
+        records[i]["annotations"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|a| a["label"].as_str().unwrap())
+            .collect()
+    };
+    assert_eq!(labels(1), ["magic \"M\"", "crc"]);
+    assert_eq!(labels(2), ["crc", "scale"]);
+    assert_eq!(labels(3), ["scale", "cut off"]);
+    assert_eq!(
+        records[2]["annotations"][0],
+        json!({"label": "crc", "start": 0x100e, "end": 0x1012, "field": "u32le", "value": 0xdead_beefu32})
+    );
+    assert_eq!(records[3]["annotations"][0]["value"], -1.5);
+    assert_eq!(records[3]["annotations"][1]["value"], Json::Null);
+
+    // Streaming decodes fields across chunks the same way.
+    for size in [1, 5, 8192] {
+        let mut out = Vec::new();
+        let n = json
+            .dump_reader(Trickle::new(&data, size), &mut out)
+            .unwrap();
+        assert_eq!(n, 40);
+        assert_eq!(String::from_utf8(out).unwrap(), text, "chunks of {size}");
+    }
+}
+
+#[test]
+fn squeezes() {
+    let mut data = vec![0u8; 16 * 6];
+    data[..4].copy_from_slice(b"head");
+    data.extend_from_slice(b"tail");
+    let dumper = HexDumper::new().squeeze(Squeeze::Always);
+    let types = |json: &JsonLines| -> Vec<String> {
+        parse(&json.dump(&data))
+            .iter()
+            .map(|r| match r["type"].as_str().unwrap() {
+                "squeeze" => format!("squeeze {} {}", r["offset"], r["lines"]),
+                "line" => format!("line {}", r["offset"]),
+                other => other.to_owned(),
+            })
+            .collect()
+    };
+    let json = JsonLines::new().dumper(dumper.clone());
+    assert_eq!(
+        types(&json),
+        [
+            "header",
+            "line 0",
+            "line 16",
+            "squeeze 32 4",
+            "line 96",
+            "end"
+        ]
+    );
+    // Annotated lines are always written.
+    let json = json.annotate(Annotation::new(50..52, "flag"));
+    assert_eq!(
+        types(&json),
+        [
+            "header",
+            "line 0",
+            "line 16",
+            "squeeze 32 1",
+            "line 48",
+            "squeeze 64 2",
+            "line 96",
+            "end"
+        ]
+    );
+    let json = JsonLines::new().dumper(dumper.squeeze(Squeeze::After(5)));
+    assert_eq!(types(&json).len(), 9);
+
+    let long = vec![7u8; 100_000];
+    let json = JsonLines::new().dumper(HexDumper::new().squeeze(Squeeze::Always));
+    let mut out = Vec::new();
+    json.dump_reader(Trickle::new(&long, 999), &mut out)
+        .unwrap();
+    assert_eq!(String::from_utf8(out).unwrap(), json.dump(&long));
+}
+
+#[test]
+fn field_names() {
+    for field in [
+        Field::U8,
+        Field::I16(Endian::Big),
+        Field::F32(Endian::Little),
+    ] {
+        assert_eq!(field.name().parse::<Field>(), Ok(field));
+    }
+    assert_eq!("U64LE".parse::<Field>(), Ok(Field::U64(Endian::Little)));
+    assert!("u8le".parse::<Field>().is_err());
+    assert!("u24be".parse::<Field>().is_err());
+    assert_eq!(Field::I8.decode(b"\xfe"), Some(Value::Signed(-2)));
+    assert_eq!(
+        Field::I32(Endian::Big).decode(b"\xff\xff\xff\xfe!"),
+        Some(Value::Signed(-2))
+    );
+    assert_eq!(Field::U16(Endian::Big).decode(b"\x01"), None);
+    assert_eq!(Value::Float(0.25).to_string(), "0.25");
+}
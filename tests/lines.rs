@@ -0,0 +1,46 @@
+#![cfg(feature = "std")]
+
+use hexdump::HexDumper;
+
+fn sample(len: usize) -> Vec<u8> {
+    (0..len).map(|i| (i * 7 % 256) as u8).collect()
+}
+
+#[test]
+fn lines_join_to_dump() {
+    let dumper = HexDumper::new().bytes_per_line(10).group_size(4);
+    for len in [0, 1, 10, 11, 95] {
+        let data = sample(len);
+        let joined: String = dumper.lines(&data).map(|l| l + "\n").collect();
+        assert_eq!(joined, dumper.dump(&data));
+    }
+}
+
+#[test]
+fn reverse_matches_forward() {
+    let dumper = HexDumper::new();
+    let data = sample(70);
+    let forward: Vec<String> = dumper.lines(&data).collect();
+    let mut backward: Vec<String> = dumper.lines(&data).rev().collect();
+    backward.reverse();
+    assert_eq!(forward, backward);
+}
+
+#[test]
+fn nth_and_take() {
+    let dumper = HexDumper::new();
+    let data = sample(16 * 100 + 3);
+    let all: Vec<String> = dumper.lines(&data).collect();
+    assert_eq!(all.len(), 101);
+    assert_eq!(dumper.lines(&data).len(), 101);
+
+    let mut lines = dumper.lines(&data);
+    assert_eq!(lines.nth(40), Some(all[40].clone()));
+    assert_eq!(lines.next(), Some(all[41].clone()));
+    assert_eq!(lines.len(), 59);
+    assert_eq!(lines.nth(100), None);
+    assert_eq!(lines.next(), None);
+
+    let first: Vec<String> = dumper.lines(&data).take(3).collect();
+    assert_eq!(first, all[..3]);
+}
@@ -0,0 +1,102 @@
+//! Checks the tool presets. The `od` layouts are compared against output
+//! recorded from GNU `od`, see `tests/golden/od/cases.txt`.
+
+#![cfg(feature = "std")]
+
+use std::fs;
+use std::path::Path;
+
+use hexdump::{HexDumper, Preset, Squeeze, UnknownPreset};
+
+#[test]
+fn od_golden_corpus() {
+    let golden = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/golden");
+    let mut checked = 0;
+    for entry in fs::read_dir(golden.join("inputs")).unwrap() {
+        let path = entry.unwrap().path();
+        let file_name = path.file_name().unwrap().to_str().unwrap();
+        let data = fs::read(&path).unwrap();
+        for (case, preset) in [
+            ("hex", Preset::OdHex),
+            ("decimal", Preset::OdDecimal),
+            ("octal", Preset::OdOctal),
+        ] {
+            let expected =
+                fs::read_to_string(golden.join(format!("od/{file_name}.{case}"))).unwrap();
+            let dumper = HexDumper::new().preset(preset);
+            assert_eq!(dumper.dump(&data), expected, "od {case} {file_name}");
+
+            let mut streamed = Vec::new();
+            dumper.dump_reader(&data[..], &mut streamed).unwrap();
+            assert_eq!(streamed, expected.as_bytes(), "od {case} {file_name}");
+            checked += 1;
+        }
+    }
+    assert!(checked > 10);
+}
+
+#[test]
+fn hexdump_canonical() {
+    let dumper = HexDumper::new().preset(Preset::HexdumpCanonical);
+    assert_eq!(
+        dumper.dump(b"Hello, world!\n\x00\x01\xff"),
+        "00000000  48 65 6c 6c 6f 2c 20 77  6f 72 6c 64 21 0a 00 01  |Hello, world!...|\n\
+         00000010  ff                                                |.|\n\
+         00000011\n"
+    );
+    assert_eq!(dumper.dump(b""), "");
+}
+
+#[test]
+fn hexdump_canonical_squeezes_repeats() {
+    let mut data = vec![0u8; 64];
+    data.extend_from_slice(b"0123456789abcdef");
+    data.extend_from_slice(&[0u8; 36]);
+    let expected = "\
+00000000  00 00 00 00 00 00 00 00  00 00 00 00 00 00 00 00  |................|
+*
+00000040  30 31 32 33 34 35 36 37  38 39 61 62 63 64 65 66  |0123456789abcdef|
+00000050  00 00 00 00 00 00 00 00  00 00 00 00 00 00 00 00  |................|
+*
+00000070  00 00 00 00                                       |....|
+00000074
+";
+    let dumper = HexDumper::new().preset(Preset::HexdumpCanonical);
+    assert_eq!(dumper.dump(&data), expected);
+
+    let mut streamed = Vec::new();
+    dumper.dump_reader(&data[..], &mut streamed).unwrap();
+    assert_eq!(String::from_utf8(streamed).unwrap(), expected);
+}
+
+#[test]
+fn setters_after_preset_adjust_it() {
+    let dumper = HexDumper::new()
+        .preset(Preset::HexdumpCanonical)
+        .bytes_per_line(8)
+        .squeeze(Squeeze::Off);
+    assert_eq!(
+        dumper.dump(&[0; 9]),
+        "00000000  00 00 00 00 00 00 00 00  |........|\n\
+         00000008  00                       |.|\n\
+         00000009\n"
+    );
+}
+
+#[test]
+fn parses_names() {
+    for (name, preset) in [
+        ("xxd", Preset::Xxd),
+        ("xxd-plain", Preset::XxdPlain),
+        ("xxd-bits", Preset::XxdBits),
+        ("hexdump", Preset::HexdumpCanonical),
+        ("od-hex", Preset::OdHex),
+        ("od-decimal", Preset::OdDecimal),
+        ("od-octal", Preset::OdOctal),
+    ] {
+        assert_eq!(name.parse(), Ok(preset));
+    }
+    let err = "XXD".parse::<Preset>().unwrap_err();
+    assert_eq!(err, UnknownPreset);
+    assert!(err.to_string().contains("xxd-plain"));
+}
@@ -0,0 +1,202 @@
+#![cfg(feature = "std")]
+
+mod common;
+
+use std::io;
+
+use hexdump::color::ColorChoice;
+use hexdump::search::{Pattern, PatternError, SearchDumper, Searcher};
+use hexdump::HexDumper;
+
+use common::Trickle;
+
+/// Pushes `data` to a searcher in chunks of `size` bytes.
+fn chunked(pattern: &Pattern, data: &[u8], size: usize) -> Vec<std::ops::Range<u64>> {
+    let mut searcher = Searcher::new(pattern);
+    let mut found = Vec::new();
+    for chunk in data.chunks(size) {
+        found.extend(searcher.push(chunk));
+        assert!(found.iter().all(|m| m.start < searcher.searched()));
+    }
+    found.extend(searcher.finish());
+    found
+}
+
+fn haystack() -> Vec<u8> {
+    let mut data: Vec<u8> = (0..20_000u32).map(|i| (i * 31 % 251) as u8).collect();
+    for at in [0, 8188, 8192, 12_345, 16_381, 19_996] {
+        data[at..at + 4].copy_from_slice(b"MZ\x90\x00");
+    }
+    data
+}
+
+#[test]
+fn hex_patterns() {
+    let pattern = Pattern::hex("4D 5A ?? ?0").unwrap();
+    let expected: Vec<_> = [0, 8188, 8192, 12_345, 16_381, 19_996]
+        .iter()
+        .map(|&at| at..at + 4)
+        .collect();
+    let data = haystack();
+    assert_eq!(pattern.find_iter(&data).collect::<Vec<_>>(), expected);
+    assert_eq!(Pattern::hex("4d5a9000").unwrap().find(&data), Some(0..4));
+    assert_eq!(
+        Pattern::hex("?? ??").unwrap().find_iter(b"abcde").count(),
+        2
+    );
+    assert_eq!(Pattern::hex("?1").unwrap().find(b"\x10\x21"), Some(1..2));
+
+    assert_eq!(Pattern::hex("").unwrap_err(), PatternError::Empty);
+    assert_eq!(
+        Pattern::hex("4d 5").unwrap_err(),
+        PatternError::UnpairedNibble
+    );
+    assert_eq!(
+        Pattern::hex("4d zz").unwrap_err(),
+        PatternError::InvalidChar {
+            index: 3,
+            found: 'z'
+        }
+    );
+}
+
+#[test]
+fn strings() {
+    let data = b"\xffhello\xffh\0e\0l\0l\0o\0\xff\0h\0e\0l\0l\0o";
+    assert_eq!(Pattern::text("hello").find(data), Some(1..6));
+    assert_eq!(Pattern::utf16le("hello").find(data), Some(7..17));
+    assert_eq!(Pattern::utf16be("hello").find(data), Some(18..28));
+    assert_eq!(Pattern::bytes(Vec::new()).find(data), None);
+}
+
+#[test]
+fn streams_across_chunks() {
+    let data = haystack();
+    let pattern = Pattern::hex("4D 5A ?? ?0").unwrap();
+    let expected: Vec<_> = pattern
+        .find_iter(&data)
+        .map(|m| m.start as u64..m.end as u64)
+        .collect();
+    for size in [1, 2, 3, 7, 4096, 8191, 100_000] {
+        assert_eq!(chunked(&pattern, &data, size), expected, "chunks of {size}");
+    }
+    let found: Vec<_> = pattern
+        .find_reader(Trickle::new(&data, 5))
+        .collect::<io::Result<_>>()
+        .unwrap();
+    assert_eq!(found, expected);
+}
+
+#[cfg(feature = "regex")]
+#[test]
+fn regex() {
+    let data = b"id=17; id=4096; name=x; id=;id=2";
+    let pattern = Pattern::regex(r"id=\d+").unwrap();
+    let expected = [0..5, 7..14, 28..32];
+    assert_eq!(pattern.find_iter(data).collect::<Vec<_>>(), expected);
+    let expected: Vec<_> = expected
+        .iter()
+        .map(|m| m.start as u64..m.end as u64)
+        .collect();
+    for size in [1, 2, 5, 100] {
+        assert_eq!(chunked(&pattern, data, size), expected, "chunks of {size}");
+    }
+    // Greedy matches are not cut at chunk boundaries.
+    let pattern = Pattern::regex("a+").unwrap();
+    assert_eq!(chunked(&pattern, b"baaaaaab", 2), vec![1..7]);
+    // Empty matches do not repeat.
+    let pattern = Pattern::regex("x*").unwrap();
+    assert_eq!(pattern.find_iter(b"ab").count(), 3);
+    assert_eq!(chunked(&pattern, b"ab", 1).len(), 3);
+    // Matches longer than the limit are cut where chunks end.
+    let pattern = Pattern::regex("a+").unwrap().max_len(4);
+    assert_eq!(chunked(&pattern, b"aaaaaaaaaa", 1)[0], 0..4);
+    assert!(matches!(
+        Pattern::regex("(").unwrap_err(),
+        PatternError::Regex(_)
+    ));
+    // Dumps count and mark only the matches with bytes in them.
+    let search = SearchDumper::new(Pattern::regex("a*").unwrap());
+    let mut out = Vec::new();
+    assert_eq!(search.dump_reader(&b"xaayba"[..], &mut out).unwrap(), 2);
+    let text = String::from_utf8(out).unwrap();
+    assert_eq!(
+        text.lines().last().unwrap(),
+        format!("{:13}^^ ^^{:7}^^{:34}^^{:2}^", "", "", "", "")
+    );
+}
+
+#[test]
+fn dumps_with_context() {
+    let mut data = vec![0u8; 256];
+    data[0x45..0x48].copy_from_slice(b"key");
+    data[0xe0] = b'k';
+    data[0xfe..].copy_from_slice(b"ke");
+    let dumper = HexDumper::new();
+    let lines = |data: &[u8], from: usize, to: usize| {
+        dumper
+            .clone()
+            .base_address(from as u64)
+            .dump(&data[from..to])
+    };
+    let search = SearchDumper::new(Pattern::text("key"));
+    // Without colour, carets mark the matched bytes.
+    let carets = format!("{:25}^^ ^^ ^^{:32}^^^\n", "", "");
+    let around = |before: usize, after: usize| {
+        format!(
+            "{}{carets}{}",
+            lines(&data, before, 0x50),
+            lines(&data, 0x50, after)
+        )
+    };
+    assert_eq!(search.dump(&data), around(0, 0x100));
+    assert_eq!(search.clone().context(0).dump(&data), around(0x40, 0x50));
+    let search = search.context(1);
+    assert_eq!(search.dump(&data), around(0x30, 0x60));
+    // A match across a line boundary shows both lines.
+    let first = around(0x30, 0x60);
+    data[0x9e..0xa1].copy_from_slice(b"key");
+    let text = search.dump(&data);
+    assert_eq!(
+        text,
+        format!(
+            "{first}--\n{}{:52}^^ ^^{:17}^^\n{}{:10}^^{:48}^\n{}",
+            lines(&data, 0x80, 0xa0),
+            "",
+            "",
+            lines(&data, 0xa0, 0xb0),
+            "",
+            "",
+            lines(&data, 0xb0, 0xc0)
+        )
+    );
+    let mut out = Vec::new();
+    assert_eq!(
+        search
+            .dump_reader(Trickle::new(&data, 3), &mut out)
+            .unwrap(),
+        2
+    );
+    assert_eq!(String::from_utf8(out).unwrap(), text);
+}
+
+#[test]
+fn highlights_matches() {
+    let data = b"say hello to hell and back";
+    let search = SearchDumper::new(Pattern::text("hell")).dumper(
+        HexDumper::new()
+            .color(ColorChoice::Always)
+            .bytes_per_line(8),
+    );
+    let text = search.dump(data);
+    let lines: Vec<&str> = text.lines().collect();
+    assert_eq!(lines.len(), 4);
+    // `hell` spans the first two lines; the yellow background marks it.
+    assert!(lines[0].contains("\x1b[30;43m68 65 6c 6c"), "{text:?}");
+    assert!(lines[1].contains("\x1b[30;43m68 65 6c\x1b[0m"), "{text:?}");
+    assert!(
+        lines[2].starts_with("00000010: \x1b[30;43m6c\x1b[0m"),
+        "{text:?}"
+    );
+    assert!(!lines[3].contains("\x1b[30;43m"), "{text:?}");
+}
@@ -0,0 +1,78 @@
+#![cfg(feature = "serde")]
+
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, PartialEq, Debug)]
+struct Fixture {
+    #[serde(with = "hexdump::serde")]
+    payload: Vec<u8>,
+    #[serde(with = "hexdump::serde")]
+    header: [u8; 4],
+    #[serde(with = "hexdump::serde::compact")]
+    key: Vec<u8>,
+}
+
+fn fixture(payload: Vec<u8>) -> Fixture {
+    Fixture {
+        payload,
+        header: *b"HDR\x01",
+        key: vec![0x01, 0xab],
+    }
+}
+
+#[test]
+fn round_trips() {
+    for len in [0, 1, 16, 17, 100] {
+        let value = fixture((0..len).map(|i| (i * 7) as u8).collect());
+        let json = serde_json::to_string(&value).unwrap();
+        assert_eq!(serde_json::from_str::<Fixture>(&json).unwrap(), value);
+    }
+}
+
+#[test]
+fn reads_hand_edited_dumps() {
+    // A different layout, a squeezed run, a stale ASCII gutter and
+    // spaced-out compact hex all read back.
+    let json = r#"{
+        "payload": "00000000  00 00 00 00 00 00 00 00  00 00 00 00 00 00 00 00  |................|\n*\n00000020  ff 41  |xx|\n00000022\n",
+        "header": "0: 48 44 52 02",
+        "key": "01 ab\n"
+    }"#;
+    let value: Fixture = serde_json::from_str(json).unwrap();
+    let mut payload = vec![0; 32];
+    payload.extend_from_slice(&[0xff, 0x41]);
+    assert_eq!(value.payload, payload);
+    assert_eq!(value.header, *b"HDR\x02");
+    assert_eq!(value.key, [0x01, 0xab]);
+}
+
+#[test]
+fn reports_bad_input() {
+    let bad_hex = r#"{"payload": "00000000: 4g", "header": "0: 00 00 00 00", "key": ""}"#;
+    let err = serde_json::from_str::<Fixture>(bad_hex).unwrap_err();
+    assert!(
+        err.to_string()
+            .starts_with("line 1, column 12: invalid hex digit"),
+        "{err}"
+    );
+
+    let short = r#"{"payload": "", "header": "0: 00 00", "key": ""}"#;
+    let err = serde_json::from_str::<Fixture>(short).unwrap_err();
+    assert!(
+        err.to_string()
+            .starts_with("invalid length 2, expected as many bytes as the field holds"),
+        "{err}"
+    );
+
+    let odd = r#"{"payload": "", "header": "0: 00 00 00 00", "key": "abc"}"#;
+    assert!(serde_json::from_str::<Fixture>(odd).is_err());
+
+    // A typo in an offset must not make the reader fill terabytes.
+    let far = r#"{"payload": "7fff00000000: 41", "header": "0: 00 00 00 00", "key": ""}"#;
+    let err = serde_json::from_str::<Fixture>(far).unwrap_err();
+    assert!(
+        err.to_string()
+            .starts_with("line 1, column 1: offset too large"),
+        "{err}"
+    );
+}
@@ -0,0 +1,80 @@
+use core::fmt::Write;
+
+use hexdump::{HexDump, HexDumper, Preset, SliceWriter, Squeeze};
+
+/// Only `core::fmt::Write` paths, so this runs without the `std` feature.
+#[test]
+fn writes_without_std() {
+    let mut data = [0u8; 40];
+    data[..7].copy_from_slice(b"hexdump");
+    let dumper = HexDumper::new()
+        .preset(Preset::HexdumpCanonical)
+        .squeeze(Squeeze::Always);
+    let mut buf = [0u8; 256];
+    let n = dumper.dump_into(&data, &mut buf).unwrap();
+    assert_eq!(
+        core::str::from_utf8(&buf[..n]).unwrap(),
+        "\
+00000000  68 65 78 64 75 6d 70 00  00 00 00 00 00 00 00 00  |hexdump.........|
+00000010  00 00 00 00 00 00 00 00  00 00 00 00 00 00 00 00  |................|
+00000020  00 00 00 00 00 00 00 00                           |........|
+00000028
+"
+    );
+
+    let mut buf = [0u8; 64];
+    let mut out = SliceWriter::new(&mut buf);
+    HexDumper::new()
+        .bytes_per_line(4)
+        .write_line(&mut out, 0x10, b"ab\x00")
+        .unwrap();
+    assert_eq!(out.as_str(), "00000010: 61 62 00     |ab.|");
+    out.clear();
+    write!(out, "{:X}", HexDump(b"\xca\xfe")).unwrap();
+    assert_eq!(out.as_str(), "CAFE");
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn dump_into_matches_dump() {
+    let mut data = vec![0u8; 100];
+    data.extend(0..=255);
+    let dumper = HexDumper::new().preset(Preset::HexdumpCanonical);
+    let expected = dumper.dump(&data);
+    let mut buf = vec![0u8; expected.len()];
+    let n = dumper.dump_into(&data, &mut buf).unwrap();
+    assert_eq!(n, expected.len());
+    assert_eq!(buf, expected.as_bytes());
+}
+
+#[test]
+fn overflow_keeps_whole_writes() {
+    let mut buf = [0u8; 8];
+    let mut out = SliceWriter::new(&mut buf);
+    out.write_str("abcde").unwrap();
+    assert!(out.write_str("fghi").is_err());
+    assert_eq!(out.as_str(), "abcde");
+    out.write_str("fgh").unwrap();
+    assert_eq!(out.into_written(), b"abcdefgh");
+
+    let too_small = HexDumper::new().dump_into(&[0; 32], &mut [0u8; 100]);
+    assert!(too_small.is_err());
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn line_at_a_time_into_a_small_buffer() {
+    let dumper = HexDumper::new().bytes_per_line(8);
+    let data = b"firmware image header";
+    let mut buf = [0u8; 48];
+    let mut out = SliceWriter::new(&mut buf);
+    let mut text = String::new();
+    for (i, line) in data.chunks(8).enumerate() {
+        out.clear();
+        dumper.write_line(&mut out, i as u64 * 8, line).unwrap();
+        assert!(!out.is_empty());
+        text.push_str(out.as_str());
+        text.push('\n');
+    }
+    assert_eq!(text, dumper.dump(data));
+}
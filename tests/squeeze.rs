@@ -0,0 +1,119 @@
+#![cfg(feature = "std")]
+
+use hexdump::undump::undump;
+use hexdump::{HexDumper, Squeeze};
+
+/// Runs of 1, 2 and 5 repeated zero lines between distinct lines.
+fn sample() -> Vec<u8> {
+    let mut data = Vec::new();
+    for (i, run) in [2, 3, 6].into_iter().enumerate() {
+        data.extend(std::iter::repeat_n(0u8, 4 * run));
+        data.extend_from_slice(&[b'a' + i as u8; 4]);
+    }
+    data.extend_from_slice(&[0; 4]);
+    data
+}
+
+fn dumper(squeeze: Squeeze) -> HexDumper {
+    HexDumper::new()
+        .bytes_per_line(4)
+        .show_ascii(false)
+        .squeeze(squeeze)
+}
+
+#[test]
+fn policies() {
+    let data = sample();
+    let lines = |text: String| text.lines().map(str::to_owned).collect::<Vec<_>>();
+
+    let off = lines(dumper(Squeeze::Off).dump(&data));
+    assert_eq!(off.len(), data.len() / 4);
+
+    let always = lines(dumper(Squeeze::Always).dump(&data));
+    assert_eq!(
+        always,
+        [
+            "00000000: 00 00 00 00",
+            "*",
+            "00000008: 61 61 61 61",
+            "0000000c: 00 00 00 00",
+            "*",
+            "00000018: 62 62 62 62",
+            "0000001c: 00 00 00 00",
+            "*",
+            "00000034: 63 63 63 63",
+            "00000038: 00 00 00 00",
+        ]
+    );
+
+    let after = lines(dumper(Squeeze::After(2)).dump(&data));
+    assert_eq!(
+        after,
+        [
+            "00000000: 00 00 00 00",
+            "00000004: 00 00 00 00",
+            "00000008: 61 61 61 61",
+            "0000000c: 00 00 00 00",
+            "*",
+            "00000018: 62 62 62 62",
+            "0000001c: 00 00 00 00",
+            "*",
+            "00000034: 63 63 63 63",
+            "00000038: 00 00 00 00",
+        ]
+    );
+}
+
+#[test]
+fn annotation_counts_collapsed_lines() {
+    let text = dumper(Squeeze::Always)
+        .squeeze_annotation(true)
+        .dump(&sample());
+    let stars: Vec<&str> = text.lines().filter(|l| l.starts_with('*')).collect();
+    assert_eq!(
+        stars,
+        [
+            "* (1 identical line)",
+            "* (2 identical lines)",
+            "* (5 identical lines)"
+        ]
+    );
+}
+
+#[test]
+fn run_at_end_of_input() {
+    let text = dumper(Squeeze::Always).dump(&[7; 12]);
+    assert_eq!(text, "00000000: 07 07 07 07\n*\n");
+}
+
+#[test]
+fn streaming_matches_slice() {
+    let mut data = sample();
+    // Long enough for runs to straddle the streaming chunk boundary.
+    data.extend(std::iter::repeat_n(0u8, 20_000));
+    data.extend_from_slice(b"tail");
+    for squeeze in [
+        Squeeze::Off,
+        Squeeze::Always,
+        Squeeze::After(3),
+        Squeeze::After(10_000),
+    ] {
+        for annotate in [false, true] {
+            let dumper = dumper(squeeze).squeeze_annotation(annotate);
+            let mut streamed = Vec::new();
+            dumper.dump_reader(&data[..], &mut streamed).unwrap();
+            assert_eq!(String::from_utf8(streamed).unwrap(), dumper.dump(&data));
+        }
+    }
+}
+
+#[test]
+fn annotated_dumps_undump() {
+    let mut data = sample();
+    data.extend_from_slice(b"end!");
+    let text = dumper(Squeeze::Always)
+        .show_ascii(true)
+        .squeeze_annotation(true)
+        .dump(&data);
+    assert_eq!(undump(&text).unwrap(), data);
+}
@@ -0,0 +1,140 @@
+#![cfg(feature = "std")]
+
+mod common;
+
+use hexdump::image::{AddressTooLarge, Image};
+use hexdump::srec::{self, Encoder, SrecError, SrecFile};
+
+use common::checksummed;
+
+/// Builds a record from its fields, with a correct checksum.
+fn record(kind: u8, address: u32, address_len: usize, data: &[u8]) -> String {
+    let mut fields = vec![(address_len + data.len() + 1) as u8];
+    fields.extend_from_slice(&address.to_be_bytes()[4 - address_len..]);
+    fields.extend_from_slice(data);
+    checksummed(&format!("S{kind}"), &fields, |sum| !sum)
+}
+
+#[test]
+fn record_types() {
+    let text = [
+        record(0, 0, 2, b"boot.bin"),
+        record(1, 0x1000, 2, b"s1"),
+        record(2, 0x12_3456, 3, b"s2"),
+        record(3, 0x8000_0000, 4, b"s3"),
+        record(4, 0, 2, b"reserved"),
+        record(5, 3, 2, &[]),
+        record(7, 0x8000_0004, 4, &[]),
+    ]
+    .concat();
+    let file = srec::parse(&text).unwrap();
+    assert_eq!(file.header, b"boot.bin");
+    assert_eq!(file.image.get(0x1001), Some(b'1'));
+    assert_eq!(file.image.get(0x12_3456), Some(b's'));
+    assert_eq!(file.image.get(0x8000_0001), Some(b'3'));
+    assert_eq!(file.image.segments().len(), 3);
+    assert_eq!(file.start, Some(0x8000_0004));
+    // A 24-bit count, and no count or termination at all.
+    let text = [record(1, 0, 2, b"a"), record(6, 1, 3, &[])].concat();
+    assert!(srec::parse(&text).is_ok());
+    let file = srec::parse(&record(1, 0, 2, b"a")).unwrap();
+    assert_eq!(file.start, None);
+}
+
+#[test]
+fn errors() {
+    let cases = [
+        ("X1040000410A", SrecError::InvalidType { line: 1 }),
+        ("S", SrecError::InvalidType { line: 1 }),
+        ("S1040000410G", SrecError::InvalidHex { line: 1 }),
+        ("S1050000410A", SrecError::BadLength { line: 1 }),
+        ("S3040000410A", SrecError::BadLength { line: 1 }),
+        (
+            "S1040000410B",
+            SrecError::Checksum {
+                line: 1,
+                expected: 0xba,
+                found: 0x0b,
+            },
+        ),
+    ];
+    for (text, expected) in cases {
+        assert_eq!(srec::parse(text).unwrap_err(), expected, "{text}");
+    }
+    let text = [record(1, 0, 2, b"ab"), record(5, 2, 2, &[])].concat();
+    let err = srec::parse(&text).unwrap_err();
+    assert_eq!(
+        err,
+        SrecError::Count {
+            line: 2,
+            expected: 1,
+            found: 2
+        }
+    );
+    assert_eq!(err.to_string(), "line 2: record count is 2, expected 1");
+    let text = [record(1, 0x10, 2, b"ab"), record(1, 0x11, 2, b"c")].concat();
+    assert_eq!(
+        srec::parse(&text).unwrap_err(),
+        SrecError::Overlap {
+            line: 2,
+            address: 0x11
+        }
+    );
+}
+
+#[test]
+fn round_trips() {
+    let mut image = Image::new();
+    image.insert(0x00f0, &[0x55; 20]).unwrap();
+    image.insert(0x0200, b"end").unwrap();
+    let file = SrecFile {
+        header: b"v1".to_vec(),
+        image,
+        start: Some(0x0100),
+    };
+    let text = Encoder::new().encode(&file).unwrap();
+    let expected = [
+        record(0, 0, 2, b"v1"),
+        record(1, 0x00f0, 2, &[0x55; 16]),
+        record(1, 0x0100, 2, &[0x55; 4]),
+        record(1, 0x0200, 2, b"end"),
+        record(5, 3, 2, &[]),
+        record(9, 0x0100, 2, &[]),
+    ]
+    .concat();
+    assert_eq!(text, expected);
+    assert_eq!(srec::parse(&text).unwrap(), file);
+
+    // Record types widen with the addresses.
+    let wide = SrecFile {
+        image: Image::from_bytes(0x1_0000, *b"x"),
+        ..SrecFile::default()
+    };
+    let text = Encoder::new().record_len(250).encode(&wide).unwrap();
+    assert_eq!(
+        text,
+        [
+            record(0, 0, 2, b""),
+            record(2, 0x1_0000, 3, b"x"),
+            record(5, 1, 2, &[]),
+            record(8, 0, 3, &[]),
+        ]
+        .concat()
+    );
+    let wide = SrecFile {
+        start: Some(0x0100_0000),
+        ..SrecFile::default()
+    };
+    let text = Encoder::new().encode(&wide).unwrap();
+    assert!(text.ends_with(&record(7, 0x0100_0000, 4, &[])));
+    assert_eq!(srec::parse(&text).unwrap(), wide);
+
+    let far = SrecFile {
+        image: Image::from_bytes(1 << 32, *b"a"),
+        ..SrecFile::default()
+    };
+    assert_eq!(
+        Encoder::new().encode(&far),
+        Err(AddressTooLarge { address: 1 << 32 })
+    );
+}
@@ -0,0 +1,46 @@
+#![cfg(feature = "std")]
+
+mod common;
+
+use std::io::{self, Read};
+
+use hexdump::HexDumper;
+
+use common::Trickle;
+
+fn sample(len: usize) -> Vec<u8> {
+    (0..len).map(|i| (i * 31 % 251) as u8).collect()
+}
+
+#[test]
+fn matches_slice_output() {
+    for dumper in [
+        HexDumper::new(),
+        HexDumper::new().bytes_per_line(7).group_size(3),
+        HexDumper::new().bytes_per_line(32).show_ascii(false),
+    ] {
+        for len in [0, 1, 7, 16, 17, 8192, 20_000] {
+            let data = sample(len);
+            let mut out = Vec::new();
+            let n = dumper
+                .dump_reader(Trickle::uneven(&data, 7), &mut out)
+                .unwrap();
+            assert_eq!(n, len as u64);
+            assert_eq!(String::from_utf8(out).unwrap(), dumper.dump(&data));
+        }
+    }
+}
+
+#[test]
+fn read_errors_propagate() {
+    struct Broken;
+    impl Read for Broken {
+        fn read(&mut self, _: &mut [u8]) -> io::Result<usize> {
+            Err(io::Error::other("device gone"))
+        }
+    }
+    let err = HexDumper::new()
+        .dump_reader(Broken, io::sink())
+        .unwrap_err();
+    assert_eq!(err.to_string(), "device gone");
+}
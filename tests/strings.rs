@@ -0,0 +1,168 @@
+#![cfg(feature = "std")]
+
+mod common;
+
+use std::io;
+
+use hexdump::color::ColorChoice;
+use hexdump::strings::{Encoding, Found, Strings};
+use hexdump::HexDumper;
+
+use common::Trickle;
+
+fn found(offset: u64, len: u64, encoding: Encoding, text: &str) -> Found {
+    Found {
+        offset,
+        len,
+        encoding,
+        text: text.to_owned(),
+    }
+}
+
+fn utf16le(text: &str) -> Vec<u8> {
+    text.encode_utf16().flat_map(u16::to_le_bytes).collect()
+}
+
+fn utf32be(text: &str) -> Vec<u8> {
+    text.chars()
+        .flat_map(|c| (c as u32).to_be_bytes())
+        .collect()
+}
+
+/// Strings in every encoding, separated by bytes that end them all.
+fn sample() -> Vec<u8> {
+    let mut data = vec![0xff; 3];
+    data.extend_from_slice(b"GNU C\x07");
+    data.extend_from_slice("größe\x00".as_bytes());
+    data.push(0xff);
+    data.extend(utf16le("Élan"));
+    data.extend_from_slice(&[0xff, 0xff]);
+    data.extend(utf32be("RSDS"));
+    data.extend_from_slice(b"\xff\x01ab\xff");
+    data
+}
+
+#[test]
+fn encodings_and_lengths() {
+    let data = sample();
+    assert_eq!(
+        Strings::new().find(&data),
+        [found(3, 5, Encoding::Ascii, "GNU C")]
+    );
+    let all = Strings::new().encodings(&Encoding::ALL).find(&data);
+    assert_eq!(
+        all,
+        [
+            found(3, 5, Encoding::Ascii, "GNU C"),
+            found(9, 7, Encoding::Utf8, "größe"),
+            found(18, 8, Encoding::Utf16Le, "Élan"),
+            found(28, 16, Encoding::Utf32Be, "RSDS"),
+        ]
+    );
+    // ASCII alone stops at the first byte above 0x7f.
+    let ascii = Strings::new().min_len(2).find(&data);
+    assert_eq!(ascii[1], found(9, 2, Encoding::Ascii, "gr"));
+    assert_eq!(ascii.last(), Some(&found(46, 2, Encoding::Ascii, "ab")));
+    assert_eq!(
+        Strings::new()
+            .min_len(6)
+            .encodings(&Encoding::ALL)
+            .find(&data),
+        []
+    );
+    assert_eq!(
+        Strings::new().min_len(0).find(b"\x00a\x00"),
+        [found(1, 1, Encoding::Ascii, "a")]
+    );
+    assert_eq!(Encoding::Utf16Be.to_string(), "utf-16be");
+}
+
+#[test]
+fn rejects_noise() {
+    // Control characters, invalid and truncated UTF-8 end strings.
+    let data = b"abc\tdef\xc3(ghi\xe2\x82jkl\xed\xa0\x80mno";
+    let texts: Vec<_> = Strings::new()
+        .min_len(3)
+        .encodings(&[Encoding::Utf8])
+        .find(data)
+        .into_iter()
+        .map(|found| found.text)
+        .collect();
+    assert_eq!(texts, ["abc", "def", "(ghi", "jkl", "mno"]);
+    // Wide characters outside ASCII and Latin-1 letters are too likely to
+    // be numbers.
+    let numbers: Vec<u8> = [0x0101u16, 0x0400, 0x0300, 0x00d7, 0x0102]
+        .iter()
+        .flat_map(|n| n.to_le_bytes())
+        .collect();
+    let wide = Strings::new()
+        .min_len(1)
+        .encodings(&[Encoding::Utf16Le])
+        .find(&numbers);
+    assert_eq!(wide, []);
+}
+
+#[test]
+fn streams_in_order() {
+    let mut data = Vec::new();
+    for i in 0..3000u32 {
+        data.extend_from_slice(&[0xff, 0xfe]);
+        if i % 3 == 0 {
+            data.extend(utf16le(&format!("wide {i}")));
+        } else {
+            data.extend_from_slice(format!("narrow {i}").as_bytes());
+        }
+    }
+    let strings = Strings::new().encodings(&[Encoding::Ascii, Encoding::Utf16Le]);
+    let expected = strings.find(&data);
+    assert_eq!(expected.len(), 3000);
+    assert!(expected.windows(2).all(|w| w[0].offset < w[1].offset));
+    assert_eq!(
+        expected[3],
+        found(14 + 10 + 10 + 2, 12, Encoding::Utf16Le, "wide 3")
+    );
+    for size in [1, 7, 8192] {
+        let streamed: Vec<_> = strings
+            .find_reader(Trickle::new(&data, size))
+            .collect::<io::Result<_>>()
+            .unwrap();
+        assert_eq!(streamed, expected, "chunks of {size}");
+    }
+    // A scanner holds back strings until those before them are complete.
+    let mut scanner = strings.scanner();
+    assert_eq!(scanner.push(b"\x00l\x00o\x00n\x00g\x00 \x00"), []);
+    assert_eq!(scanner.pending_from(), 1);
+    assert_eq!(scanner.push(b"\x00"), []);
+    let found = scanner.finish();
+    assert_eq!(found.iter().map(|f| f.offset).collect::<Vec<_>>(), [1]);
+}
+
+#[test]
+fn reports() {
+    let mut data = vec![0u8; 40];
+    data[14..30].copy_from_slice(b"/lib/ld-linux.so");
+    let mut out = Vec::new();
+    let count = Strings::new()
+        .dumper(HexDumper::new().base_address(0x400))
+        .lines(true)
+        .report(Trickle::new(&data, 5), &mut out)
+        .unwrap();
+    assert_eq!(count, 1);
+    let dumper = HexDumper::new().base_address(0x400);
+    let expected = format!(
+        "0000040e  ascii     /lib/ld-linux.so\n  {}  {}",
+        dumper.dump(&data[..16]),
+        dumper.base_address(0x410).dump(&data[16..32]),
+    );
+    assert_eq!(String::from_utf8(out).unwrap(), expected);
+
+    // The string's bytes are highlighted when colour is on.
+    let mut out = Vec::new();
+    Strings::new()
+        .dumper(HexDumper::new().color(ColorChoice::Always))
+        .lines(true)
+        .report(&data[..], &mut out)
+        .unwrap();
+    let text = String::from_utf8(out).unwrap();
+    assert!(text.contains("\x1b[30;43m2f 6c"));
+}
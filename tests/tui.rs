@@ -0,0 +1,285 @@
+#![cfg(feature = "tui")]
+
+use hexdump::tui::{KeyCode, KeyEvent, KeyModifiers, Viewer};
+use hexdump::{HexDumper, Preset};
+
+fn press(viewer: &mut Viewer, keys: &str) {
+    for c in keys.chars() {
+        let code = match c {
+            '\n' => KeyCode::Enter,
+            c => KeyCode::Char(c),
+        };
+        viewer.key(KeyEvent::new(code, KeyModifiers::NONE));
+    }
+}
+
+fn special(viewer: &mut Viewer, code: KeyCode) {
+    viewer.key(KeyEvent::new(code, KeyModifiers::NONE));
+}
+
+/// Removes colour escape codes.
+fn plain(row: &str) -> String {
+    let mut out = String::new();
+    let mut chars = row.chars();
+    while let Some(c) = chars.next() {
+        if c == '\x1b' {
+            chars.by_ref().find(|&c| c == 'm');
+        } else {
+            out.push(c);
+        }
+    }
+    out
+}
+
+fn sample() -> Vec<u8> {
+    (0..1000u32).map(|i| (i * 7 % 256) as u8).collect()
+}
+
+#[test]
+fn draws_dump_lines_and_status() {
+    let data = sample();
+    let mut viewer = Viewer::new(data.clone(), "sample");
+    viewer.resize(80, 10);
+    let screen = viewer.screen();
+    assert_eq!(screen.len(), 10);
+    let expected = HexDumper::new().dump(&data[..96]);
+    let rows: Vec<String> = screen[..6].iter().map(|row| plain(row)).collect();
+    assert_eq!(rows, expected.lines().collect::<Vec<_>>());
+    // The cursor byte is drawn in its own style.
+    assert!(screen[0].contains("\x1b[30;47m00"));
+    assert!(plain(&screen[6]).starts_with(" sample  0x00000000 / 0x000003e8 (0%)  u8 0"));
+    assert_eq!(
+        plain(&screen[9]),
+        "q quit  : goto  / search  n/N next/previous  g/G start/end"
+    );
+}
+
+#[test]
+fn narrows_lines_to_fit() {
+    let mut viewer = Viewer::new(sample(), "sample").dumper(HexDumper::new().preset(Preset::Xxd));
+    viewer.resize(40, 8);
+    let rows: Vec<String> = viewer.screen()[..4].iter().map(|row| plain(row)).collect();
+    assert!(
+        rows.iter().all(|row| row.chars().count() <= 40),
+        "{rows:#?}"
+    );
+    // Whole groups of two bytes.
+    assert!(rows[1].starts_with("00000008: "), "{rows:#?}");
+}
+
+#[test]
+fn moves_and_scrolls() {
+    let mut viewer = Viewer::new(sample(), "sample");
+    viewer.resize(80, 10);
+    special(&mut viewer, KeyCode::Down);
+    press(&mut viewer, "ll");
+    assert_eq!(viewer.cursor(), 18);
+    special(&mut viewer, KeyCode::End);
+    assert_eq!(viewer.cursor(), 31);
+    special(&mut viewer, KeyCode::PageDown);
+    assert_eq!(viewer.cursor(), 31 + 6 * 16);
+    press(&mut viewer, "G");
+    assert_eq!(viewer.cursor(), 999);
+    let screen = viewer.screen();
+    assert!(plain(&screen[5]).starts_with("000003e0: "));
+    special(&mut viewer, KeyCode::Down);
+    assert_eq!(viewer.cursor(), 999);
+    press(&mut viewer, "g");
+    assert_eq!(viewer.cursor(), 0);
+    assert!(plain(&viewer.screen()[0]).starts_with("00000000: "));
+    press(&mut viewer, "q");
+    assert!(viewer.is_done());
+}
+
+#[test]
+fn goto_prompt() {
+    let mut viewer = Viewer::new(sample(), "sample");
+    viewer.resize(80, 10);
+    press(&mut viewer, ":0x40");
+    assert_eq!(plain(&viewer.screen()[9]), "goto: 0x40_");
+    press(&mut viewer, "\n");
+    assert_eq!(viewer.cursor(), 0x40);
+    press(&mut viewer, ":+8\n:-0x10\n");
+    assert_eq!(viewer.cursor(), 0x38);
+    press(&mut viewer, ":zz\n");
+    assert_eq!(viewer.cursor(), 0x38);
+    assert_eq!(plain(&viewer.screen()[9]), "not an offset: zz");
+    press(&mut viewer, ":99999\n");
+    assert_eq!(viewer.cursor(), 999);
+}
+
+#[test]
+fn search() {
+    let mut data = vec![0u8; 300];
+    data[10..14].copy_from_slice(b"\x89PNG");
+    data[200..204].copy_from_slice(b"\x89PNG");
+    let mut viewer = Viewer::new(data, "image");
+    viewer.resize(80, 10);
+    press(&mut viewer, "/PNG\n");
+    assert_eq!(viewer.cursor(), 11);
+    // Matches are highlighted.
+    assert!(viewer.screen()[0].contains("\x1b[30;43m4e 47"));
+    press(&mut viewer, "n");
+    assert_eq!(viewer.cursor(), 201);
+    press(&mut viewer, "n");
+    assert_eq!(viewer.cursor(), 11);
+    assert_eq!(plain(&viewer.screen()[9]), "search wrapped");
+    press(&mut viewer, "N");
+    assert_eq!(viewer.cursor(), 201);
+    press(&mut viewer, "/89 50\n");
+    assert_eq!(viewer.cursor(), 10);
+    press(&mut viewer, "G/?9 5? 4e\n");
+    assert_eq!(viewer.cursor(), 10);
+    press(&mut viewer, "/\"ab\"\n");
+    assert_eq!(viewer.cursor(), 10);
+    assert_eq!(plain(&viewer.screen()[9]), "pattern not found");
+}
+
+#[test]
+fn values_under_the_cursor() {
+    let mut data = vec![0x00, 0x00, 0xc0, 0x3f, 0, 0, 0, 0];
+    data.extend(1_700_000_000u32.to_le_bytes());
+    data.extend([0xff, 0xfe]);
+    let mut viewer = Viewer::new(data, "values");
+    viewer.resize(200, 10);
+    let screen = viewer.screen();
+    assert_eq!(
+        plain(&screen[7]),
+        "LE  u16 0  u32 1069547520  u64 1069547520  f32 1.5  f64 5.28426686e-315  \
+         time 2003-11-23 00:32:00"
+    );
+    assert_eq!(
+        plain(&screen[8]),
+        "BE  u16 0  u32 49215  u64 211376815472640  f32 6.8965e-41  \
+         f64 1.04434022852356e-309  time 1970-01-01 13:40:15"
+    );
+    press(&mut viewer, ":8\n");
+    assert!(plain(&viewer.screen()[7]).ends_with("time 2023-11-14 22:13:20"));
+    press(&mut viewer, ":12\n");
+    let screen = viewer.screen();
+    assert!(plain(&screen[6])
+        .trim_end()
+        .ends_with("u8 255 (-1)  0b11111111"));
+    assert_eq!(
+        plain(&screen[7]),
+        "LE  u16 65279 (-257)  u32 -  u64 -  f32 -  f64 -  time -"
+    );
+}
+
+#[test]
+fn opens_files() {
+    let path = std::env::temp_dir().join(format!("hexdump-tui-{}", std::process::id()));
+    std::fs::write(&path, b"mapped").unwrap();
+    let mut viewer = Viewer::open(&path).unwrap();
+    viewer.resize(80, 6);
+    assert!(plain(&viewer.screen()[0]).ends_with("|mapped|"));
+    std::fs::write(&path, b"").unwrap();
+    let mut viewer = Viewer::open(&path).unwrap();
+    viewer.resize(80, 6);
+    assert_eq!(plain(&viewer.screen()[0]), "");
+    press(&mut viewer, "Gl");
+    assert_eq!(viewer.cursor(), 0);
+    std::fs::remove_file(&path).unwrap();
+}
+
+#[test]
+fn edits_hex_and_text() {
+    let mut viewer = Viewer::new(b"abcdef".to_vec(), "edit").editable(true);
+    viewer.resize(80, 6);
+    // Viewing only: hex digits do nothing.
+    let mut readonly = Viewer::new(b"abcdef".to_vec(), "edit");
+    press(&mut readonly, "ff");
+    assert!(!readonly.buffer().is_modified());
+
+    press(&mut viewer, "4");
+    assert_eq!(viewer.buffer().get(0), Some(0x41));
+    assert_eq!(viewer.cursor(), 0);
+    press(&mut viewer, "2");
+    assert_eq!(viewer.buffer().slice(0..6), b"Bbcdef");
+    assert_eq!(viewer.cursor(), 1);
+    assert!(plain(&viewer.screen()[2]).starts_with(" edit [+]  OVR hex  0x00000001 / 0x00000006"));
+
+    press(&mut viewer, "i2d");
+    special(&mut viewer, KeyCode::Tab);
+    press(&mut viewer, "XY");
+    special(&mut viewer, KeyCode::Esc);
+    assert_eq!(viewer.buffer().slice(0..10), b"B-XYbcdef");
+    assert_eq!(viewer.cursor(), 4);
+    press(&mut viewer, "x");
+    special(&mut viewer, KeyCode::Backspace);
+    assert_eq!(viewer.buffer().slice(0..10), b"B-Xcdef");
+    assert_eq!(viewer.cursor(), 3);
+    // Overwriting just past the last byte appends.
+    press(&mut viewer, "Gi0a");
+    assert_eq!(viewer.buffer().slice(0..10), b"B-Xcdef\n");
+    assert_eq!(viewer.cursor(), 8);
+
+    // A byte typed as two digits is undone in one step.
+    press(&mut viewer, "u");
+    assert_eq!(viewer.buffer().slice(0..10), b"B-Xcdef");
+    assert_eq!(viewer.cursor(), 7);
+    press(&mut viewer, "u");
+    assert_eq!(viewer.buffer().slice(0..10), b"B-XYcdef");
+    viewer.key(KeyEvent::new(KeyCode::Char('r'), KeyModifiers::CONTROL));
+    assert_eq!(viewer.buffer().slice(0..10), b"B-Xcdef");
+    press(&mut viewer, "UU");
+    assert_eq!(viewer.buffer().slice(0..10), b"B-Xcdef\n");
+    assert_eq!(plain(&viewer.screen()[5]), "nothing to redo");
+
+    // Quitting asks first, and saving has nowhere to go.
+    press(&mut viewer, "q");
+    assert!(!viewer.is_done());
+    assert_eq!(
+        plain(&viewer.screen()[5]),
+        "unsaved changes: w saves, q again quits without saving"
+    );
+    press(&mut viewer, "w");
+    assert_eq!(
+        plain(&viewer.screen()[5]),
+        "not saved: the buffer has no file to save to"
+    );
+    press(&mut viewer, "qq");
+    assert!(viewer.is_done());
+}
+
+#[test]
+fn saves_edits() {
+    let path = std::env::temp_dir().join(format!("hexdump-tui-edit-{}", std::process::id()));
+    std::fs::write(&path, b"v1.0").unwrap();
+    let mut viewer = Viewer::open(&path).unwrap().editable(true);
+    viewer.resize(80, 6);
+    press(&mut viewer, "l32");
+    viewer.key(KeyEvent::new(KeyCode::Char('s'), KeyModifiers::CONTROL));
+    assert_eq!(plain(&viewer.screen()[5]), "saved 4 bytes");
+    assert_eq!(std::fs::read(&path).unwrap(), b"v2.0");
+    press(&mut viewer, "G");
+    special(&mut viewer, KeyCode::Tab);
+    press(&mut viewer, "1");
+    special(&mut viewer, KeyCode::Tab);
+    std::fs::write(&path, b"v3.00").unwrap();
+    press(&mut viewer, "w");
+    assert_eq!(
+        plain(&viewer.screen()[5]),
+        "not saved: the file changed on disk since it was opened"
+    );
+    assert_eq!(std::fs::read(&path).unwrap(), b"v3.00");
+    std::fs::remove_file(&path).unwrap();
+}
+
+#[test]
+fn binary_usage_errors() {
+    for args in [
+        &["-c", "0"][..],
+        &["-c", "4097"],
+        &["-c", "0x10000000000"],
+        &["--preset", "bogus"],
+        &["-g", "+2"],
+    ] {
+        let output = std::process::Command::new(env!("CARGO_BIN_EXE_hexdump-tui"))
+            .args(args)
+            .output()
+            .unwrap();
+        assert_eq!(output.status.code(), Some(2), "{args:?}");
+        assert!(String::from_utf8_lossy(&output.stderr).starts_with("hexdump-tui: "));
+    }
+}
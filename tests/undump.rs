@@ -0,0 +1,185 @@
+#![cfg(feature = "std")]
+
+use std::fs;
+use std::path::Path;
+
+use hexdump::undump::{undump, UndumpError, Undumper};
+use hexdump::xxd::{Xxd, XxdMode};
+use hexdump::{HexDumper, OffsetBase, Preset, Squeeze, TrailingOffset};
+
+fn inputs() -> Vec<Vec<u8>> {
+    let dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/golden/inputs");
+    let mut inputs: Vec<Vec<u8>> = fs::read_dir(dir)
+        .unwrap()
+        .map(|e| fs::read(e.unwrap().path()).unwrap())
+        .collect();
+    inputs.push(b"cafe babe dead beef".to_vec());
+    inputs.push(b"ab                ".to_vec());
+    // Gutters starting with spaces and then looking like hex.
+    inputs.push(b"  ab".to_vec());
+    inputs.push(b"  12".to_vec());
+    inputs.push(b"0123456789abcdef  ab  12".to_vec());
+    inputs
+}
+
+#[test]
+fn round_trips_every_layout() {
+    let dumpers = [
+        HexDumper::new(),
+        HexDumper::new()
+            .bytes_per_line(7)
+            .group_size(3)
+            .uppercase(true),
+        HexDumper::new().show_ascii(false),
+        HexDumper::new().preset(Preset::Xxd),
+        HexDumper::new().preset(Preset::Xxd).group_size(4),
+        HexDumper::new().preset(Preset::HexdumpCanonical),
+        HexDumper::new().preset(Preset::OdHex),
+    ];
+    for data in inputs() {
+        for dumper in &dumpers {
+            let text = dumper.dump(&data);
+            assert_eq!(undump(&text).unwrap(), data, "{text}");
+        }
+        for (preset, base) in [
+            (Preset::OdDecimal, OffsetBase::Decimal),
+            (Preset::OdOctal, OffsetBase::Octal),
+        ] {
+            let text = HexDumper::new().preset(preset).dump(&data);
+            let parsed = Undumper::new().offset_base(base).parse(&text).unwrap();
+            assert_eq!(parsed, data);
+        }
+        let plain = Xxd::new().mode(XxdMode::Plain).columns(7).dump(&data);
+        assert_eq!(Undumper::new().plain(true).parse(&plain).unwrap(), data);
+    }
+}
+
+#[test]
+fn trimmed_gutters() {
+    let text = "00000000: 7879 2020 2020  xy\n";
+    assert_eq!(undump(text).unwrap(), b"xy    ");
+    // A full line's gutter that looks like hex once its spaces are gone.
+    let text = "\
+00000000: 3031 3233 3435 3637 3839 6162 6364 6566  0123456789abcdef
+00000010: 2020 6162 2020 2020 2020 2020 2020 2020    ab
+";
+    let mut expected = b"0123456789abcdef  ab".to_vec();
+    expected.resize(32, b' ');
+    assert_eq!(undump(text).unwrap(), expected);
+}
+
+#[test]
+fn lines_are_placed_by_offset() {
+    let text = "\
+00000010: 6768
+00000000: 6162 6364  abcd
+";
+    let mut expected = b"abcd".to_vec();
+    expected.resize(0x10, 0);
+    expected.extend_from_slice(b"gh");
+    assert_eq!(undump(text).unwrap(), expected);
+}
+
+#[test]
+fn origin_skips_leading_gap() {
+    let text = Xxd::new().seek(3).dump(b"0123456789");
+    assert_eq!(Undumper::new().origin(3).parse(&text).unwrap(), b"3456789");
+    assert_eq!(&undump(&text).unwrap()[..], b"\0\0\x003456789");
+    assert_eq!(
+        Undumper::new().origin(4).parse(&text),
+        Err(UndumpError::OffsetBeforeOrigin { line: 1, column: 1 })
+    );
+}
+
+#[test]
+fn far_offsets_are_refused() {
+    let too_large = Err(UndumpError::OffsetTooLarge { line: 1, column: 1 });
+    assert_eq!(undump("ffffffffffffffff: 00\n"), too_large);
+    assert_eq!(undump("7fff00000000: 4142  AB\n"), too_large);
+    assert_eq!(
+        Undumper::new()
+            .origin(0x7fff_0000_0000)
+            .parse("7fff00000000: 4142  AB\n"),
+        Ok(b"AB".to_vec())
+    );
+
+    let text = "00000000: 4142  AB\n*\n7fff00000000: 4142  AB\n";
+    assert_eq!(
+        undump(text),
+        Err(UndumpError::OffsetTooLarge { line: 3, column: 1 })
+    );
+    assert_eq!(
+        Undumper::new()
+            .max_len(4)
+            .parse("00000000: 4142\n00000002: 4344\n"),
+        Ok(b"ABCD".to_vec())
+    );
+    assert_eq!(
+        Undumper::new()
+            .max_len(4)
+            .parse("00000000: 4142\n00000003: 4344\n"),
+        Err(UndumpError::OffsetTooLarge { line: 2, column: 1 })
+    );
+}
+
+#[test]
+fn squeezed_runs_repeat_the_previous_line() {
+    let data: Vec<u8> = b"0123".iter().copied().cycle().take(70).collect();
+    let dumper = HexDumper::new().preset(Preset::HexdumpCanonical);
+    let text = dumper.dump(&data);
+    assert!(text.contains("*\n"));
+    assert_eq!(undump(&text).unwrap(), data);
+
+    // Runs at the end, with no offset after them.
+    let mut data = b"head".repeat(4);
+    data.extend([0; 64]);
+    let dumper = HexDumper::new().squeeze(Squeeze::Always);
+    let counted = dumper.clone().squeeze_annotation(true).dump(&data);
+    assert!(counted.ends_with("* (3 identical lines)\n"));
+    assert_eq!(undump(&counted).unwrap(), data);
+    let bare = dumper.dump(&data);
+    assert!(bare.ends_with("*\n"));
+    assert_eq!(
+        undump(&bare),
+        Err(UndumpError::UnendedRun { line: 3, column: 1 })
+    );
+    let closed = dumper.trailing_offset(TrailingOffset::NonEmpty).dump(&data);
+    assert_eq!(undump(&closed).unwrap(), data);
+    // A run too long for the output.
+    assert_eq!(
+        Undumper::new()
+            .max_len(64)
+            .parse("00000000: 4142\n* (40 identical lines)\n"),
+        Err(UndumpError::OffsetTooLarge { line: 2, column: 1 })
+    );
+}
+
+#[test]
+fn errors_report_line_and_column() {
+    assert_eq!(
+        undump("+0000000: 41 42\n"),
+        Err(UndumpError::InvalidOffset { line: 1, column: 1 })
+    );
+    let err = undump("00000000: 41 42\nzz: 43\n").unwrap_err();
+    assert_eq!(err, UndumpError::InvalidOffset { line: 2, column: 1 });
+    assert_eq!(err.to_string(), "line 2, column 1: invalid offset");
+
+    assert_eq!(
+        undump("00000000: 41 4g\n"),
+        Err(UndumpError::InvalidHex {
+            line: 1,
+            column: 15
+        })
+    );
+    assert_eq!(
+        undump("  00000000: 414\n"),
+        Err(UndumpError::UnpairedDigit {
+            line: 1,
+            column: 15
+        })
+    );
+    assert_eq!(
+        Undumper::new().plain(true).parse("4142\n43\n4"),
+        Err(UndumpError::UnpairedDigit { line: 3, column: 1 })
+    );
+}
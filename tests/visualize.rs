@@ -0,0 +1,165 @@
+#![cfg(feature = "visualize")]
+
+use hexdump::color::{Color, Style, Theme};
+use hexdump::visualize::{Bitmap, Colors, Layout, Visualizer};
+
+/// A deterministic stream of bytes that looks random.
+fn noise(len: usize) -> Vec<u8> {
+    let mut state = 0x2545_f491_4f6c_dd1du64;
+    (0..len)
+        .map(|_| {
+            state ^= state << 13;
+            state ^= state >> 7;
+            state ^= state << 17;
+            (state >> 56) as u8
+        })
+        .collect()
+}
+
+/// Every pixel, row by row.
+fn pixels(bitmap: &Bitmap) -> Vec<[u8; 4]> {
+    bitmap
+        .as_rgba()
+        .chunks(4)
+        .map(|p| p.try_into().unwrap())
+        .collect()
+}
+
+#[test]
+fn linear() {
+    let data: Vec<u8> = (0..10).map(|i| i * 20).collect();
+    let bitmap = Visualizer::new()
+        .colors(Colors::Greyscale)
+        .width(4)
+        .render(&data);
+    assert_eq!((bitmap.width(), bitmap.height()), (4, 3));
+    assert_eq!(bitmap.as_rgba().len(), 4 * 3 * 4);
+    assert_eq!(bitmap.pixel(1, 0), Some([20, 20, 20, 255]));
+    assert_eq!(bitmap.pixel(0, 1), Some([80, 80, 80, 255]));
+    assert_eq!(bitmap.pixel(1, 2), Some([180, 180, 180, 255]));
+    // Past the end of the input.
+    assert_eq!(bitmap.pixel(2, 2), Some([0, 0, 0, 0]));
+    assert_eq!(bitmap.pixel(4, 0), None);
+    assert_eq!(bitmap.pixel(0, 3), None);
+
+    let empty = Visualizer::new().render(b"");
+    assert_eq!((empty.width(), empty.height()), (256, 1));
+    assert!(empty.as_rgba().iter().all(|&b| b == 0));
+    let empty = Visualizer::new().layout(Layout::Hilbert).render(b"");
+    assert_eq!(pixels(&empty), [[0, 0, 0, 0]]);
+}
+
+#[test]
+fn hilbert_keeps_neighbours_together() {
+    let bitmap = Visualizer::new().layout(Layout::Hilbert).render(&[0; 1000]);
+    assert_eq!((bitmap.width(), bitmap.height()), (32, 32));
+    // Find where each byte goes by drawing it alone in grey, then check
+    // that it sits next to the one before.
+    let places: Vec<(usize, usize)> = (0..1024)
+        .map(|i| {
+            let data: Vec<u8> = (0..1024).map(|j| u8::from(j == i)).collect();
+            let bitmap = Visualizer::new()
+                .colors(Colors::Greyscale)
+                .layout(Layout::Hilbert)
+                .render(&data);
+            let at = pixels(&bitmap).iter().position(|p| p[0] == 1).unwrap();
+            (at % 32, at / 32)
+        })
+        .collect();
+    assert_eq!(places[0], (0, 0));
+    assert_eq!(places[1023], (31, 0));
+    for pair in places.windows(2) {
+        let ((x0, y0), (x1, y1)) = (pair[0], pair[1]);
+        assert_eq!(x0.abs_diff(x1) + y0.abs_diff(y1), 1, "{pair:?}");
+    }
+    let mut sorted = places;
+    sorted.sort();
+    sorted.dedup();
+    assert_eq!(sorted.len(), 1024);
+}
+
+#[test]
+fn byte_classes() {
+    let theme = Theme {
+        printable: Style::new().fg(Color::Rgb(1, 2, 3)),
+        null: Style::new(),
+        ..Theme::DEFAULT
+    };
+    let bitmap = Visualizer::new().theme(theme).render(b"a\x00\xff\t");
+    let (r, g, b) = Color::RED.to_rgb();
+    let (ws_r, ws_g, ws_b) = Color::GREEN.to_rgb();
+    assert_eq!(
+        pixels(&bitmap)[..4],
+        [
+            [1, 2, 3, 255],
+            [229, 229, 229, 255],
+            [r, g, b, 255],
+            [ws_r, ws_g, ws_b, 255],
+        ]
+    );
+}
+
+#[test]
+fn entropy() {
+    let mut data = vec![0u8; 4096];
+    data.extend(noise(4096));
+    data.extend(b"The quick brown fox jumps over the lazy dog. ".repeat(90));
+    let bitmap = Visualizer::new()
+        .colors(Colors::Entropy)
+        .entropy_window(256)
+        .render(&data);
+    let colors = pixels(&bitmap);
+    // A run of one value is black.
+    assert_eq!(colors[100], [0, 0, 0, 255]);
+    // Random bytes are close to the top of the scale, pink.
+    let random = colors[4096 + 2048];
+    assert!(random[0] > 150 && random[2] > 150, "{random:?}");
+    // Text is in between, blue with little red.
+    let text = colors[8192 + 1024];
+    assert!(
+        text[0] < 50 && text[2] > 60 && text[2] < random[2],
+        "{text:?}"
+    );
+
+    // Windows longer than the input are cut to it.
+    let short = Visualizer::new().colors(Colors::Entropy).render(&[0, 255]);
+    assert_eq!(
+        pixels(&short)[..3],
+        [[255, 0, 255, 255], [255, 0, 255, 255], [0; 4]]
+    );
+}
+
+#[test]
+fn bytes_per_pixel() {
+    let bitmap = Visualizer::new()
+        .colors(Colors::Greyscale)
+        .bytes_per_pixel(4)
+        .width(2)
+        .render(&[0, 10, 20, 30, 100, 101, 200]);
+    assert_eq!((bitmap.width(), bitmap.height()), (2, 1));
+    assert_eq!(pixels(&bitmap), [[15, 15, 15, 255], [134, 134, 134, 255]]);
+
+    let bitmap = Visualizer::new()
+        .layout(Layout::Hilbert)
+        .bytes_per_pixel(64)
+        .render(&noise(1 << 16));
+    assert_eq!((bitmap.width(), bitmap.height()), (32, 32));
+}
+
+#[test]
+fn png() {
+    let data = noise(3000);
+    let visualizer = Visualizer::new().width(100);
+    let bitmap = visualizer.render(&data);
+    let mut file = Vec::new();
+    visualizer.write_png(&data, &mut file).unwrap();
+
+    let decoder = png::Decoder::new(&file[..]);
+    let mut reader = decoder.read_info().unwrap();
+    let mut buf = vec![0; reader.output_buffer_size()];
+    let info = reader.next_frame(&mut buf).unwrap();
+    assert_eq!((info.width, info.height), (100, 30));
+    assert_eq!(info.color_type, png::ColorType::Rgba);
+    assert_eq!(info.bit_depth, png::BitDepth::Eight);
+    assert_eq!(&buf[..info.buffer_size()], bitmap.as_rgba());
+}